@@ -4,11 +4,16 @@
 //! and are used by multiple systems throughout the game engine.
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Core player identification marker component  
 #[derive(Component, Debug)]
 pub struct Player;
 
+/// Marker component for god mode - the entity ignores all incoming damage
+#[derive(Component, Debug)]
+pub struct GodMode;
+
 /// Player movement configuration component
 #[derive(Component, Debug)]
 pub struct PlayerMovementConfig {
@@ -97,4 +102,41 @@ impl Default for CharacterModel {
             model_entity: None,
         }
     }
-}
\ No newline at end of file
+}
+
+fn default_spawn_weight() -> u32 {
+    1
+}
+
+/// One weighted entry in a spawn point's spawn table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpawnTableEntry {
+    /// Enemy id from config/enemies.json
+    pub enemy_id: String,
+    #[serde(default = "default_spawn_weight")]
+    pub weight: u32,
+}
+
+/// Inclusive range of enemy levels - spawns roll their level within it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelBand {
+    pub min: u32,
+    pub max: u32,
+}
+
+/// Enemy spawn point placed in a zone - the game keeps it populated up to `max_population`
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct SpawnPoint {
+    pub spawn_table: Vec<SpawnTableEntry>,
+    /// Seconds between an enemy dying and its replacement spawning
+    pub respawn_delay: f32,
+    /// Random +/- seconds added to each respawn so camps don't pop all at once
+    #[serde(default)]
+    pub respawn_jitter: f32,
+    pub max_population: u32,
+    /// Enemies spawn and patrol within this distance of the spawn point
+    pub wander_radius: f32,
+    /// Levels enemies spawn at here - overrides their own level range, stats scale to match
+    #[serde(default)]
+    pub level_band: Option<LevelBand>,
+}
@@ -153,6 +153,9 @@ pub enum ProgressionEvent {
         skill_id: String,
         amount: u64,
         source: String,
+        /// Level of whatever awarded the experience - drives skill-by-use scaling.
+        /// Sources without a level of their own (quests, exploration) pass the player's level.
+        target_level: u32,
     },
     /// Level up occurred
     LevelUp {
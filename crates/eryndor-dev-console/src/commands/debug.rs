@@ -148,9 +148,8 @@ fn cmd_reload(
     }
 }
 
-/// Marker component for god mode
-#[derive(Component)]
-pub struct GodMode;
+/// Marker component for god mode - shared through eryndor-core so combat can honour it
+pub use eryndor_core::components::GodMode;
 
 /// System to process god mode toggle commands
 pub fn process_godmode_commands(
@@ -2,6 +2,7 @@
 
 use bevy::prelude::*;
 use eryndor_dialogue::components::{NpcInfo, NpcType, DialogueState, DialogueInteractable};
+use eryndor_core::components::{SpawnPoint, LevelBand};
 use crate::{CommandRegistry, CommandDef, CommandResult, ConsoleState, DevModeChanged, ZoneTransition};
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -13,9 +14,22 @@ pub struct ZoneDefinition {
     pub spawn_point: [f32; 3],
     pub terrain_type: String,
     pub npcs: Vec<ZoneNpc>,
+    #[serde(default)]
+    pub spawn_points: Vec<ZoneSpawnPoint>,
+    /// Enemy levels for spawn points that don't set their own band
+    #[serde(default)]
+    pub level_band: Option<LevelBand>,
     pub environment: ZoneEnvironment,
 }
 
+/// Enemy spawn point declared by a zone - the game populates it from config/enemies.json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZoneSpawnPoint {
+    pub position: [f32; 3],
+    #[serde(flatten)]
+    pub spawn_point: SpawnPoint,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ZoneNpc {
     pub npc_type: String,
@@ -46,6 +60,7 @@ pub fn process_zone_loading_commands(
     load_query: Query<(Entity, &LoadZoneCommand)>,
     clear_query: Query<Entity, With<ClearZoneCommand>>,
     npc_query: Query<Entity, With<NpcInfo>>,
+    spawn_point_query: Query<Entity, With<SpawnPoint>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut zone_transition_writer: EventWriter<ZoneTransition>,
@@ -56,14 +71,24 @@ pub fn process_zone_loading_commands(
         for npc_entity in &npc_query {
             commands.entity(npc_entity).despawn();
         }
+        // The game despawns enemies whose spawn point disappears
+        for spawn_point_entity in &spawn_point_query {
+            commands.entity(spawn_point_entity).despawn();
+        }
         commands.entity(entity).despawn();
     }
 
     // Process zone loading commands
     for (entity, load_cmd) in &load_query {
         if let Ok(zone_def) = load_zone_definition(&load_cmd.zone_name) {
-            // Clear existing NPCs first
-            commands.spawn(ClearZoneCommand);
+            // Clear existing NPCs and spawn points first - queuing a ClearZoneCommand here
+            // would run next frame and remove the zone we're about to load
+            for npc_entity in &npc_query {
+                commands.entity(npc_entity).despawn();
+            }
+            for spawn_point_entity in &spawn_point_query {
+                commands.entity(spawn_point_entity).despawn();
+            }
             
             // Load NPCs from zone definition
             for zone_npc in &zone_def.npcs {
@@ -106,13 +131,25 @@ pub fn process_zone_loading_commands(
                 ));
             }
             
+            // Place enemy spawn points
+            for (index, zone_spawn_point) in zone_def.spawn_points.iter().enumerate() {
+                let position = Vec3::new(zone_spawn_point.position[0], zone_spawn_point.position[1], zone_spawn_point.position[2]);
+                let mut spawn_point = zone_spawn_point.spawn_point.clone();
+                spawn_point.level_band = spawn_point.level_band.or(zone_def.level_band);
+                commands.spawn((
+                    Transform::from_translation(position),
+                    spawn_point,
+                    Name::new(format!("{} spawn point {}", zone_def.name, index + 1)),
+                ));
+            }
+            
             // Send zone transition event
             zone_transition_writer.write(ZoneTransition {
                 from_zone: None, // We don't track current zone yet
                 to_zone: zone_def.name.clone(),
             });
             
-            info!("Loaded zone '{}' with {} NPCs", zone_def.name, zone_def.npcs.len());
+            info!("Loaded zone '{}' with {} NPCs and {} spawn points", zone_def.name, zone_def.npcs.len(), zone_def.spawn_points.len());
         } else {
             error!("Failed to load zone: {}", load_cmd.zone_name);
         }
@@ -0,0 +1,499 @@
+/*!
+ * Combat Simulator - Headless combat balance tool
+ *
+ * Pits a player loadout against an enemy from enemies.json over and over without rendering,
+ * running the game's own auto-attack, attack table, damage and status effect systems, and prints:
+ * - Time to kill (fights the player won)
+ * - Player DPS
+ * - Damage taken
+ * - Win rate
+ * with mean, standard deviation, variance, min and max across all trials
+ *
+ * Usage: cargo run -p eryndor-game --bin combat_sim -- --enemy <id> [options]
+ *   (run from the repository root so config/ resolves)
+ *   --enemy <id>            Enemy id from config/enemies.json (required)
+ *   --weapon <id>           Main hand weapon id from config/weapons.json (default: sword)
+ *   --offhand <id>          Off-hand weapon or shield id (default: none)
+ *   --armor <type>          light, medium or heavy (default: medium)
+ *   --level <n>             Character level (default: 1)
+ *   --skill-level <n>       Level of every skill not listed in --skills (default: 1)
+ *   --skills <id=n,...>     Skill levels by id, e.g. swordsmanship=20,shield_defense=10
+ *   --enemy-level <n>       Fixed enemy level (default: rolled from the enemy's level range)
+ *   --player-health <n>     Player max health (default: 100)
+ *   --trials <n>            Number of fights to simulate (default: 1000)
+ *   --max-seconds <n>       Fights still going after this long count as losses (default: 300)
+ *   --format <csv|json>     Output format (default: csv)
+ * Example: cargo run -p eryndor-game --bin combat_sim -- --enemy wild_boar --weapon axe --skills axe_mastery=15 --trials 500
+ *
+ * Only auto-attacks are simulated - hotbar abilities need a rotation and are left out.
+ */
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use avian3d::prelude::SpatialQueryPipeline;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::env;
+use std::process;
+use std::time::Duration;
+use rand::prelude::*;
+use eryndor::components::{Player, ArmorType, CharacterLevel, CharacterLoadouts, CharacterSkills, Loadout, SkillType, WeaponType};
+use eryndor::resources::{ProgressionConfig, EnemyDatabase, EnemyDefinition, StatusEffectConfig, load_progression_config, load_enemy_database, load_loot_tables, load_status_effect_config};
+use eryndor::systems::*;
+
+/// Simulation step - matches a 60 FPS frame
+const TICK_SECONDS: f32 = 1.0 / 60.0;
+/// Enemies stand this far in front of the player - inside every weapon's reach and every enemy's attack range
+const ENGAGE_DISTANCE: f32 = 1.5;
+/// Health the player spawns with in game
+const DEFAULT_PLAYER_HEALTH: f32 = 100.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Csv,
+    Json,
+}
+
+/// Everything describing one matchup - parsed from the command line
+#[derive(Debug, Clone)]
+struct SimSettings {
+    enemy_id: String,
+    loadout: Loadout,
+    level: u32,
+    default_skill_level: u32,
+    skill_levels: HashMap<SkillType, u32>,
+    enemy_level: Option<u32>,
+    player_health: f32,
+    trials: u32,
+    max_seconds: f32,
+    format: OutputFormat,
+}
+
+/// Result of a single fight
+#[derive(Debug, Clone, Copy)]
+struct TrialResult {
+    won: bool,
+    duration: f32,
+    damage_dealt: f32,
+    damage_taken: f32,
+}
+
+/// Running totals for the fight in progress
+#[derive(Resource, Debug, Default)]
+struct TrialLog {
+    damage_dealt: f32,
+    damage_taken: f32,
+}
+
+/// The two combatants of the fight in progress
+#[derive(Resource, Debug)]
+struct Combatants {
+    player: Entity,
+    enemy: Entity,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+struct MetricSummary {
+    mean: f32,
+    std_dev: f32,
+    variance: f32,
+    min: f32,
+    max: f32,
+}
+
+impl MetricSummary {
+    /// Sample statistics - all zeros for an empty set (e.g. time to kill when every fight was lost)
+    fn from_samples(samples: &[f32]) -> Self {
+        if samples.is_empty() {
+            return Self { mean: 0.0, std_dev: 0.0, variance: 0.0, min: 0.0, max: 0.0 };
+        }
+
+        let count = samples.len() as f32;
+        let mean = samples.iter().sum::<f32>() / count;
+        let variance = if samples.len() > 1 {
+            samples.iter().map(|sample| (sample - mean).powi(2)).sum::<f32>() / (count - 1.0)
+        } else {
+            0.0
+        };
+
+        Self {
+            mean,
+            std_dev: variance.sqrt(),
+            variance,
+            min: samples.iter().copied().fold(f32::INFINITY, f32::min),
+            max: samples.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SimReport {
+    enemy: String,
+    trials: u32,
+    wins: u32,
+    win_rate: MetricSummary,
+    time_to_kill: MetricSummary,
+    dps: MetricSummary,
+    damage_taken: MetricSummary,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let progression_config = load_progression_config();
+    let status_config = load_status_effect_config();
+    let enemy_database = load_enemy_database(&status_config, &progression_config, &load_loot_tables());
+
+    let settings = match parse_args(&args, &progression_config) {
+        Ok(settings) => settings,
+        Err(error) => {
+            eprintln!("❌ {}", error);
+            eprintln!("Usage: combat_sim --enemy <id> [--weapon <id>] [--offhand <id>] [--armor <type>] [--level <n>]");
+            eprintln!("                  [--skill-level <n>] [--skills <id=n,...>] [--enemy-level <n>] [--player-health <n>]");
+            eprintln!("                  [--trials <n>] [--max-seconds <n>] [--format <csv|json>]");
+            process::exit(2);
+        }
+    };
+
+    let Some(definition) = enemy_database.get(&settings.enemy_id) else {
+        let mut known: Vec<&String> = enemy_database.enemies.keys().collect();
+        known.sort();
+        eprintln!("❌ Unknown enemy '{}' - known enemies: {:?}", settings.enemy_id, known);
+        process::exit(2);
+    };
+
+    let results: Vec<TrialResult> = (0..settings.trials)
+        .map(|_| run_trial(&settings, definition, &progression_config, &enemy_database, &status_config))
+        .collect();
+
+    let report = build_report(&settings, &results);
+    match settings.format {
+        OutputFormat::Csv => print_csv(&report),
+        OutputFormat::Json => match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(error) => {
+                eprintln!("❌ Failed to serialize report: {}", error);
+                process::exit(1);
+            }
+        },
+    }
+}
+
+fn parse_args(args: &[String], config: &ProgressionConfig) -> Result<SimSettings, String> {
+    let mut settings = SimSettings {
+        enemy_id: String::new(),
+        loadout: Loadout {
+            name: "Simulated".to_string(),
+            primary_weapon: WeaponType::Sword,
+            secondary_item: None,
+            armor_type: ArmorType::Medium,
+            active_abilities: Vec::new(),
+        },
+        level: 1,
+        default_skill_level: 1,
+        skill_levels: HashMap::new(),
+        enemy_level: None,
+        player_health: DEFAULT_PLAYER_HEALTH,
+        trials: 1000,
+        max_seconds: 300.0,
+        format: OutputFormat::Csv,
+    };
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("Missing value for {}", flag))?;
+        match flag.as_str() {
+            "--enemy" => settings.enemy_id = value.clone(),
+            "--weapon" => {
+                settings.loadout.primary_weapon = config
+                    .weapon_id_to_enum(value)
+                    .ok_or_else(|| format!("Unknown weapon '{}'", value))?;
+            }
+            "--offhand" => {
+                settings.loadout.secondary_item = Some(config
+                    .weapon_id_to_enum(value)
+                    .ok_or_else(|| format!("Unknown weapon '{}'", value))?);
+            }
+            "--armor" => {
+                settings.loadout.armor_type = match value.as_str() {
+                    "light" => ArmorType::Light,
+                    "medium" => ArmorType::Medium,
+                    "heavy" => ArmorType::Heavy,
+                    _ => return Err(format!("Unknown armor type '{}' - use light, medium or heavy", value)),
+                };
+            }
+            "--level" => settings.level = parse_number(flag, value)?,
+            "--skill-level" => settings.default_skill_level = parse_number(flag, value)?,
+            "--skills" => {
+                for entry in value.split(',').filter(|entry| !entry.is_empty()) {
+                    let (skill_id, level) = entry
+                        .split_once('=')
+                        .ok_or_else(|| format!("Skill levels look like skill_id=level, got '{}'", entry))?;
+                    let skill = config
+                        .skill_id_to_enum(skill_id)
+                        .ok_or_else(|| format!("Unknown skill '{}'", skill_id))?;
+                    settings.skill_levels.insert(skill, parse_number(flag, level)?);
+                }
+            }
+            "--enemy-level" => settings.enemy_level = Some(parse_number(flag, value)?),
+            "--player-health" => settings.player_health = parse_number(flag, value)?,
+            "--trials" => settings.trials = parse_number(flag, value)?,
+            "--max-seconds" => settings.max_seconds = parse_number(flag, value)?,
+            "--format" => {
+                settings.format = match value.as_str() {
+                    "csv" => OutputFormat::Csv,
+                    "json" => OutputFormat::Json,
+                    _ => return Err(format!("Unknown format '{}' - use csv or json", value)),
+                };
+            }
+            _ => return Err(format!("Unknown option '{}'", flag)),
+        }
+    }
+
+    if settings.enemy_id.is_empty() {
+        return Err("--enemy is required".to_string());
+    }
+    if settings.trials == 0 {
+        return Err("--trials must be at least 1".to_string());
+    }
+
+    Ok(settings)
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("Invalid value '{}' for {}", value, flag))
+}
+
+/// Build a headless app with the real combat systems and fight one enemy to the death
+fn run_trial(
+    settings: &SimSettings,
+    definition: &EnemyDefinition,
+    progression_config: &ProgressionConfig,
+    enemy_database: &EnemyDatabase,
+    status_config: &StatusEffectConfig,
+) -> TrialResult {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(TICK_SECONDS)))
+        // No colliders are spawned, so line of sight is always clear
+        .init_resource::<SpatialQueryPipeline>()
+        .insert_resource(progression_config.clone())
+        .insert_resource(enemy_database.clone())
+        .insert_resource(status_config.clone())
+        .insert_resource(CombatConfig::default())
+        .init_resource::<CombatState>()
+        .init_resource::<DynamicDifficulty>()
+        .init_resource::<TrialLog>()
+        .add_event::<DamageEvent>()
+        .add_event::<DamageDealt>()
+        .add_event::<AttackAvoided>()
+        .add_event::<HealEvent>()
+        .add_event::<HealingDone>()
+        .add_event::<FireProjectile>()
+        .add_event::<ApplyStatusEffect>()
+        .add_event::<StatusEffectApplied>()
+        .add_systems(Update, (
+            sync_auto_attack_with_loadout,
+            handle_player_auto_attack.after(sync_auto_attack_with_loadout),
+            handle_enemy_attacks,
+            land_projectiles.after(handle_player_auto_attack),
+            tick_status_effects,
+            resolve_damage_events
+                .after(handle_player_auto_attack)
+                .after(handle_enemy_attacks)
+                .after(land_projectiles)
+                .after(tick_status_effects),
+            resolve_heal_events.after(tick_status_effects),
+            trigger_status_effects_from_damage.after(resolve_damage_events),
+            apply_status_effects.after(trigger_status_effects_from_damage),
+            record_trial_damage.after(resolve_damage_events),
+        ));
+
+    let combatants = spawn_combatants(app.world_mut(), settings, definition, progression_config);
+    app.insert_resource(combatants);
+
+    let mut elapsed = 0.0;
+    let won = loop {
+        app.update();
+        elapsed += TICK_SECONDS;
+
+        let world = app.world();
+        let combatants = world.resource::<Combatants>();
+        let is_alive = |entity: Entity| world.get::<Health>(entity).is_some_and(|health| health.is_alive());
+
+        if !is_alive(combatants.enemy) {
+            break true;
+        }
+        if !is_alive(combatants.player) || elapsed >= settings.max_seconds {
+            break false;
+        }
+    };
+
+    let log = app.world().resource::<TrialLog>();
+    TrialResult {
+        won,
+        duration: elapsed,
+        damage_dealt: log.damage_dealt,
+        damage_taken: log.damage_taken,
+    }
+}
+
+/// Spawn the player and the enemy face to face, already fighting each other
+fn spawn_combatants(world: &mut World, settings: &SimSettings, definition: &EnemyDefinition, config: &ProgressionConfig) -> Combatants {
+    let mut skills = CharacterSkills::default();
+    for skill in SkillType::all_skills() {
+        let level = settings.skill_levels.get(&skill).copied().unwrap_or(settings.default_skill_level);
+        if let Some(skill_line) = skills.get_skill_line_mut(skill) {
+            skill_line.level = level;
+        }
+    }
+
+    let player = world.spawn((
+        Player,
+        Transform::default(), // Facing +Z, towards the enemy
+        CharacterLevel { level: settings.level, ..default() },
+        skills,
+        CharacterLoadouts {
+            loadouts: vec![settings.loadout.clone()],
+            ..default()
+        },
+        Health::new(settings.player_health),
+        StatusEffects::default(),
+    )).id();
+
+    let level = settings.enemy_level.unwrap_or_else(|| definition.level_range.roll(&mut thread_rng()));
+    let position = Vec3::new(0.0, ENEMY_HALF_HEIGHT, ENGAGE_DISTANCE);
+    let mut ai = EnemyAi::new(position);
+    ai.target = Some(player);
+    ai.state = EnemyAiState::Attack;
+
+    let enemy_component = Enemy::from_definition(EnemyType::new(settings.enemy_id.as_str()), definition, level, config);
+    let enemy_health = Health::new(enemy_component.stats.max_health);
+    // Characters face +Z, so turn the enemy's front (not its back) towards the player
+    let to_player = -position;
+    let enemy = world.spawn((
+        enemy_component,
+        Transform::from_translation(position).with_rotation(Quat::from_rotation_y(to_player.x.atan2(to_player.z))),
+        enemy_health,
+        enemy_damage_resistances(definition),
+        ai,
+        ThreatTable::default(),
+        StatusEffects::default(),
+    )).id();
+
+    world.resource_mut::<CombatState>().player_target = Some(enemy);
+    world.resource_mut::<CombatState>().in_combat = true;
+
+    Combatants { player, enemy }
+}
+
+// System to land ranged swings straight away - there's no world for projectiles to fly through
+fn land_projectiles(
+    mut projectile_events: EventReader<FireProjectile>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    for event in projectile_events.read() {
+        damage_events.write(event.payload.clone());
+    }
+}
+
+// System to total up damage dealt and taken during the fight
+fn record_trial_damage(
+    mut damage_dealt_events: EventReader<DamageDealt>,
+    combatants: Res<Combatants>,
+    mut log: ResMut<TrialLog>,
+) {
+    for event in damage_dealt_events.read() {
+        if event.target == combatants.player {
+            log.damage_taken += event.amount;
+        } else if event.target == combatants.enemy {
+            log.damage_dealt += event.amount;
+        }
+    }
+}
+
+fn build_report(settings: &SimSettings, results: &[TrialResult]) -> SimReport {
+    let wins: Vec<f32> = results.iter().map(|result| if result.won { 1.0 } else { 0.0 }).collect();
+    let time_to_kill: Vec<f32> = results.iter().filter(|result| result.won).map(|result| result.duration).collect();
+    let dps: Vec<f32> = results.iter().map(|result| result.damage_dealt / result.duration.max(TICK_SECONDS)).collect();
+    let damage_taken: Vec<f32> = results.iter().map(|result| result.damage_taken).collect();
+
+    SimReport {
+        enemy: settings.enemy_id.clone(),
+        trials: settings.trials,
+        wins: time_to_kill.len() as u32,
+        win_rate: MetricSummary::from_samples(&wins),
+        time_to_kill: MetricSummary::from_samples(&time_to_kill),
+        dps: MetricSummary::from_samples(&dps),
+        damage_taken: MetricSummary::from_samples(&damage_taken),
+    }
+}
+
+fn print_csv(report: &SimReport) {
+    println!("metric,mean,std_dev,variance,min,max");
+    for (name, metric) in [
+        ("win_rate", &report.win_rate),
+        ("time_to_kill", &report.time_to_kill),
+        ("dps", &report.dps),
+        ("damage_taken", &report.damage_taken),
+    ] {
+        println!("{},{:.3},{:.3},{:.3},{:.3},{:.3}", name, metric.mean, metric.std_dev, metric.variance, metric.min, metric.max);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eryndor::components::AttackPosition;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let config = load_progression_config();
+
+        let settings = parse_args(&args(&["--enemy", "wild_boar", "--armor", "heavy", "--skills", "swordsmanship=20", "--trials", "50", "--format", "json"]), &config).unwrap();
+        assert_eq!(settings.enemy_id, "wild_boar");
+        assert_eq!(settings.loadout.armor_type, ArmorType::Heavy);
+        assert_eq!(settings.skill_levels.get(&SkillType::Swordsmanship), Some(&20));
+        assert_eq!(settings.trials, 50);
+        assert_eq!(settings.format, OutputFormat::Json);
+
+        assert!(parse_args(&args(&["--weapon", "sword"]), &config).is_err()); // --enemy is required
+        assert!(parse_args(&args(&["--enemy", "wild_boar", "--trials", "0"]), &config).is_err());
+        assert!(parse_args(&args(&["--enemy", "wild_boar", "--armor", "mithril"]), &config).is_err());
+        assert!(parse_args(&args(&["--enemy", "wild_boar", "--skills", "swordsmanship"]), &config).is_err());
+        assert!(parse_args(&args(&["--enemy"]), &config).is_err());
+    }
+
+    #[test]
+    fn test_metric_summary() {
+        let summary = MetricSummary::from_samples(&[2.0, 4.0, 6.0]);
+        assert_eq!(summary.mean, 4.0);
+        assert_eq!(summary.variance, 4.0);
+        assert_eq!(summary.std_dev, 2.0);
+        assert_eq!(summary.min, 2.0);
+        assert_eq!(summary.max, 6.0);
+
+        assert_eq!(MetricSummary::from_samples(&[3.0]).variance, 0.0);
+        assert_eq!(MetricSummary::from_samples(&[]).mean, 0.0);
+    }
+
+    #[test]
+    fn test_player_swings_land_on_the_enemy_front() {
+        let config = load_progression_config();
+        let enemy_database = load_enemy_database(&load_status_effect_config(), &config, &load_loot_tables());
+        let (enemy_id, definition) = enemy_database.enemies.iter().next().unwrap();
+        let settings = parse_args(&args(&["--enemy", enemy_id]), &config).unwrap();
+
+        let mut world = World::new();
+        world.init_resource::<CombatState>();
+        let combatants = spawn_combatants(&mut world, &settings, definition, &config);
+
+        let player_position = world.get::<Transform>(combatants.player).unwrap().translation;
+        let enemy_transform = world.get::<Transform>(combatants.enemy).unwrap();
+        assert_eq!(attack_position(enemy_transform, player_position), AttackPosition::Front);
+    }
+}
@@ -5,7 +5,7 @@ pub mod progression;
 pub mod quest;
 pub mod dialogue;
 
-pub use player::{Player, PlayerMovementConfig, PlayerMovementState, CharacterType, CharacterModel};
+pub use player::{Player, GodMode, PlayerMovementConfig, PlayerMovementState, CharacterType, CharacterModel};
 pub use animation::*;
 pub use progression::*;
 pub use quest::*;
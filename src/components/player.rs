@@ -1,8 +1,7 @@
 use bevy::prelude::*;
 
-/// Core player identification marker component  
-#[derive(Component, Debug)]
-pub struct Player;
+/// Core player identification marker component - shared with the dev console through eryndor-core
+pub use eryndor_core::components::{Player, GodMode};
 
 /// Player movement configuration component
 #[derive(Component, Debug)]
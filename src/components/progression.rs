@@ -1,10 +1,12 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use crate::resources::{ExperienceCurve, ProgressionConfig};
 
 /// Global character level - represents overall character power and progression
 #[derive(Component, Debug, Clone)]
 pub struct CharacterLevel {
-    /// Current character level (1-50)
+    /// Current character level (1 to the configured max level)
     pub level: u32,
     /// Experience points towards next level
     pub experience: u64,
@@ -17,28 +19,20 @@ impl Default for CharacterLevel {
         Self {
             level: 1,
             experience: 0,
-            experience_to_next_level: Self::calculate_experience_for_level(2),
+            experience_to_next_level: ExperienceCurve::character_default().experience_for_level(2),
         }
     }
 }
 
 impl CharacterLevel {
-    /// Calculate total experience required to reach a specific level
-    pub fn calculate_experience_for_level(level: u32) -> u64 {
-        if level <= 1 {
-            return 0; // Level 1 requires 0 experience
-        }
-        // Exponential growth formula: level^2 * 100 + level * 200
-        ((level as u64).pow(2) * 100) + ((level as u64) * 200)
-    }
-    
     /// Add experience and handle level ups
-    pub fn gain_experience(&mut self, exp: u64) -> bool {
+    pub fn gain_experience(&mut self, exp: u64, curve: &ExperienceCurve) -> bool {
         self.experience += exp;
+        self.experience_to_next_level = curve.experience_for_level(self.level + 1);
         
-        if self.experience >= self.experience_to_next_level && self.level < 50 {
+        if self.experience >= self.experience_to_next_level && self.level < curve.level_cap() {
             self.level += 1;
-            self.experience_to_next_level = Self::calculate_experience_for_level(self.level + 1);
+            self.experience_to_next_level = curve.experience_for_level(self.level + 1);
             true // Level up occurred
         } else {
             false // No level up
@@ -46,15 +40,15 @@ impl CharacterLevel {
     }
     
     /// Get progress towards next level (0.0 to 1.0)
-    pub fn level_progress(&self) -> f32 {
-        if self.level >= 50 {
+    pub fn level_progress(&self, curve: &ExperienceCurve) -> f32 {
+        if self.level >= curve.level_cap() {
             return 1.0;
         }
         
-        let current_level_exp = Self::calculate_experience_for_level(self.level);
-        let next_level_exp = self.experience_to_next_level;
-        let progress_in_level = self.experience - current_level_exp;
-        let level_exp_range = next_level_exp - current_level_exp;
+        let current_level_exp = curve.experience_for_level(self.level);
+        let next_level_exp = curve.experience_for_level(self.level + 1);
+        let progress_in_level = self.experience.saturating_sub(current_level_exp);
+        let level_exp_range = next_level_exp.saturating_sub(current_level_exp).max(1);
         
         (progress_in_level as f32) / (level_exp_range as f32)
     }
@@ -257,7 +251,7 @@ pub enum DamageType {
 /// Individual skill line progression (V2 system)
 #[derive(Debug, Clone)]
 pub struct SkillLineV2 {
-    /// Current skill level (1-50)
+    /// Current skill level (1 to the skill's max level)
     pub level: u32,
     /// Experience points towards next level
     pub experience: u64,
@@ -279,19 +273,13 @@ impl Default for SkillLineV2 {
 }
 
 impl SkillLineV2 {
-    /// Calculate experience required for a specific skill level
-    pub fn experience_for_level(level: u32) -> u64 {
-        // Skill progression: level^1.8 * 50 + level * 25
-        ((level as f64).powf(1.8) * 50.0) as u64 + ((level as u64) * 25)
-    }
-    
     /// Add experience from skill usage
-    pub fn gain_experience(&mut self, exp: u64) -> bool {
+    pub fn gain_experience(&mut self, exp: u64, curve: &ExperienceCurve) -> bool {
         self.experience += exp;
         self.usage_count += 1;
         
-        let required_exp = Self::experience_for_level(self.level + 1);
-        if self.experience >= required_exp && self.level < 50 {
+        let required_exp = curve.experience_for_level(self.level + 1);
+        if self.experience >= required_exp && self.level < curve.level_cap() {
             self.level += 1;
             true // Level up occurred
         } else {
@@ -300,15 +288,15 @@ impl SkillLineV2 {
     }
     
     /// Get progress towards next level (0.0 to 1.0)
-    pub fn level_progress(&self) -> f32 {
-        if self.level >= 50 {
+    pub fn level_progress(&self, curve: &ExperienceCurve) -> f32 {
+        if self.level >= curve.level_cap() {
             return 1.0;
         }
         
-        let current_level_exp = Self::experience_for_level(self.level);
-        let next_level_exp = Self::experience_for_level(self.level + 1);
-        let progress_in_level = self.experience - current_level_exp;
-        let level_exp_range = next_level_exp - current_level_exp;
+        let current_level_exp = curve.experience_for_level(self.level);
+        let next_level_exp = curve.experience_for_level(self.level + 1);
+        let progress_in_level = self.experience.saturating_sub(current_level_exp);
+        let level_exp_range = next_level_exp.saturating_sub(current_level_exp).max(1);
         
         (progress_in_level as f32) / (level_exp_range as f32)
     }
@@ -375,11 +363,13 @@ impl CharacterSkills {
         self.skills.get_mut(&skill_type)
     }
     
-    /// Use a skill and gain experience (legacy hardcoded version)
+    /// Use a skill and gain experience along the skill's experience curve
     pub fn use_skill(&mut self, 
                      skill_type: SkillType, 
                      target_level: u32, 
-                     effectiveness: f32) -> bool {
+                     effectiveness: f32,
+                     config: &ProgressionConfig) -> bool {
+        let curve = config.skill_curve_by_enum(skill_type);
         if let Some(skill_line) = self.skills.get_mut(&skill_type) {
             let exp_gained = skill_line.calculate_usage_experience(
                 target_level, 
@@ -387,7 +377,7 @@ impl CharacterSkills {
                 self.rested_experience_bonus
             );
             
-            skill_line.gain_experience(exp_gained)
+            skill_line.gain_experience(exp_gained, &curve)
         } else {
             false
         }
@@ -580,6 +570,38 @@ impl WeaponType {
         )
     }
     
+    /// Bows, crossbows and thrown weapons fire projectiles instead of landing instant hits
+    pub fn is_ranged(&self) -> bool {
+        self.projectile_flight().is_some()
+    }
+
+    pub fn is_staff(&self) -> bool {
+        matches!(self,
+            WeaponType::FireStaff | WeaponType::IceStaff | WeaponType::LightningStaff |
+            WeaponType::ShadowStaff | WeaponType::NatureStaff | WeaponType::ArcaneStaff |
+            WeaponType::RestorationStaff | WeaponType::DivinationStaff
+        )
+    }
+
+    pub fn is_thrown(&self) -> bool {
+        matches!(self, WeaponType::ThrowingKnife | WeaponType::ThrowingAxe | WeaponType::Javelin)
+    }
+
+    /// How this weapon's projectiles fly - arrows and bolts stay nearly flat, thrown weapons arc
+    pub fn projectile_flight(&self) -> Option<ProjectileFlight> {
+        let (speed, gravity) = match self {
+            WeaponType::Bow => (45.0, 2.0),
+            WeaponType::Longbow => (55.0, 2.0),
+            WeaponType::Crossbow => (60.0, 1.0),
+            WeaponType::HandCrossbow => (45.0, 1.0),
+            WeaponType::ThrowingKnife => (25.0, 9.81),
+            WeaponType::ThrowingAxe => (20.0, 9.81),
+            WeaponType::Javelin => (28.0, 9.81),
+            _ => return None,
+        };
+        Some(ProjectileFlight { speed, gravity })
+    }
+
     /// Get weapon damage type for future damage system integration
     pub fn damage_type(&self) -> DamageType {
         match self {
@@ -614,6 +636,34 @@ impl WeaponType {
     }
 }
 
+/// Launch speed (units/second) and gravity (units/second²) of a projectile
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectileFlight {
+    pub speed: f32,
+    pub gravity: f32,
+}
+
+/// Which side of its target an attack comes from, relative to where the target is facing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttackPosition {
+    #[default]
+    Front,
+    Flank,
+    Rear,
+}
+
+impl AttackPosition {
+    /// Whether attacking from here meets a positional requirement - flank requirements accept the rear too
+    pub fn satisfies(&self, required: AttackPosition) -> bool {
+        match required {
+            AttackPosition::Front => *self == AttackPosition::Front,
+            AttackPosition::Flank => *self != AttackPosition::Front,
+            AttackPosition::Rear => *self == AttackPosition::Rear,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ArmorType {
     Heavy,   // Plate, chainmail - reduces damage, supports tanking
@@ -630,6 +680,15 @@ impl ArmorType {
             ArmorType::Medium => None, // Medium armor doesn't have specific skill requirements
         }
     }
+
+    /// Get the resistance key used by damage_types.json for this armor
+    pub fn resistance_key(&self) -> &'static str {
+        match self {
+            ArmorType::Heavy => "heavy_armor",
+            ArmorType::Light => "light_armor",
+            ArmorType::Medium => "medium_armor",
+        }
+    }
 }
 
 /// Individual loadout configuration for role switching
@@ -12,6 +12,7 @@ use resources::*;
 use states::*;
 use components::quest::QuestEvent;
 use components::dialogue::DialogueEvent;
+use eryndor_core::events::ProgressionEvent;
 
 // Re-export logging setup function for main.rs
 pub use systems::logging::setup_logging;
@@ -20,6 +21,13 @@ pub struct EryndorPlugin;
 
 impl Plugin for EryndorPlugin {
     fn build(&self, app: &mut App) {
+        // Enemies reference abilities, status effects and loot tables, and encounters reference enemies, so load in that order
+        let progression_config = load_progression_config();
+        let status_effect_config = load_status_effect_config();
+        let loot_tables = load_loot_tables();
+        let enemy_database = load_enemy_database(&status_effect_config, &progression_config, &loot_tables);
+        let encounter_database = load_encounter_database(&enemy_database, &status_effect_config, &progression_config);
+
         app
             // Physics - Avian 3D integration
             .add_plugins(PhysicsPlugins::default())
@@ -32,6 +40,11 @@ impl Plugin for EryndorPlugin {
             
             // Resources - Global state
             .insert_resource(load_config())
+            .insert_resource(progression_config) // Skills, weapons, damage types from config/
+            .insert_resource(status_effect_config) // DoTs, HoTs, stuns and slows from config/
+            .insert_resource(enemy_database) // Enemy stats, levels and AI tuning from config/
+            .insert_resource(loot_tables) // Enemy drops from config/
+            .insert_resource(encounter_database) // Boss phases, rotations and arenas from config/
             .init_resource::<InputResource>()
             .init_resource::<CollisionDebugConfig>() // Debug collision interaction
             .init_resource::<GameDebugConfig>() // Configurable debug logging
@@ -77,12 +90,164 @@ impl Plugin for EryndorPlugin {
             
             // Combat systems - only in InGame state
             .add_systems(Update, (
-                spawn_demo_enemies,
                 handle_target_selection,
-                handle_player_auto_attack,
-                cleanup_dead_enemies,
+                sync_auto_attack_with_loadout,
+                handle_player_auto_attack.after(sync_auto_attack_with_loadout),
+                resolve_damage_events.after(handle_player_auto_attack),
+                resolve_heal_events.after(execute_abilities),
+                handle_damage_dealt.after(resolve_damage_events),
+                handle_dead_enemies.after(handle_damage_dealt),
+                cleanup_corpses.after(handle_dead_enemies),
                 display_target_health,
             ).run_if(in_state(GameState::InGame)))
+            // Threat and enemy AI systems - only in InGame state
+            .add_systems(Update, (
+                generate_threat_from_damage.after(resolve_damage_events),
+                generate_threat_from_healing.after(resolve_heal_events),
+                handle_taunt_events.after(execute_abilities),
+                update_threat_targets
+                    .after(generate_threat_from_damage)
+                    .after(generate_threat_from_healing)
+                    .after(handle_taunt_events),
+                update_enemy_ai.after(update_threat_targets),
+            ).run_if(in_state(GameState::InGame)))
+            // Targeting systems - only in InGame state
+            .add_systems(Update, (
+                handle_click_targeting
+                    .after(handle_input)
+                    .before(handle_target_selection),
+                handle_focus_and_assist.after(handle_target_selection),
+                highlight_targets.after(handle_focus_and_assist),
+                update_target_frame_ui.after(handle_focus_and_assist),
+            ).run_if(in_state(GameState::InGame)))
+            // Projectile systems - only in InGame state
+            .init_resource::<ProjectileAssets>()
+            .add_systems(Update, (
+                launch_projectiles
+                    .after(handle_player_auto_attack)
+                    .after(execute_abilities),
+                update_projectiles
+                    .after(launch_projectiles)
+                    .before(resolve_damage_events),
+            ).run_if(in_state(GameState::InGame)))
+            .add_event::<FireProjectile>()
+            // Attack table systems - only in InGame state
+            .add_systems(Update, (
+                tick_riposte_windows,
+                open_riposte_window
+                    .after(resolve_damage_events)
+                    .after(tick_riposte_windows),
+            ).run_if(in_state(GameState::InGame)))
+            .add_event::<DamageEvent>()
+            .add_event::<DamageDealt>()
+            .add_event::<AttackAvoided>()
+            .add_event::<HealEvent>()
+            .add_event::<HealingDone>()
+            .add_event::<TauntEvent>()
+            // Ability systems - only in InGame state
+            .add_systems(Update, (
+                tick_ability_cooldowns,
+                handle_hotbar_input.after(tick_ability_cooldowns),
+                execute_abilities.after(handle_hotbar_input).before(resolve_damage_events),
+                update_hotbar_ui.after(handle_hotbar_input),
+            ).run_if(in_state(GameState::InGame)))
+            .add_event::<AbilityUsed>()
+            .add_event::<AbilityFailed>()
+            // Spellcasting systems - only in InGame state
+            .add_systems(Update, (
+                update_casting
+                    .after(handle_hotbar_input)
+                    .before(execute_abilities),
+                update_cast_bar_ui.after(update_casting),
+            ).run_if(in_state(GameState::InGame)))
+            .add_event::<CastInterrupted>()
+            // Combo systems - only in InGame state
+            .add_systems(Update, (
+                tick_combo_windows.before(handle_hotbar_input),
+                update_combo_windows
+                    .after(tick_combo_windows)
+                    .after(update_casting)
+                    .after(handle_hotbar_input),
+            ).run_if(in_state(GameState::InGame)))
+            // Status effect systems - only in InGame state
+            .add_systems(Update, (
+                trigger_status_effects_from_damage.after(resolve_damage_events),
+                apply_status_effects
+                    .after(trigger_status_effects_from_damage)
+                    .after(execute_abilities),
+                handle_dispel_events.after(execute_abilities),
+                tick_status_effects
+                    .after(apply_status_effects)
+                    .after(handle_dispel_events),
+            ).run_if(in_state(GameState::InGame)))
+            .add_event::<ApplyStatusEffect>()
+            .add_event::<StatusEffectApplied>()
+            .add_event::<DispelEvent>()
+            // Combat log and DPS/HPS meter - only in InGame state
+            .init_resource::<CombatLog>()
+            .add_systems(Update, (
+                record_combat_log
+                    .after(resolve_damage_events)
+                    .after(resolve_heal_events)
+                    .after(apply_status_effects),
+                handle_combat_log_input,
+                update_combat_meter_ui
+                    .after(record_combat_log)
+                    .after(handle_combat_log_input),
+            ).run_if(in_state(GameState::InGame)))
+            // Floating combat text and hit feedback - only in InGame state
+            .init_resource::<HitFlashMaterial>()
+            .init_resource::<HitStop>()
+            .add_systems(Update, (
+                spawn_combat_text
+                    .after(resolve_damage_events)
+                    .after(resolve_heal_events)
+                    .after(apply_status_effects),
+                update_combat_text.after(spawn_combat_text),
+                trigger_hit_flash.after(resolve_damage_events),
+                update_hit_flashes.after(trigger_hit_flash),
+                apply_hit_stop.after(resolve_damage_events),
+            ).run_if(in_state(GameState::InGame)))
+            .add_systems(OnExit(GameState::InGame), reset_hit_stop)
+            // Enemy spawn point systems - only in InGame state
+            .add_systems(Update, (
+                spawn_demo_spawn_points,
+                initialize_spawn_points.after(spawn_demo_spawn_points),
+                update_spawn_points.after(initialize_spawn_points).after(handle_dead_enemies),
+                despawn_orphaned_enemies,
+            ).run_if(in_state(GameState::InGame)))
+            // Loot systems - only in InGame state
+            .add_systems(Update, (
+                initialize_inventory,
+                generate_corpse_loot.after(handle_dead_enemies),
+                loot_nearby_corpse.after(generate_corpse_loot),
+                highlight_lootable_corpses,
+            ).run_if(in_state(GameState::InGame)))
+            .add_event::<LootPickedUp>()
+            // Boss encounter systems - only in InGame state
+            .add_systems(Update, (
+                spawn_encounter_bosses,
+                update_boss_encounters.after(update_enemy_ai),
+            ).run_if(in_state(GameState::InGame)))
+            .add_event::<EncounterStarted>()
+            .add_event::<PhaseChanged>()
+            .add_event::<EncounterWiped>()
+            .add_event::<EncounterDefeated>()
+            // Enemy attacks, player vitals, death and respawn - only in InGame state
+            .add_systems(Update, (
+                spawn_respawn_points,
+                handle_enemy_attacks.after(update_enemy_ai),
+                track_player_damage_taken.after(resolve_damage_events),
+                regenerate_vitals.after(track_player_damage_taken),
+                apply_armor_mana_bonus.before(regenerate_vitals),
+                detect_player_death.after(resolve_damage_events),
+                handle_player_respawn.after(detect_player_death),
+            ).run_if(in_state(GameState::InGame)))
+            .add_event::<PlayerDied>()
+            .add_event::<PlayerRespawned>()
+            // Dynamic difficulty - only in InGame state, and only adjusts when enabled in config
+            .init_resource::<DynamicDifficulty>()
+            .add_systems(Update, update_dynamic_difficulty.after(detect_player_death).run_if(in_state(GameState::InGame)))
             .add_systems(Startup, (
                 setup_animation_assets,
                 // setup_character_controller, // Not needed for simple kinematic controller
@@ -107,7 +272,10 @@ impl Plugin for EryndorPlugin {
                 debug_rested_bonus_system,
                 debug_award_character_experience_system,
                 debug_quest_rewards_system,
+                apply_experience_gained.after(handle_damage_dealt),
+                train_armor_skills.after(resolve_damage_events),
             ).run_if(in_state(GameState::InGame)))
+            .add_event::<ProgressionEvent>()
             
             // Quest systems - only in InGame state
             .add_systems(Update, (
@@ -7,6 +7,10 @@ pub struct GameConfig {
     pub graphics: GraphicsConfig,
     pub input: InputConfig,
     pub debug: DebugConfig,
+    #[serde(default)]
+    pub gameplay: GameplayConfig,
+    #[serde(default)]
+    pub combat_feedback: CombatFeedbackConfig,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -29,6 +33,56 @@ pub struct DebugConfig {
     pub wireframe_mode: bool,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct GameplayConfig {
+    pub respawn_delay: f32,            // Seconds spent dead before respawning
+    pub death_experience_penalty: f32, // Fraction of the current level's progress lost on death
+    pub respawn_health_fraction: f32,  // Health restored on respawn (1.0 = full)
+    pub respawn_mana_fraction: f32,    // Mana restored on respawn (1.0 = full)
+    pub corpse_duration: f32,          // Seconds enemy corpses linger before despawning
+    pub dynamic_difficulty: bool,      // Adjust enemy aggression to repeated deaths and easy wins
+}
+
+impl Default for GameplayConfig {
+    fn default() -> Self {
+        Self {
+            respawn_delay: 5.0,
+            death_experience_penalty: 0.1,
+            respawn_health_fraction: 0.5,
+            respawn_mana_fraction: 0.5,
+            corpse_duration: 60.0,
+            dynamic_difficulty: false,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct CombatFeedbackConfig {
+    pub damage_numbers: bool,     // Floating numbers for damage dealt and taken
+    pub healing_numbers: bool,    // Floating numbers for healing
+    pub avoidance_text: bool,     // Floating "Miss", "Dodge" and "Parry"
+    pub status_effect_text: bool, // Floating names of status effects as they land
+    pub hit_flash: bool,          // Enemies flash white when damaged
+    pub hit_stop: bool,           // The game briefly slows when the player lands a hit
+    pub hit_stop_duration: f32,   // Real seconds a hit slows the game for - criticals last twice as long
+}
+
+impl Default for CombatFeedbackConfig {
+    fn default() -> Self {
+        Self {
+            damage_numbers: true,
+            healing_numbers: true,
+            avoidance_text: true,
+            status_effect_text: true,
+            hit_flash: true,
+            hit_stop: true,
+            hit_stop_duration: 0.04,
+        }
+    }
+}
+
 impl Default for GameConfig {
     fn default() -> Self {
         Self {
@@ -46,6 +100,8 @@ impl Default for GameConfig {
                 show_entity_count: false,
                 wireframe_mode: false,
             },
+            gameplay: GameplayConfig::default(),
+            combat_feedback: CombatFeedbackConfig::default(),
         }
     }
 }
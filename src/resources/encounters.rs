@@ -0,0 +1,291 @@
+use bevy::prelude::*;
+use eryndor_config::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::components::AttackPosition;
+use crate::resources::{EnemyDatabase, ProgressionConfig, StatusEffectConfig};
+
+/// Resource containing all boss encounters from encounters.json, keyed by encounter id
+#[derive(Resource, Debug, Clone, Default)]
+pub struct EncounterDatabase {
+    pub encounters: HashMap<String, EncounterDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterDefinition {
+    pub name: String,
+    pub boss: String,       // Enemy id from enemies.json
+    pub position: [f32; 3], // Boss spawn point and centre of the arena
+    pub arena_radius: f32,  // Leaving this circle resets the fight
+    #[serde(default)]
+    pub enrage: Option<EnrageConfig>,
+    pub phases: Vec<EncounterPhase>,
+}
+
+impl EncounterDefinition {
+    pub fn position(&self) -> Vec3 {
+        Vec3::from_array(self.position)
+    }
+
+    /// The furthest phase past the current one whose trigger has been met
+    /// Phases can be skipped if the boss is burst through several thresholds at once
+    pub fn next_phase(&self, current_phase: usize, health_fraction: f32, elapsed_secs: f32) -> Option<usize> {
+        self.phases
+            .iter()
+            .enumerate()
+            .skip(current_phase + 1)
+            .rev()
+            .find(|(_, phase)| phase.trigger.is_some_and(|trigger| trigger.is_met(health_fraction, elapsed_secs)))
+            .map(|(index, _)| index)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct EnrageConfig {
+    pub after_seconds: f32,
+    pub damage_multiplier: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterPhase {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub trigger: Option<PhaseTrigger>, // The opening phase has none
+    #[serde(default)]
+    pub rotation: Vec<BossAbility>,    // Cast in order, looping
+    #[serde(default)]
+    pub adds: Vec<AddSpawn>,           // Spawned when the phase begins
+}
+
+/// What moves an encounter into a phase - `{ "health_below": 0.5 }` or `{ "after_seconds": 90.0 }`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PhaseTrigger {
+    HealthBelow(f32),  // Fraction of the boss's max health
+    AfterSeconds(f32), // Time since the encounter started
+}
+
+impl PhaseTrigger {
+    pub fn is_met(&self, health_fraction: f32, elapsed_secs: f32) -> bool {
+        match self {
+            PhaseTrigger::HealthBelow(threshold) => health_fraction < *threshold,
+            PhaseTrigger::AfterSeconds(seconds) => elapsed_secs >= *seconds,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BossAbility {
+    pub id: String,
+    pub name: String,
+    pub cooldown: f32, // Seconds until the next ability in the rotation
+    #[serde(default)]
+    pub damage: f32,
+    #[serde(default)]
+    pub damage_type: Option<String>, // Damage type id - defaults to the boss's own
+    #[serde(default)]
+    pub radius: f32,                  // 0 hits the boss's target, otherwise everything within the radius
+    #[serde(default)]
+    pub arc: Option<AttackPosition>,  // Radius abilities only hit this side of the boss - front cleaves, rear kicks
+    #[serde(default)]
+    pub status_effect: Option<String>,
+    #[serde(default)]
+    pub adds: Vec<AddSpawn>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddSpawn {
+    pub enemy_id: String,
+    #[serde(default = "default_add_count")]
+    pub count: u32,
+}
+
+fn default_add_count() -> u32 {
+    1
+}
+
+impl EncounterDatabase {
+    /// Load encounter definitions through the shared JSON config loader
+    pub fn load_from_file(
+        path: &str,
+        enemies: &EnemyDatabase,
+        status_effects: &StatusEffectConfig,
+        progression: &ProgressionConfig,
+    ) -> Result<Self, ConfigError> {
+        let encounters: HashMap<String, EncounterDefinition> = JsonConfigLoader::load_from_file(path)?;
+        let database = EncounterDatabase { encounters };
+        database.validate()?;
+        database.validate_references(enemies, status_effects, progression)?;
+        Ok(database)
+    }
+
+    pub fn get(&self, encounter_id: &str) -> Option<&EncounterDefinition> {
+        self.encounters.get(encounter_id)
+    }
+
+    /// Catch encounters that could never progress or would spin on a zero cooldown
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for (encounter_id, encounter) in &self.encounters {
+            let problem = if encounter.phases.is_empty() {
+                Some("needs at least one phase")
+            } else if encounter.phases[0].trigger.is_some() {
+                Some("the first phase can't have a trigger")
+            } else if encounter.phases.iter().skip(1).any(|phase| phase.trigger.is_none()) {
+                Some("every phase after the first needs a trigger")
+            } else if encounter.arena_radius <= 0.0 {
+                Some("arena_radius must be positive")
+            } else if encounter.phases.iter().flat_map(|phase| &phase.rotation).any(|ability| ability.cooldown <= 0.0) {
+                Some("ability cooldowns must be positive")
+            } else if encounter.phases.iter().flat_map(|phase| &phase.rotation).any(|ability| ability.arc.is_some() && ability.radius <= 0.0) {
+                Some("abilities with an arc need a radius")
+            } else {
+                None
+            };
+
+            if let Some(message) = problem {
+                return Err(ConfigError::Validation {
+                    message: format!("encounter '{}': {}", encounter_id, message),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Catch bosses, adds, status effects and damage types that don't exist in their own configs
+    pub fn validate_references(
+        &self,
+        enemies: &EnemyDatabase,
+        status_effects: &StatusEffectConfig,
+        progression: &ProgressionConfig,
+    ) -> Result<(), ConfigError> {
+        for (encounter_id, encounter) in &self.encounters {
+            let abilities = encounter.phases.iter().flat_map(|phase| &phase.rotation);
+            let mut adds = encounter.phases.iter()
+                .flat_map(|phase| &phase.adds)
+                .chain(abilities.clone().flat_map(|ability| &ability.adds));
+
+            let problem = if enemies.get(&encounter.boss).is_none() {
+                Some(format!("unknown boss enemy '{}'", encounter.boss))
+            } else if let Some(add) = adds.find(|add| enemies.get(&add.enemy_id).is_none()) {
+                Some(format!("unknown add enemy '{}'", add.enemy_id))
+            } else if let Some(effect) = abilities.clone().filter_map(|ability| ability.status_effect.as_ref()).find(|effect| status_effects.get_effect(effect).is_none()) {
+                Some(format!("unknown status effect '{}'", effect))
+            } else if let Some(damage_type) = abilities.filter_map(|ability| ability.damage_type.as_ref()).find(|damage_type| !progression.damage_types.contains_key(*damage_type)) {
+                Some(format!("unknown damage type '{}'", damage_type))
+            } else {
+                None
+            };
+
+            if let Some(message) = problem {
+                return Err(ConfigError::Validation {
+                    message: format!("encounter '{}': {}", encounter_id, message),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Load boss encounters from the config directory, checked against the configs they reference
+pub fn load_encounter_database(
+    enemies: &EnemyDatabase,
+    status_effects: &StatusEffectConfig,
+    progression: &ProgressionConfig,
+) -> EncounterDatabase {
+    let config_path = "config/encounters.json";
+
+    match EncounterDatabase::load_from_file(config_path, enemies, status_effects, progression) {
+        Ok(database) => {
+            info!("Successfully loaded {} encounters from {}", database.encounters.len(), config_path);
+            database
+        },
+        Err(e) => {
+            warn!("Failed to load encounter configuration: {}", e);
+            warn!("No boss encounters will be spawned");
+            EncounterDatabase::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phase(id: &str, trigger: Option<PhaseTrigger>) -> EncounterPhase {
+        EncounterPhase { id: id.to_string(), name: id.to_string(), trigger, rotation: Vec::new(), adds: Vec::new() }
+    }
+
+    fn encounter() -> EncounterDefinition {
+        EncounterDefinition {
+            name: "Test".to_string(),
+            boss: "wild_boar".to_string(),
+            position: [0.0; 3],
+            arena_radius: 20.0,
+            enrage: None,
+            phases: vec![
+                phase("one", None),
+                phase("two", Some(PhaseTrigger::HealthBelow(0.7))),
+                phase("three", Some(PhaseTrigger::HealthBelow(0.3))),
+                phase("four", Some(PhaseTrigger::AfterSeconds(120.0))),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_phase_transitions() {
+        let encounter = encounter();
+        assert_eq!(encounter.next_phase(0, 0.9, 10.0), None);
+        assert_eq!(encounter.next_phase(0, 0.6, 10.0), Some(1));
+        // Burst past two thresholds at once
+        assert_eq!(encounter.next_phase(0, 0.2, 10.0), Some(2));
+        assert_eq!(encounter.next_phase(1, 0.6, 130.0), Some(3));
+        assert_eq!(encounter.next_phase(3, 0.1, 130.0), None);
+    }
+
+    #[test]
+    fn test_validation_requires_triggers_after_first_phase() {
+        let mut database = EncounterDatabase::default();
+        database.encounters.insert("test".to_string(), encounter());
+        assert!(database.validate().is_ok());
+
+        database.encounters.get_mut("test").unwrap().phases[2].trigger = None;
+        assert!(database.validate().is_err());
+    }
+
+    #[test]
+    fn test_validation_rejects_unknown_references() {
+        let status_effects = crate::resources::load_status_effect_config();
+        let progression = crate::resources::load_progression_config();
+        let enemies = crate::resources::load_enemy_database(&status_effects, &progression, &crate::resources::load_loot_tables());
+        let mut database = EncounterDatabase::default();
+        database.encounters.insert("test".to_string(), encounter());
+        assert!(database.validate_references(&enemies, &status_effects, &progression).is_ok());
+
+        let mut unknown_add = database.clone();
+        unknown_add.encounters.get_mut("test").unwrap().phases[1].adds.push(AddSpawn { enemy_id: "dragon".to_string(), count: 1 });
+        assert!(unknown_add.validate_references(&enemies, &status_effects, &progression).is_err());
+
+        let ability = BossAbility {
+            id: "slam".to_string(),
+            name: "Slam".to_string(),
+            cooldown: 5.0,
+            damage: 10.0,
+            damage_type: Some("nature".to_string()),
+            radius: 0.0,
+            arc: None,
+            status_effect: Some("stunned".to_string()),
+            adds: Vec::new(),
+        };
+        database.encounters.get_mut("test").unwrap().phases[0].rotation.push(ability);
+        assert!(database.validate_references(&enemies, &status_effects, &progression).is_ok());
+
+        let mut unknown_effect = database.clone();
+        unknown_effect.encounters.get_mut("test").unwrap().phases[0].rotation[0].status_effect = Some("cursed".to_string());
+        assert!(unknown_effect.validate_references(&enemies, &status_effects, &progression).is_err());
+
+        let mut unknown_damage_type = database.clone();
+        unknown_damage_type.encounters.get_mut("test").unwrap().phases[0].rotation[0].damage_type = Some("sonic".to_string());
+        assert!(unknown_damage_type.validate_references(&enemies, &status_effects, &progression).is_err());
+    }
+}
@@ -0,0 +1,376 @@
+use bevy::prelude::*;
+use eryndor_config::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use eryndor_core::components::LevelBand;
+use crate::components::ArmorType;
+use crate::resources::{LootTableDatabase, ProgressionConfig, StatusEffectConfig};
+
+/// Resource containing all enemy definitions from enemies.json, keyed by enemy id
+#[derive(Resource, Debug, Clone, Default)]
+pub struct EnemyDatabase {
+    pub enemies: HashMap<String, EnemyDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemyDefinition {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub level_range: LevelRange,
+    pub stats: EnemyStats, // Stats at the bottom of the level range
+    #[serde(default)]
+    pub scaling: EnemyScaling,
+    #[serde(default)]
+    pub resistances: Vec<String>, // Resistance keys from damage_types.json
+    #[serde(default)]
+    pub abilities: Vec<String>, // Status effect ids the enemy's attacks apply in turn, one per ai.ability_cooldown
+    #[serde(default)]
+    pub model: EnemyModel,
+    #[serde(default)]
+    pub loot_table: Option<String>,
+    pub ai: EnemyAiConfig,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct LevelRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl LevelRange {
+    /// Pick a spawn level within the range
+    pub fn roll(&self, rng: &mut impl Rng) -> u32 {
+        if self.max <= self.min {
+            self.min
+        } else {
+            rng.gen_range(self.min..=self.max)
+        }
+    }
+}
+
+/// Zone and spawn point level bands roll exactly like an enemy's own range
+impl From<LevelBand> for LevelRange {
+    fn from(band: LevelBand) -> Self {
+        Self { min: band.min, max: band.max }
+    }
+}
+
+/// Compounding growth per level above the bottom of the level range
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct EnemyScaling {
+    #[serde(default = "default_health_growth")]
+    pub health_growth: f32,
+    #[serde(default = "default_damage_growth")]
+    pub damage_growth: f32,
+    #[serde(default = "default_experience_growth")]
+    pub experience_growth: f32,
+}
+
+fn default_health_growth() -> f32 { 0.12 }
+fn default_damage_growth() -> f32 { 0.08 }
+fn default_experience_growth() -> f32 { 0.1 }
+
+impl Default for EnemyScaling {
+    fn default() -> Self {
+        Self {
+            health_growth: default_health_growth(),
+            damage_growth: default_damage_growth(),
+            experience_growth: default_experience_growth(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemyStats {
+    pub max_health: f32,
+    pub damage: f32,
+    pub damage_type: String, // Damage type id from damage_types.json
+    pub attack_speed: f32,   // Seconds between melee attacks
+    pub attack_range: f32,
+    pub move_speed: f32,
+    #[serde(default)]
+    pub armor_type: Option<String>, // "heavy", "medium" or "light"
+    pub experience_reward: u64,
+}
+
+impl EnemyStats {
+    pub fn armor_type(&self) -> Option<ArmorType> {
+        match self.armor_type.as_deref()? {
+            "heavy" => Some(ArmorType::Heavy),
+            "medium" => Some(ArmorType::Medium),
+            "light" => Some(ArmorType::Light),
+            _ => None,
+        }
+    }
+
+    /// These stats grown from `base_level` to `level` - levels below the base shrink them instead
+    pub fn scaled_to(&self, level: u32, base_level: u32, scaling: &EnemyScaling) -> Self {
+        let levels = level as i32 - base_level as i32;
+        let grow = |growth: f32| (1.0 + growth).powi(levels);
+
+        Self {
+            max_health: (self.max_health * grow(scaling.health_growth)).round().max(1.0),
+            damage: self.damage * grow(scaling.damage_growth),
+            experience_reward: (self.experience_reward as f32 * grow(scaling.experience_growth)).round() as u64,
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemyModel {
+    #[serde(default)]
+    pub path: Option<String>, // glTF scene under assets/ - falls back to a coloured block
+    pub color: [f32; 3],
+}
+
+impl Default for EnemyModel {
+    fn default() -> Self {
+        Self {
+            path: None,
+            color: [0.8, 0.2, 0.2],
+        }
+    }
+}
+
+impl EnemyModel {
+    pub fn color(&self) -> Color {
+        Color::srgb(self.color[0], self.color[1], self.color[2])
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemyAiConfig {
+    pub aggro_radius: f32,   // Distance at which an idle or patrolling enemy notices the player
+    pub leash_distance: f32, // Maximum distance from spawn before the enemy returns home
+    pub patrol_radius: f32,  // How far from spawn the enemy wanders while patrolling
+    #[serde(default = "default_ability_cooldown")]
+    pub ability_cooldown: f32, // Seconds between attacks that carry one of the enemy's abilities
+}
+
+fn default_ability_cooldown() -> f32 { 10.0 }
+
+impl EnemyDatabase {
+    /// Load enemy definitions through the shared JSON config loader
+    pub fn load_from_file(
+        path: &str,
+        status_effects: &StatusEffectConfig,
+        progression: &ProgressionConfig,
+        loot_tables: &LootTableDatabase,
+    ) -> Result<Self, ConfigError> {
+        let enemies: HashMap<String, EnemyDefinition> = JsonConfigLoader::load_from_file(path)?;
+        let database = EnemyDatabase { enemies };
+        database.validate()?;
+        database.validate_references(status_effects, progression, loot_tables)?;
+        Ok(database)
+    }
+
+    pub fn get(&self, enemy_id: &str) -> Option<&EnemyDefinition> {
+        self.enemies.get(enemy_id)
+    }
+
+    /// Catch obviously broken definitions before they reach the game world
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for (enemy_id, definition) in &self.enemies {
+            let problem = if definition.level_range.min == 0 || definition.level_range.min > definition.level_range.max {
+                Some("level_range must satisfy 1 <= min <= max")
+            } else if definition.stats.max_health <= 0.0 {
+                Some("max_health must be positive")
+            } else if definition.scaling.health_growth < 0.0 || definition.scaling.damage_growth < 0.0 || definition.scaling.experience_growth < 0.0 {
+                Some("scaling growth must not be negative")
+            } else if definition.stats.attack_speed <= 0.0 {
+                Some("attack_speed must be positive")
+            } else if definition.ai.ability_cooldown <= 0.0 {
+                Some("ability_cooldown must be positive")
+            } else if definition.stats.armor_type.is_some() && definition.stats.armor_type().is_none() {
+                Some("armor_type must be heavy, medium or light")
+            } else {
+                None
+            };
+
+            if let Some(message) = problem {
+                return Err(ConfigError::Validation {
+                    message: format!("enemy '{}': {}", enemy_id, message),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Catch abilities, damage types, resistances and loot tables that don't exist in their own configs
+    pub fn validate_references(
+        &self,
+        status_effects: &StatusEffectConfig,
+        progression: &ProgressionConfig,
+        loot_tables: &LootTableDatabase,
+    ) -> Result<(), ConfigError> {
+        for (enemy_id, definition) in &self.enemies {
+            let problem = if let Some(ability) = definition.abilities.iter().find(|ability| status_effects.get_effect(ability).is_none()) {
+                Some(format!("unknown ability '{}'", ability))
+            } else if progression.get_damage_type(&definition.stats.damage_type).is_none() {
+                Some(format!("unknown damage type '{}'", definition.stats.damage_type))
+            } else if let Some(resistance) = definition.resistances.iter().find(|resistance| !progression.is_resistance_key(resistance)) {
+                Some(format!("unknown resistance '{}'", resistance))
+            } else if let Some(table_id) = definition.loot_table.as_ref().filter(|table_id| loot_tables.get(table_id).is_none()) {
+                Some(format!("unknown loot table '{}'", table_id))
+            } else {
+                None
+            };
+
+            if let Some(message) = problem {
+                return Err(ConfigError::Validation {
+                    message: format!("enemy '{}': {}", enemy_id, message),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Load enemy definitions from the config directory
+pub fn load_enemy_database(
+    status_effects: &StatusEffectConfig,
+    progression: &ProgressionConfig,
+    loot_tables: &LootTableDatabase,
+) -> EnemyDatabase {
+    let config_path = "config/enemies.json";
+
+    match EnemyDatabase::load_from_file(config_path, status_effects, progression, loot_tables) {
+        Ok(database) => {
+            info!("Successfully loaded {} enemies from {}", database.enemies.len(), config_path);
+            database
+        },
+        Err(e) => {
+            warn!("Failed to load enemy configuration: {}", e);
+            warn!("Using fallback hardcoded configuration");
+
+            // Minimal fallback so the starting area still has something to fight
+            let mut enemies = HashMap::new();
+            enemies.insert("wild_boar".to_string(), EnemyDefinition {
+                name: "Wild Boar".to_string(),
+                description: "An aggressive boar that charges anything entering its territory".to_string(),
+                level_range: LevelRange { min: 1, max: 3 },
+                stats: EnemyStats {
+                    max_health: 80.0,
+                    damage: 20.0,
+                    damage_type: "piercing".to_string(),
+                    attack_speed: 2.0,
+                    attack_range: 2.0,
+                    move_speed: 5.5,
+                    armor_type: Some("light".to_string()),
+                    experience_reward: 40,
+                },
+                scaling: EnemyScaling::default(),
+                resistances: Vec::new(),
+                abilities: Vec::new(),
+                model: EnemyModel { path: None, color: [0.6, 0.4, 0.2] },
+                loot_table: None,
+                ai: EnemyAiConfig {
+                    aggro_radius: 12.0,
+                    leash_distance: 40.0,
+                    patrol_radius: 12.0,
+                    ability_cooldown: default_ability_cooldown(),
+                },
+            });
+
+            EnemyDatabase { enemies }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_level_range_roll_stays_in_range() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let range = LevelRange { min: 3, max: 5 };
+        for _ in 0..50 {
+            assert!((3..=5).contains(&range.roll(&mut rng)));
+        }
+        assert_eq!(LevelRange { min: 4, max: 4 }.roll(&mut rng), 4);
+    }
+
+    #[test]
+    fn test_stats_scale_with_level() {
+        let status_effects = crate::resources::load_status_effect_config();
+        let progression = crate::resources::load_progression_config();
+        let stats = load_enemy_database(&status_effects, &progression, &crate::resources::load_loot_tables()).get("wild_boar").unwrap().stats.clone();
+        let scaling = EnemyScaling { health_growth: 0.1, damage_growth: 0.1, experience_growth: 0.5 };
+
+        let same = stats.scaled_to(1, 1, &scaling);
+        assert_eq!(same.max_health, stats.max_health);
+        assert_eq!(same.experience_reward, stats.experience_reward);
+
+        let higher = stats.scaled_to(3, 1, &scaling);
+        assert_eq!(higher.max_health, (stats.max_health * 1.21).round());
+        assert!((higher.damage - stats.damage * 1.21).abs() < 0.001);
+        assert_eq!(higher.experience_reward, (stats.experience_reward as f32 * 2.25).round() as u64);
+        assert_eq!(higher.attack_speed, stats.attack_speed);
+
+        assert!(stats.scaled_to(1, 3, &scaling).max_health < stats.max_health);
+    }
+
+    #[test]
+    fn test_validation_rejects_bad_armor() {
+        let definition = EnemyDefinition {
+            name: "Test Wolf".to_string(),
+            description: String::new(),
+            level_range: LevelRange { min: 1, max: 2 },
+            stats: EnemyStats {
+                max_health: 60.0,
+                damage: 8.0,
+                damage_type: "piercing".to_string(),
+                attack_speed: 1.5,
+                attack_range: 2.0,
+                move_speed: 6.0,
+                armor_type: Some("medium".to_string()),
+                experience_reward: 20,
+            },
+            scaling: EnemyScaling::default(),
+            resistances: Vec::new(),
+            abilities: Vec::new(),
+            model: EnemyModel::default(),
+            loot_table: None,
+            ai: EnemyAiConfig { aggro_radius: 10.0, leash_distance: 30.0, patrol_radius: 8.0, ability_cooldown: 10.0 },
+        };
+        let mut database = EnemyDatabase::default();
+        database.enemies.insert("test_wolf".to_string(), definition);
+        assert!(database.validate().is_ok());
+
+        database.enemies.get_mut("test_wolf").unwrap().stats.armor_type = Some("mithril".to_string());
+        assert!(database.validate().is_err());
+    }
+
+    #[test]
+    fn test_validation_rejects_unknown_references() {
+        let status_effects = crate::resources::load_status_effect_config();
+        let progression = crate::resources::load_progression_config();
+        let loot_tables = crate::resources::load_loot_tables();
+        let mut database = load_enemy_database(&status_effects, &progression, &loot_tables);
+        let boar = database.enemies.get_mut("wild_boar").unwrap();
+        boar.abilities = vec!["bleeding".to_string()];
+        boar.resistances = vec!["fire_weakness".to_string()];
+        assert!(database.validate_references(&status_effects, &progression, &loot_tables).is_ok());
+
+        let mut unknown_ability = database.clone();
+        unknown_ability.enemies.get_mut("wild_boar").unwrap().abilities.push("Heavy Strike".to_string());
+        assert!(unknown_ability.validate_references(&status_effects, &progression, &loot_tables).is_err());
+
+        let mut unknown_damage_type = database.clone();
+        unknown_damage_type.enemies.get_mut("wild_boar").unwrap().stats.damage_type = "peircing".to_string();
+        assert!(unknown_damage_type.validate_references(&status_effects, &progression, &loot_tables).is_err());
+
+        let mut unknown_resistance = database.clone();
+        unknown_resistance.enemies.get_mut("wild_boar").unwrap().resistances.push("fire_resistence".to_string());
+        assert!(unknown_resistance.validate_references(&status_effects, &progression, &loot_tables).is_err());
+
+        let mut unknown_loot_table = database;
+        unknown_loot_table.enemies.get_mut("wild_boar").unwrap().loot_table = Some("wild_bore".to_string());
+        assert!(unknown_loot_table.validate_references(&status_effects, &progression, &loot_tables).is_err());
+    }
+}
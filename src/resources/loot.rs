@@ -0,0 +1,323 @@
+use bevy::prelude::*;
+use eryndor_config::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Nested tables deeper than this are skipped - stops a table that references itself from looping forever
+const MAX_LOOT_TABLE_DEPTH: u32 = 8;
+
+/// Resource containing all loot tables from loot_tables.json, keyed by table id
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LootTableDatabase {
+    pub tables: HashMap<String, LootTable>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LootTable {
+    #[serde(default)]
+    pub guaranteed: Vec<LootEntry>, // Always dropped
+    #[serde(default)]
+    pub chance: Vec<LootEntry>,     // Each entry rolled independently against its own chance
+    #[serde(default)]
+    pub rolls: u32,                 // Number of picks from the weighted pool
+    #[serde(default)]
+    pub weighted: Vec<LootEntry>,
+}
+
+/// A single drop - either an item, a nested table, or nothing at all (both left empty)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LootEntry {
+    #[serde(default)]
+    pub item: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,  // Display name - falls back to the item id
+    #[serde(default)]
+    pub table: Option<String>, // Nested table rolled in place of an item
+    #[serde(default = "default_weight")]
+    pub weight: u32,           // Only used in the weighted pool
+    #[serde(default = "default_chance")]
+    pub chance: f32,           // Only used in the chance list
+    #[serde(default)]
+    pub quantity: QuantityRange,
+    #[serde(default)]
+    pub quest: Option<QuestRequirement>,
+}
+
+impl Default for LootEntry {
+    fn default() -> Self {
+        Self {
+            item: None,
+            name: None,
+            table: None,
+            weight: default_weight(),
+            chance: default_chance(),
+            quantity: QuantityRange::default(),
+            quest: None,
+        }
+    }
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+fn default_chance() -> f32 {
+    1.0
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct QuantityRange {
+    pub min: u32,
+    pub max: u32,
+    #[serde(default)]
+    pub per_level: f32, // Extra items per enemy level above 1, rounded down
+}
+
+impl Default for QuantityRange {
+    fn default() -> Self {
+        Self { min: 1, max: 1, per_level: 0.0 }
+    }
+}
+
+impl QuantityRange {
+    pub fn roll(&self, level: u32, rng: &mut impl Rng) -> u32 {
+        let base = if self.max <= self.min {
+            self.min
+        } else {
+            rng.gen_range(self.min..=self.max)
+        };
+        let level_bonus = (self.per_level.max(0.0) * level.saturating_sub(1) as f32) as u32;
+        base + level_bonus
+    }
+}
+
+/// Quest-only drop - only rolled while the player's quest is in this phase
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestRequirement {
+    pub quest_id: String,
+    pub phase: String,
+}
+
+/// An item stack produced by rolling a loot table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LootDrop {
+    pub item_id: String,
+    pub name: String,
+    pub quantity: u32,
+}
+
+impl LootTableDatabase {
+    /// Load loot tables through the shared JSON config loader
+    pub fn load_from_file(path: &str) -> Result<Self, ConfigError> {
+        let tables: HashMap<String, LootTable> = JsonConfigLoader::load_from_file(path)?;
+        let database = LootTableDatabase { tables };
+        database.validate()?;
+        Ok(database)
+    }
+
+    pub fn get(&self, table_id: &str) -> Option<&LootTable> {
+        self.tables.get(table_id)
+    }
+
+    /// Catch broken entries and dangling nested table references
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for (table_id, table) in &self.tables {
+            let entries = table.guaranteed.iter().chain(&table.chance).chain(&table.weighted);
+            for entry in entries {
+                let problem = if entry.item.is_some() && entry.table.is_some() {
+                    Some("an entry can't have both an item and a table".to_string())
+                } else if entry.quantity.min > entry.quantity.max {
+                    Some("quantity min must not exceed max".to_string())
+                } else if !(0.0..=1.0).contains(&entry.chance) {
+                    Some("chance must be between 0 and 1".to_string())
+                } else if let Some(nested) = entry.table.as_ref().filter(|nested| !self.tables.contains_key(*nested)) {
+                    Some(format!("unknown nested table '{}'", nested))
+                } else {
+                    None
+                };
+
+                if let Some(message) = problem {
+                    return Err(ConfigError::Validation {
+                        message: format!("loot table '{}': {}", table_id, message),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Roll a table for an enemy of the given level; stacks of the same item are merged
+    pub fn roll(
+        &self,
+        table_id: &str,
+        level: u32,
+        quest_active: impl Fn(&QuestRequirement) -> bool,
+        rng: &mut impl Rng,
+    ) -> Vec<LootDrop> {
+        let mut drops = Vec::new();
+        self.roll_table(table_id, level, &quest_active, rng, 0, &mut drops);
+        drops
+    }
+
+    fn roll_table<R: Rng>(
+        &self,
+        table_id: &str,
+        level: u32,
+        quest_active: &dyn Fn(&QuestRequirement) -> bool,
+        rng: &mut R,
+        depth: u32,
+        drops: &mut Vec<LootDrop>,
+    ) {
+        if depth > MAX_LOOT_TABLE_DEPTH {
+            warn!("Loot table '{}' nested too deeply - skipping", table_id);
+            return;
+        }
+        let Some(table) = self.tables.get(table_id) else {
+            warn!("Unknown loot table '{}'", table_id);
+            return;
+        };
+
+        let available = |entry: &&LootEntry| entry.quest.as_ref().is_none_or(|quest| quest_active(quest));
+
+        for entry in table.guaranteed.iter().filter(available) {
+            self.roll_entry(entry, level, quest_active, rng, depth, drops);
+        }
+
+        for entry in table.chance.iter().filter(available) {
+            if rng.gen::<f32>() < entry.chance {
+                self.roll_entry(entry, level, quest_active, rng, depth, drops);
+            }
+        }
+
+        let pool: Vec<&LootEntry> = table.weighted.iter().filter(available).collect();
+        let total_weight: u32 = pool.iter().map(|entry| entry.weight).sum();
+        if total_weight == 0 {
+            return;
+        }
+        for _ in 0..table.rolls {
+            let mut roll = rng.gen_range(0..total_weight);
+            for entry in &pool {
+                if roll < entry.weight {
+                    self.roll_entry(entry, level, quest_active, rng, depth, drops);
+                    break;
+                }
+                roll -= entry.weight;
+            }
+        }
+    }
+
+    fn roll_entry<R: Rng>(
+        &self,
+        entry: &LootEntry,
+        level: u32,
+        quest_active: &dyn Fn(&QuestRequirement) -> bool,
+        rng: &mut R,
+        depth: u32,
+        drops: &mut Vec<LootDrop>,
+    ) {
+        if let Some(nested) = &entry.table {
+            self.roll_table(nested, level, quest_active, rng, depth + 1, drops);
+            return;
+        }
+        let Some(item_id) = &entry.item else {
+            return; // "Nothing" entry
+        };
+
+        let quantity = entry.quantity.roll(level, rng);
+        if quantity == 0 {
+            return;
+        }
+
+        match drops.iter_mut().find(|drop| &drop.item_id == item_id) {
+            Some(drop) => drop.quantity += quantity,
+            None => drops.push(LootDrop {
+                item_id: item_id.clone(),
+                name: entry.name.clone().unwrap_or_else(|| item_id.clone()),
+                quantity,
+            }),
+        }
+    }
+}
+
+/// Load loot tables from the config directory
+pub fn load_loot_tables() -> LootTableDatabase {
+    let config_path = "config/loot_tables.json";
+
+    match LootTableDatabase::load_from_file(config_path) {
+        Ok(database) => {
+            info!("Successfully loaded {} loot tables from {}", database.tables.len(), config_path);
+            database
+        },
+        Err(e) => {
+            warn!("Failed to load loot table configuration: {}", e);
+            warn!("Enemies will not drop loot");
+            LootTableDatabase::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn item(item_id: &str, quantity: QuantityRange) -> LootEntry {
+        LootEntry { item: Some(item_id.to_string()), quantity, ..Default::default() }
+    }
+
+    fn database() -> LootTableDatabase {
+        let mut tables = HashMap::new();
+        tables.insert("gems".to_string(), LootTable {
+            rolls: 1,
+            weighted: vec![item("ruby", QuantityRange::default())],
+            ..Default::default()
+        });
+        tables.insert("boar".to_string(), LootTable {
+            guaranteed: vec![
+                item("boar_hide", QuantityRange { min: 1, max: 1, per_level: 0.5 }),
+                LootEntry { table: Some("gems".to_string()), ..Default::default() },
+            ],
+            chance: vec![LootEntry {
+                quest: Some(QuestRequirement { quest_id: "hunt".to_string(), phase: "tusks".to_string() }),
+                ..item("boar_tusk", QuantityRange::default())
+            }],
+            ..Default::default()
+        });
+        LootTableDatabase { tables }
+    }
+
+    #[test]
+    fn test_guaranteed_and_nested_drops_scale_with_level() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let drops = database().roll("boar", 5, |_| false, &mut rng);
+
+        assert_eq!(drops.len(), 2);
+        assert_eq!(drops[0], LootDrop { item_id: "boar_hide".to_string(), name: "boar_hide".to_string(), quantity: 3 });
+        assert_eq!(drops[1].item_id, "ruby");
+    }
+
+    #[test]
+    fn test_quest_drops_need_active_phase() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let database = database();
+
+        let without_quest = database.roll("boar", 1, |_| false, &mut rng);
+        assert!(without_quest.iter().all(|drop| drop.item_id != "boar_tusk"));
+
+        let with_quest = database.roll("boar", 1, |quest| quest.phase == "tusks", &mut rng);
+        assert!(with_quest.iter().any(|drop| drop.item_id == "boar_tusk"));
+    }
+
+    #[test]
+    fn test_validation_rejects_unknown_nested_table() {
+        let mut database = database();
+        assert!(database.validate().is_ok());
+
+        database.tables.get_mut("boar").unwrap().weighted.push(LootEntry {
+            table: Some("missing".to_string()),
+            ..Default::default()
+        });
+        assert!(database.validate().is_err());
+    }
+}
@@ -2,8 +2,16 @@ pub mod config;
 pub mod input;
 pub mod progression_config;
 pub mod debug_config;
+pub mod status_effects;
+pub mod enemies;
+pub mod loot;
+pub mod encounters;
 
 pub use config::*;
 pub use input::*;
 pub use progression_config::*;
-pub use debug_config::*;
\ No newline at end of file
+pub use debug_config::*;
+pub use status_effects::*;
+pub use enemies::*;
+pub use loot::*;
+pub use encounters::*;
\ No newline at end of file
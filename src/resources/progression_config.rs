@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use crate::components::AttackPosition;
 
 /// Main resource containing all progression configuration data
 #[derive(Resource, Debug, Clone)]
@@ -11,6 +12,7 @@ pub struct ProgressionConfig {
     pub weapons: HashMap<String, WeaponConfig>,
     pub damage_types: HashMap<String, DamageTypeConfig>,
     pub roles: HashMap<String, RoleConfig>,
+    pub armor_types: HashMap<String, ArmorTypeConfig>, // Keyed by the damage_types.json resistance key
     pub character_progression: CharacterProgressionConfig,
     pub skill_progression: SkillProgressionConfig,
 }
@@ -24,6 +26,8 @@ pub struct SkillConfig {
     pub role_categories: Vec<String>,
     pub max_level: u32,
     pub abilities: HashMap<u32, Vec<AbilityUnlock>>, // Level -> Abilities
+    #[serde(default)]
+    pub experience_curve: Option<ExperienceCurveOverride>, // Replaces parts of the shared skill curve
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,7 +35,74 @@ pub struct AbilityUnlock {
     pub name: String,
     pub ability_type: String, // "Active", "Passive", "Quest", "Trainer"
     pub description: String,
-    pub requirements: Vec<String>,
+    pub requirements: Vec<String>, // Not enforced yet - abilities unlock from skill level alone
+    #[serde(default)]
+    pub cooldown: f32, // Seconds - 0 means only the global cooldown applies
+    #[serde(default)]
+    pub range: f32, // Max distance to target - 0 means self-cast, no target needed
+    #[serde(default)]
+    pub damage_multiplier: f32, // Damage = weapon base_damage x this x skill-level scaling - 0 means no damage
+    #[serde(default)]
+    pub damage_type: Option<String>, // Damage type ID - defaults to the equipped weapon's type
+    #[serde(default)]
+    pub healing: f32, // Flat healing applied to the friendly target, or the caster if there is none
+    #[serde(default)]
+    pub taunt: bool,
+    #[serde(default)]
+    pub duration: f32, // Seconds the ability's effect lasts (taunts, buffs)
+    #[serde(default)]
+    pub off_global_cooldown: bool,
+    #[serde(default)]
+    pub status_effect: Option<String>, // Status effect ID applied to the target (or caster for self-cast)
+    #[serde(default)]
+    pub dispels: Vec<String>, // Dispel categories removed from the friendly target, or the caster if there is none
+    #[serde(default)]
+    pub projectile_speed: f32, // Spells that travel to their target - 0 hits instantly
+    #[serde(default)]
+    pub mana_cost: f32,
+    #[serde(default)]
+    pub cast_time: f32, // Seconds standing still before the spell goes off - 0 is instant
+    #[serde(default)]
+    pub channel_time: f32, // Seconds the spell is channelled, delivered in even ticks
+    #[serde(default)]
+    pub channel_ticks: u32, // How many ticks a channel is split into (at least 1)
+    #[serde(default)]
+    pub requires_parry: bool, // Only usable for a few seconds after parrying an attack (Riposte)
+    #[serde(default)]
+    pub requires_position: Option<AttackPosition>, // Side of the target the caster must stand on (Backstab: rear)
+    #[serde(default)]
+    pub combo: Option<ComboDefinition>, // Follow-ups this ability opens up when used
+    #[serde(default)]
+    pub requires_combo: bool, // Only usable as the follow-up of another ability's combo
+}
+
+/// Combo window opened by an ability - the listed follow-ups are enabled or empowered until it closes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComboDefinition {
+    pub window: f32, // Seconds the follow-ups stay open after the opener is used
+    pub follow_ups: Vec<ComboFollowUp>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComboFollowUp {
+    pub ability: String,
+    #[serde(default = "default_multiplier")]
+    pub damage_multiplier: f32, // Damage and healing multiplier when used inside the window
+}
+
+fn default_multiplier() -> f32 {
+    1.0
+}
+
+impl AbilityUnlock {
+    pub fn is_active(&self) -> bool {
+        self.ability_type == "Active"
+    }
+
+    /// Harmful abilities need a hostile target - everything else lands on a friendly target or the caster
+    pub fn is_harmful(&self) -> bool {
+        self.damage_multiplier > 0.0 || self.taunt
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,6 +128,15 @@ pub struct DamageTypeConfig {
     pub color_hex: String, // For UI display
     pub resistances: HashMap<String, f32>, // Armor type -> resistance value
     pub special_effects: Vec<String>,
+    #[serde(default)]
+    pub special_effect_chances: HashMap<String, f32>, // Special effect -> proc chance per hit
+}
+
+impl DamageTypeConfig {
+    /// Display colour from color_hex - white if the hex can't be parsed
+    pub fn color(&self) -> Color {
+        Srgba::hex(&self.color_hex).map(Color::from).unwrap_or(Color::WHITE)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,22 +151,133 @@ pub struct RoleConfig {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CharacterProgressionConfig {
+pub struct ArmorTypeConfig {
+    pub display_name: String,
+    pub skill: String, // Armor skill trained by taking hits while wearing it
+    pub armor_value: f32, // Multiplier on the armor's resistances in damage_types.json at skill 1 (1.0 = as listed)
+    #[serde(default)]
+    pub armor_value_per_skill_level: f32, // Added for every skill level past 1
+    #[serde(default = "default_multiplier")]
+    pub stamina_regeneration_multiplier: f32,
+    #[serde(default = "default_multiplier")]
+    pub sprint_stamina_multiplier: f32, // Multiplier on stamina drained while running
+    #[serde(default = "default_multiplier")]
+    pub max_mana_multiplier: f32,
+}
+
+impl ArmorTypeConfig {
+    /// Armor value at the given armor skill level - an untrained wearer gets the base value
+    pub fn armor_value_at(&self, skill_level: u32) -> f32 {
+        self.armor_value + self.armor_value_per_skill_level * skill_level.saturating_sub(1) as f32
+    }
+}
+
+/// Total experience needed to reach each level - a formula, or an explicit table of thresholds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperienceCurve {
+    #[serde(alias = "max_character_level", alias = "max_skill_level")]
     pub max_level: u32,
     pub base_experience: f32,
     pub level_multiplier: f32,
     pub level_exponent: f32,
+    #[serde(default)]
+    pub experience_table: Vec<u64>, // Total experience for levels 1, 2, 3... - replaces the formula when set
+}
+
+impl ExperienceCurve {
+    /// The character curve used when progression.json can't be loaded
+    pub fn character_default() -> Self {
+        Self {
+            max_level: 50,
+            base_experience: 200.0,
+            level_multiplier: 100.0,
+            level_exponent: 2.0,
+            experience_table: Vec::new(),
+        }
+    }
+
+    /// The skill curve used when progression.json can't be loaded
+    pub fn skill_default() -> Self {
+        Self {
+            max_level: 50,
+            base_experience: 25.0,
+            level_multiplier: 50.0,
+            level_exponent: 1.8,
+            experience_table: Vec::new(),
+        }
+    }
+
+    /// Total experience required to reach a level - level 1 is free
+    pub fn experience_for_level(&self, level: u32) -> u64 {
+        if level <= 1 {
+            return 0;
+        }
+
+        if !self.experience_table.is_empty() {
+            // Levels past the end of the table can't be reached
+            return self.experience_table.get(level as usize - 1).copied().unwrap_or(u64::MAX);
+        }
+
+        let level_f = level as f32;
+        (level_f.powf(self.level_exponent) * self.level_multiplier +
+         level_f * self.base_experience) as u64
+    }
+
+    /// Highest reachable level - a table can end before max_level
+    pub fn level_cap(&self) -> u32 {
+        if self.experience_table.is_empty() {
+            self.max_level
+        } else {
+            self.max_level.min(self.experience_table.len() as u32)
+        }
+    }
+}
+
+/// Per-skill changes to the shared skill curve - anything left out keeps the shared value
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExperienceCurveOverride {
+    #[serde(default)]
+    pub base_experience: Option<f32>,
+    #[serde(default)]
+    pub level_multiplier: Option<f32>,
+    #[serde(default)]
+    pub level_exponent: Option<f32>,
+    #[serde(default)]
+    pub experience_table: Option<Vec<u64>>,
+}
+
+impl ExperienceCurveOverride {
+    pub fn apply_to(&self, curve: &ExperienceCurve) -> ExperienceCurve {
+        ExperienceCurve {
+            max_level: curve.max_level,
+            base_experience: self.base_experience.unwrap_or(curve.base_experience),
+            level_multiplier: self.level_multiplier.unwrap_or(curve.level_multiplier),
+            level_exponent: self.level_exponent.unwrap_or(curve.level_exponent),
+            experience_table: self.experience_table.clone().unwrap_or_else(|| curve.experience_table.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterProgressionConfig {
+    #[serde(flatten)]
+    pub experience_curve: ExperienceCurve,
     pub experience_sources: HashMap<String, f32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillProgressionConfig {
-    pub max_level: u32,
-    pub base_experience: f32,
-    pub level_multiplier: f32,
-    pub level_exponent: f32,
-    pub rested_bonus_multiplier: f32,
-    pub rested_bonus_duration: f32,
+    #[serde(flatten)]
+    pub experience_curve: ExperienceCurve,
+    pub rested_bonus: RestedBonusConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestedBonusConfig {
+    pub multiplier: f32,
+    pub duration_seconds: f32,
+    #[serde(default)]
+    pub sources: Vec<String>, // Places that grant the bonus when resting
 }
 
 impl ProgressionConfig {
@@ -122,6 +313,13 @@ impl ProgressionConfig {
         let roles: HashMap<String, RoleConfig> = serde_json::from_str(&roles_content)
             .map_err(|e| format!("Failed to parse roles.json: {}", e))?;
 
+        // Load armor types configuration
+        let armor_types_path = config_path.join("armor_types.json");
+        let armor_types_content = fs::read_to_string(&armor_types_path)
+            .map_err(|e| format!("Failed to read armor_types.json: {}", e))?;
+        let armor_types: HashMap<String, ArmorTypeConfig> = serde_json::from_str(&armor_types_content)
+            .map_err(|e| format!("Failed to parse armor_types.json: {}", e))?;
+
         // Load progression configuration
         let progression_path = config_path.join("progression.json");
         let progression_content = fs::read_to_string(&progression_path)
@@ -141,6 +339,7 @@ impl ProgressionConfig {
             weapons,
             damage_types,
             roles,
+            armor_types,
             character_progression: progression_file.character_progression,
             skill_progression: progression_file.skill_progression,
         })
@@ -148,28 +347,28 @@ impl ProgressionConfig {
 
     /// Calculate experience required for a character level
     pub fn character_experience_for_level(&self, level: u32) -> u64 {
-        if level <= 1 {
-            return 0;
-        }
-        
-        let config = &self.character_progression;
-        let level_f = level as f32;
-        
-        (level_f.powf(config.level_exponent) * config.level_multiplier + 
-         level_f * config.base_experience) as u64
+        self.character_progression.experience_curve.experience_for_level(level)
     }
 
     /// Calculate experience required for a skill level
-    pub fn skill_experience_for_level(&self, _skill_id: &str, level: u32) -> u64 {
-        if level <= 1 {
-            return 0;
-        }
-        
-        let config = &self.skill_progression;
-        let level_f = level as f32;
-        
-        (level_f.powf(config.level_exponent) * config.level_multiplier + 
-         level_f * config.base_experience) as u64
+    pub fn skill_experience_for_level(&self, skill_id: &str, level: u32) -> u64 {
+        self.skill_curve(skill_id).experience_for_level(level)
+    }
+
+    /// The skill curve with the skill's own overrides applied - capped at the lower of the two max levels
+    pub fn skill_curve(&self, skill_id: &str) -> ExperienceCurve {
+        let shared = &self.skill_progression.experience_curve;
+        let Some(skill) = self.skills.get(skill_id) else {
+            return shared.clone();
+        };
+
+        let mut curve = skill
+            .experience_curve
+            .as_ref()
+            .map(|curve_override| curve_override.apply_to(shared))
+            .unwrap_or_else(|| shared.clone());
+        curve.max_level = curve.max_level.min(skill.max_level);
+        curve
     }
 
     /// Get skill configuration by ID
@@ -187,6 +386,11 @@ impl ProgressionConfig {
         self.damage_types.get(damage_type_id)
     }
 
+    /// Whether any damage type lists this resistance key
+    pub fn is_resistance_key(&self, key: &str) -> bool {
+        self.damage_types.values().any(|damage_type| damage_type.resistances.contains_key(key))
+    }
+
     /// Get role configuration by ID
     pub fn get_role(&self, role_id: &str) -> Option<&RoleConfig> {
         self.roles.get(role_id)
@@ -207,6 +411,18 @@ impl ProgressionConfig {
         }
     }
 
+    /// Find an ability by name - returns the owning skill ID and unlock level
+    pub fn find_ability(&self, ability_name: &str) -> Option<(&str, u32, &AbilityUnlock)> {
+        self.skills.iter().find_map(|(skill_id, skill)| {
+            skill.abilities.iter().find_map(|(level, abilities)| {
+                abilities
+                    .iter()
+                    .find(|ability| ability.name == ability_name)
+                    .map(|ability| (skill_id.as_str(), *level, ability))
+            })
+        })
+    }
+
     /// Get all weapons associated with a skill
     pub fn get_weapons_for_skill(&self, skill_id: &str) -> Vec<&WeaponConfig> {
         self.weapons
@@ -267,6 +483,7 @@ pub fn load_progression_config() -> ProgressionConfig {
                 role_categories: vec!["tank".to_string(), "dps".to_string()],
                 max_level: 50,
                 abilities: HashMap::new(),
+                experience_curve: None,
             });
 
             let mut weapons = HashMap::new();
@@ -292,6 +509,7 @@ pub fn load_progression_config() -> ProgressionConfig {
                 color_hex: "#FF4444".to_string(),
                 resistances: HashMap::new(),
                 special_effects: Vec::new(),
+                special_effect_chances: HashMap::new(),
             });
 
             let mut roles = HashMap::new();
@@ -310,22 +528,73 @@ pub fn load_progression_config() -> ProgressionConfig {
                 weapons,
                 damage_types,
                 roles,
+                armor_types: HashMap::new(),
                 character_progression: CharacterProgressionConfig {
-                    max_level: 50,
-                    base_experience: 200.0,
-                    level_multiplier: 100.0,
-                    level_exponent: 2.0,
+                    experience_curve: ExperienceCurve::character_default(),
                     experience_sources: HashMap::new(),
                 },
                 skill_progression: SkillProgressionConfig {
-                    max_level: 50,
-                    base_experience: 25.0,
-                    level_multiplier: 50.0,
-                    level_exponent: 1.8,
-                    rested_bonus_multiplier: 1.5,
-                    rested_bonus_duration: 300.0,
+                    experience_curve: ExperienceCurve::skill_default(),
+                    rested_bonus: RestedBonusConfig {
+                        multiplier: 1.5,
+                        duration_seconds: 300.0,
+                        sources: Vec::new(),
+                    },
                 },
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_experience_curve_formula_and_table() {
+        let formula = ExperienceCurve::character_default();
+        assert_eq!(formula.experience_for_level(1), 0);
+        assert_eq!(formula.experience_for_level(2), 800);
+        assert_eq!(formula.level_cap(), 50);
+
+        let table = ExperienceCurve { experience_table: vec![0, 100, 250, 500], ..formula };
+        assert_eq!(table.experience_for_level(3), 250);
+        assert_eq!(table.experience_for_level(5), u64::MAX);
+        assert_eq!(table.level_cap(), 4);
+    }
+
+    #[test]
+    fn test_skill_overrides_replace_parts_of_the_shared_curve() {
+        let mut config = load_progression_config();
+        let shared = config.skill_progression.experience_curve.clone();
+        let skill = config.skills.get_mut("swordsmanship").unwrap();
+        skill.max_level = 20;
+        skill.experience_curve = Some(serde_json::from_str(r#"{ "level_multiplier": 80.0 }"#).unwrap());
+
+        let curve = config.skill_curve("swordsmanship");
+        assert_eq!(curve.level_multiplier, 80.0);
+        assert_eq!(curve.level_exponent, shared.level_exponent);
+        assert_eq!(curve.level_cap(), 20.min(shared.max_level));
+        assert!(config.skill_experience_for_level("swordsmanship", 10) > shared.experience_for_level(10));
+
+        // Unknown skills fall back to the shared curve
+        assert_eq!(config.skill_experience_for_level("basket_weaving", 10), shared.experience_for_level(10));
+    }
+
+    #[test]
+    fn test_armor_value_grows_from_the_listed_resistances() {
+        let config = load_progression_config();
+        for armor in config.armor_types.values() {
+            assert_eq!(armor.armor_value_at(1), 1.0);
+            assert!(armor.armor_value_at(50) > 1.0);
+        }
+    }
+
+    #[test]
+    fn test_progression_json_level_caps_load_through_aliases() {
+        let config = ProgressionConfig::load_from_directory("config").unwrap();
+        assert_eq!(config.character_progression.experience_curve.max_level, 50);
+        assert_eq!(config.skill_progression.experience_curve.max_level, 50);
+        assert_eq!(config.skill_progression.rested_bonus.multiplier, 1.5);
+    }
+}
@@ -0,0 +1,170 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Resource containing all status effect definitions from status_effects.json
+#[derive(Resource, Debug, Clone, Default)]
+pub struct StatusEffectConfig {
+    pub effects: HashMap<String, StatusEffectDefinition>,
+}
+
+/// How re-applying an effect that is already active behaves
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum StackingRule {
+    /// Reset the duration, keep a single stack
+    #[default]
+    Refresh,
+    /// Add a stack (up to max_stacks) and reset the duration
+    Stack,
+    /// Keep the existing effect untouched
+    Ignore,
+}
+
+fn default_max_stacks() -> u32 {
+    1
+}
+
+fn default_movement_multiplier() -> f32 {
+    1.0
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusEffectDefinition {
+    pub display_name: String,
+    pub description: String,
+    pub dispel_category: String, // "magic", "physical", "poison", "disease", "curse"
+    #[serde(default)]
+    pub triggered_by: Vec<String>, // Special effects from damage_types.json that apply this effect
+    pub duration: f32,
+    #[serde(default)]
+    pub tick_interval: f32, // Seconds between ticks - 0 means the effect never ticks
+    #[serde(default)]
+    pub damage_per_tick: f32, // Per stack
+    #[serde(default)]
+    pub damage_type: Option<String>,
+    #[serde(default)]
+    pub healing_per_tick: f32, // Per stack
+    #[serde(default = "default_movement_multiplier")]
+    pub movement_multiplier: f32, // 0.5 = slowed by half, 0.0 = rooted
+    #[serde(default)]
+    pub stuns: bool, // Prevents movement and actions
+    #[serde(default)]
+    pub dodge_bonus: f32, // Added to dodge chance (0.1 = +10%)
+    #[serde(default)]
+    pub parry_bonus: f32, // Added to parry chance while holding a melee weapon
+    #[serde(default)]
+    pub block_bonus: f32, // Added to block chance while holding a shield
+    #[serde(default)]
+    pub stacking: StackingRule,
+    #[serde(default = "default_max_stacks")]
+    pub max_stacks: u32,
+    #[serde(default)]
+    pub immunity_duration: f32, // Immunity window after the effect ends (diminishing returns)
+    #[serde(default)]
+    pub beneficial: bool,
+}
+
+impl StatusEffectConfig {
+    /// Load status effect definitions from a JSON file
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(path.as_ref())
+            .map_err(|e| format!("Failed to read status_effects.json: {}", e))?;
+        let effects: HashMap<String, StatusEffectDefinition> = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse status_effects.json: {}", e))?;
+
+        Ok(StatusEffectConfig { effects })
+    }
+
+    pub fn get_effect(&self, effect_id: &str) -> Option<&StatusEffectDefinition> {
+        self.effects.get(effect_id)
+    }
+
+    /// Find the status effect triggered by a damage type special effect (e.g. "bleeding_chance")
+    /// If several effects share a trigger the first by id wins, so the result never depends on map order
+    pub fn effect_for_special(&self, special_effect: &str) -> Option<&str> {
+        self.effects
+            .iter()
+            .filter(|(_, effect)| effect.triggered_by.iter().any(|trigger| trigger == special_effect))
+            .map(|(effect_id, _)| effect_id.as_str())
+            .min()
+    }
+}
+
+/// Load status effect configuration from the config directory
+pub fn load_status_effect_config() -> StatusEffectConfig {
+    let config_path = "config/status_effects.json";
+
+    match StatusEffectConfig::load_from_file(config_path) {
+        Ok(config) => {
+            info!("Successfully loaded {} status effects from {}", config.effects.len(), config_path);
+            config
+        },
+        Err(e) => {
+            warn!("Failed to load status effect configuration: {}", e);
+            warn!("Using fallback hardcoded configuration");
+
+            // Minimal fallback so stuns and bleeds still work
+            let mut effects = HashMap::new();
+            effects.insert("bleeding".to_string(), StatusEffectDefinition {
+                display_name: "Bleeding".to_string(),
+                description: "Open wounds deal damage over time".to_string(),
+                dispel_category: "physical".to_string(),
+                triggered_by: vec!["bleeding_chance".to_string()],
+                duration: 6.0,
+                tick_interval: 1.0,
+                damage_per_tick: 3.0,
+                damage_type: Some("slashing".to_string()),
+                healing_per_tick: 0.0,
+                movement_multiplier: 1.0,
+                stuns: false,
+                dodge_bonus: 0.0,
+                parry_bonus: 0.0,
+                block_bonus: 0.0,
+                stacking: StackingRule::Stack,
+                max_stacks: 5,
+                immunity_duration: 0.0,
+                beneficial: false,
+            });
+            effects.insert("stunned".to_string(), StatusEffectDefinition {
+                display_name: "Stunned".to_string(),
+                description: "Unable to move or act".to_string(),
+                dispel_category: "physical".to_string(),
+                triggered_by: vec!["stun_chance".to_string()],
+                duration: 2.0,
+                tick_interval: 0.0,
+                damage_per_tick: 0.0,
+                damage_type: None,
+                healing_per_tick: 0.0,
+                movement_multiplier: 1.0,
+                stuns: true,
+                dodge_bonus: 0.0,
+                parry_bonus: 0.0,
+                block_bonus: 0.0,
+                stacking: StackingRule::Ignore,
+                max_stacks: 1,
+                immunity_duration: 8.0,
+                beneficial: false,
+            });
+
+            StatusEffectConfig { effects }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_triggers_pick_the_first_effect_by_id() {
+        let mut config = load_status_effect_config();
+        assert_eq!(config.effect_for_special("stun_chance"), Some("stunned"));
+
+        let dazed = config.get_effect("stunned").unwrap().clone();
+        config.effects.insert("dazed".to_string(), dazed);
+        assert_eq!(config.effect_for_special("stun_chance"), Some("dazed"));
+        assert_eq!(config.effect_for_special("unknown_effect"), None);
+    }
+}
@@ -0,0 +1,776 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+use crate::components::{Player, CharacterLoadouts, CharacterSkills, Loadout, DamageType, ActiveDialogue, ProjectileFlight, AttackPosition};
+use crate::resources::{ProgressionConfig, AbilityUnlock};
+use crate::systems::combat::{CombatState, CombatConfig, SKILL_DAMAGE_PER_LEVEL};
+use crate::systems::damage::{DamageEvent, HealEvent, DamageSource};
+use crate::systems::threat::TauntEvent;
+use crate::systems::status_effects::{StatusEffects, ApplyStatusEffect, DispelEvent};
+use crate::systems::death::Dead;
+use crate::systems::projectiles::{FireProjectile, ProjectileKind};
+use crate::systems::line_of_sight::{LineOfSight, is_facing, attack_position};
+use crate::systems::targeting::{Targetables, TargetDisposition};
+use crate::systems::spellcasting::{Casting, cast_time_multiplier};
+use crate::systems::vitals::Mana;
+use crate::systems::attack_table::{AttackTables, AttackOutcome, RiposteWindow};
+use crate::systems::combos::{ComboWindow, combo_multiplier};
+use crate::systems::ingame_ui::AbilitySlot;
+use crate::systems::quest_journal_ui::QuestJournalState;
+
+/// Global cooldown triggered by most abilities
+pub const GLOBAL_COOLDOWN: f32 = 1.5;
+
+/// Hotbar keys in slot order
+const HOTBAR_KEYS: [KeyCode; 4] = [KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4];
+
+// Ability Components
+#[derive(Debug, Clone, Copy)]
+pub struct Cooldown {
+    pub remaining: f32,
+    pub duration: f32,
+}
+
+impl Cooldown {
+    pub fn new(duration: f32) -> Self {
+        Self { remaining: duration, duration }
+    }
+
+    /// Fraction of the cooldown still remaining (1.0 = just started)
+    pub fn fraction_remaining(&self) -> f32 {
+        if self.duration <= 0.0 { 0.0 } else { (self.remaining / self.duration).clamp(0.0, 1.0) }
+    }
+}
+
+/// Per-ability cooldowns plus the shared global cooldown
+#[derive(Component, Debug, Default)]
+pub struct AbilityCooldowns {
+    pub cooldowns: HashMap<String, Cooldown>,
+    pub global_cooldown: Option<Cooldown>,
+}
+
+impl AbilityCooldowns {
+    pub fn tick(&mut self, delta_secs: f32) {
+        for cooldown in self.cooldowns.values_mut() {
+            cooldown.remaining -= delta_secs;
+        }
+        self.cooldowns.retain(|_, cooldown| cooldown.remaining > 0.0);
+
+        if let Some(gcd) = self.global_cooldown.as_mut() {
+            gcd.remaining -= delta_secs;
+            if gcd.remaining <= 0.0 {
+                self.global_cooldown = None;
+            }
+        }
+    }
+
+    pub fn remaining(&self, ability_name: &str) -> f32 {
+        self.cooldowns.get(ability_name).map(|c| c.remaining).unwrap_or(0.0)
+    }
+
+    pub fn global_remaining(&self) -> f32 {
+        self.global_cooldown.map(|c| c.remaining).unwrap_or(0.0)
+    }
+
+    /// Start the ability's cooldown and the GCD if it's on the global cooldown
+    pub fn trigger(&mut self, ability: &AbilityUnlock) {
+        self.start_cooldown(ability);
+        self.start_global_cooldown(ability);
+    }
+
+    /// Start only the ability's own cooldown - cast-time spells do this when the cast completes
+    pub fn start_cooldown(&mut self, ability: &AbilityUnlock) {
+        if ability.cooldown > 0.0 {
+            self.cooldowns.insert(ability.name.clone(), Cooldown::new(ability.cooldown));
+        }
+    }
+
+    pub fn start_global_cooldown(&mut self, ability: &AbilityUnlock) {
+        if !ability.off_global_cooldown {
+            self.global_cooldown = Some(Cooldown::new(GLOBAL_COOLDOWN));
+        }
+    }
+
+    /// Cooldown to display on a hotbar slot - whichever of the ability cooldown or GCD is longer
+    pub fn display_cooldown(&self, ability: &AbilityUnlock) -> Option<Cooldown> {
+        let own = self.cooldowns.get(&ability.name).copied();
+        let gcd = if ability.off_global_cooldown { None } else { self.global_cooldown };
+
+        match (own, gcd) {
+            (Some(own), Some(gcd)) => Some(if own.remaining >= gcd.remaining { own } else { gcd }),
+            (own, gcd) => own.or(gcd),
+        }
+    }
+}
+
+/// Why an ability couldn't be used
+#[derive(Debug, Clone, PartialEq)]
+pub enum AbilityError {
+    EmptySlot,
+    Dead,
+    Incapacitated,
+    AlreadyCasting,
+    UnknownAbility(String),
+    Passive,
+    NotUnlocked { skill_id: String, required_level: u32 },
+    OnCooldown(f32),
+    GlobalCooldown(f32),
+    NoTarget,
+    TargetDead,
+    InvalidTarget,
+    OutOfRange { distance: f32, range: f32 },
+    OutOfSight,
+    NotFacing,
+    NotEnoughMana { cost: f32, current: f32 },
+    RequiresParry,
+    RequiresCombo,
+    WrongPosition(AttackPosition),
+}
+
+impl fmt::Display for AbilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbilityError::EmptySlot => write!(f, "No ability in that slot"),
+            AbilityError::Dead => write!(f, "You can't do that while dead"),
+            AbilityError::Incapacitated => write!(f, "You can't do that while stunned"),
+            AbilityError::AlreadyCasting => write!(f, "You are already casting"),
+            AbilityError::UnknownAbility(name) => write!(f, "Unknown ability '{}'", name),
+            AbilityError::Passive => write!(f, "Passive abilities can't be activated"),
+            AbilityError::NotUnlocked { skill_id, required_level } => write!(f, "Requires {} level {}", skill_id, required_level),
+            AbilityError::OnCooldown(remaining) => write!(f, "Ability not ready ({:.1}s)", remaining),
+            AbilityError::GlobalCooldown(remaining) => write!(f, "Global cooldown ({:.1}s)", remaining),
+            AbilityError::NoTarget => write!(f, "You have no target"),
+            AbilityError::TargetDead => write!(f, "Your target is dead"),
+            AbilityError::InvalidTarget => write!(f, "You can't use that on a friendly target"),
+            AbilityError::OutOfRange { distance, range } => write!(f, "Out of range ({:.1}/{:.1})", distance, range),
+            AbilityError::OutOfSight => write!(f, "Target is out of sight"),
+            AbilityError::NotFacing => write!(f, "You must be facing your target"),
+            AbilityError::NotEnoughMana { cost, current } => write!(f, "Not enough mana ({:.0}/{:.0})", current, cost),
+            AbilityError::RequiresParry => write!(f, "You can only do that after a parry"),
+            AbilityError::RequiresCombo => write!(f, "You can only do that as a combo follow-up"),
+            AbilityError::WrongPosition(AttackPosition::Front) => write!(f, "You must be in front of your target"),
+            AbilityError::WrongPosition(AttackPosition::Flank) => write!(f, "You must be beside or behind your target"),
+            AbilityError::WrongPosition(AttackPosition::Rear) => write!(f, "You must be behind your target"),
+        }
+    }
+}
+
+/// Ability successfully activated from the hotbar
+#[derive(Event, Debug, Clone)]
+pub struct AbilityUsed {
+    pub caster: Entity,
+    pub ability_name: String,
+    pub skill_id: String,
+    pub slot_index: usize,
+    pub target: Option<Entity>,
+    pub potency: f32, // Multiplier on the ability's damage and healing - combos raise it, channels split it across ticks
+    pub applies_effects: bool, // Taunt, status effect and dispels - channels only apply them on their first tick
+}
+
+/// Ability activation rejected
+#[derive(Event, Debug, Clone)]
+pub struct AbilityFailed {
+    pub caster: Entity,
+    pub slot_index: usize,
+    pub error: AbilityError,
+}
+
+/// Target information needed to validate an ability
+#[derive(Debug, Clone, Copy)]
+pub struct AbilityTargetInfo {
+    pub distance: f32,
+    pub is_alive: bool,
+    pub in_line_of_sight: bool,
+    pub is_facing: bool,
+    pub is_hostile: bool,
+    pub position: AttackPosition, // Side of the target the caster stands on
+}
+
+/// The entity a targeted ability lands on - harmful abilities need the current target,
+/// beneficial ones fall back to the caster unless something friendly is targeted
+pub fn ability_target_entity(
+    ability: &AbilityUnlock,
+    target: Option<(Entity, TargetDisposition)>,
+    caster: Entity,
+) -> Option<Entity> {
+    if ability.range <= 0.0 {
+        return None;
+    }
+    if ability.is_harmful() {
+        return target.map(|(entity, _)| entity);
+    }
+
+    match target {
+        Some((entity, disposition)) if !disposition.is_hostile() => Some(entity),
+        _ => Some(caster),
+    }
+}
+
+/// Check whether an ability can be used right now
+pub fn validate_ability_use(
+    ability: &AbilityUnlock,
+    skill_id: &str,
+    unlock_level: u32,
+    skill_level: u32,
+    cooldowns: &AbilityCooldowns,
+    target: Option<AbilityTargetInfo>,
+) -> Result<(), AbilityError> {
+    if !ability.is_active() {
+        return Err(AbilityError::Passive);
+    }
+
+    if skill_level < unlock_level {
+        return Err(AbilityError::NotUnlocked { skill_id: skill_id.to_string(), required_level: unlock_level });
+    }
+
+    let remaining = cooldowns.remaining(&ability.name);
+    if remaining > 0.0 {
+        return Err(AbilityError::OnCooldown(remaining));
+    }
+
+    if !ability.off_global_cooldown && cooldowns.global_remaining() > 0.0 {
+        return Err(AbilityError::GlobalCooldown(cooldowns.global_remaining()));
+    }
+
+    // Self-cast abilities don't need a target
+    if ability.range > 0.0 {
+        let target = target.ok_or(AbilityError::NoTarget)?;
+        if !target.is_alive {
+            return Err(AbilityError::TargetDead);
+        }
+        if ability.is_harmful() && !target.is_hostile {
+            return Err(AbilityError::InvalidTarget);
+        }
+        if target.distance > ability.range {
+            return Err(AbilityError::OutOfRange { distance: target.distance, range: ability.range });
+        }
+        if !target.in_line_of_sight {
+            return Err(AbilityError::OutOfSight);
+        }
+        if !target.is_facing {
+            return Err(AbilityError::NotFacing);
+        }
+        if let Some(required) = ability.requires_position {
+            if !target.position.satisfies(required) {
+                return Err(AbilityError::WrongPosition(required));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Look up the ability in a hotbar slot, pick its target and check it can be used
+fn resolve_hotbar_ability<'a>(
+    slot_index: usize,
+    config: &'a ProgressionConfig,
+    loadouts: &CharacterLoadouts,
+    skills: &CharacterSkills,
+    cooldowns: &AbilityCooldowns,
+    target_for: impl Fn(&AbilityUnlock) -> Option<(Entity, AbilityTargetInfo)>,
+) -> Result<(&'a str, &'a AbilityUnlock, Option<Entity>), AbilityError> {
+    let ability_name = loadouts
+        .active_loadout()
+        .and_then(|loadout| loadout.active_abilities.get(slot_index))
+        .ok_or(AbilityError::EmptySlot)?;
+
+    let (skill_id, unlock_level, ability) = config
+        .find_ability(ability_name)
+        .ok_or_else(|| AbilityError::UnknownAbility(ability_name.clone()))?;
+
+    let skill_level = config
+        .skill_id_to_enum(skill_id)
+        .map(|skill| skills.get_skill_level(skill))
+        .unwrap_or(0);
+
+    let target = target_for(ability);
+    validate_ability_use(ability, skill_id, unlock_level, skill_level, cooldowns, target.map(|(_, info)| info))?;
+    Ok((skill_id, ability, target.map(|(entity, _)| entity)))
+}
+
+// System to tick ability cooldowns
+pub fn tick_ability_cooldowns(
+    time: Res<Time>,
+    mut cooldown_query: Query<&mut AbilityCooldowns>,
+) {
+    for mut cooldowns in &mut cooldown_query {
+        cooldowns.tick(time.delta_secs());
+    }
+}
+
+// System to activate hotbar abilities with the 1-4 keys
+#[allow(clippy::too_many_arguments)]
+pub fn handle_hotbar_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    active_dialogue: Res<ActiveDialogue>,
+    journal_state: Res<QuestJournalState>,
+    config: Res<ProgressionConfig>,
+    combat_state: Res<CombatState>,
+    mut player_query: Query<(Entity, &Transform, &CharacterLoadouts, &CharacterSkills, &mut AbilityCooldowns, Option<&mut Mana>, Option<&StatusEffects>, Option<&ComboWindow>, Has<Casting>, Has<RiposteWindow>, Has<Dead>), With<Player>>,
+    target_query: Query<&Transform>,
+    targetables: Targetables,
+    line_of_sight: LineOfSight,
+    mut ability_used_writer: EventWriter<AbilityUsed>,
+    mut ability_failed_writer: EventWriter<AbilityFailed>,
+) {
+    // Number keys belong to dialogue choices and journal tabs while those are open
+    if active_dialogue.npc_entity.is_some() || journal_state.is_visible {
+        return;
+    }
+
+    let Some(slot_index) = HOTBAR_KEYS.iter().position(|key| keyboard.just_pressed(*key)) else {
+        return;
+    };
+
+    let Ok((player_entity, player_transform, loadouts, skills, mut cooldowns, mut mana, status_effects, combo_window, is_casting, can_riposte, is_dead)) = player_query.single_mut() else {
+        return;
+    };
+
+    let incapacitated = if is_dead {
+        Some(AbilityError::Dead)
+    } else if status_effects.is_some_and(|effects| effects.is_stunned()) {
+        Some(AbilityError::Incapacitated)
+    } else if is_casting {
+        Some(AbilityError::AlreadyCasting)
+    } else {
+        None
+    };
+
+    if let Some(error) = incapacitated {
+        info!("🚫 {}", error);
+        ability_failed_writer.write(AbilityFailed {
+            caster: player_entity,
+            slot_index,
+            error,
+        });
+        return;
+    }
+
+    let current_target = combat_state
+        .player_target
+        .and_then(|target| targetables.disposition(target).map(|disposition| (target, disposition)));
+
+    let target_for = |ability: &AbilityUnlock| {
+        let target = ability_target_entity(ability, current_target, player_entity)?;
+        let transform = target_query.get(target).ok()?;
+        Some((target, AbilityTargetInfo {
+            distance: player_transform.translation.distance(transform.translation),
+            is_alive: targetables.is_alive(target),
+            in_line_of_sight: line_of_sight.between(player_entity, target),
+            is_facing: is_facing(player_transform, transform.translation),
+            is_hostile: targetables.disposition(target).is_some_and(|disposition| disposition.is_hostile()),
+            position: attack_position(transform, player_transform.translation),
+        }))
+    };
+
+    let result = resolve_hotbar_ability(slot_index, &config, loadouts, skills, &cooldowns, target_for)
+        .and_then(|(skill_id, ability, target)| match mana.as_deref() {
+            Some(mana) if mana.current < ability.mana_cost => {
+                Err(AbilityError::NotEnoughMana { cost: ability.mana_cost, current: mana.current })
+            }
+            _ if ability.requires_parry && !can_riposte => Err(AbilityError::RequiresParry),
+            _ if ability.requires_combo && combo_multiplier(combo_window, &ability.name).is_none() => Err(AbilityError::RequiresCombo),
+            _ => Ok((skill_id, ability, target)),
+        });
+
+    match result {
+        Ok((skill_id, ability, target)) => {
+            let cast_time_multiplier = cast_time_multiplier(Some(loadouts), skill_id, &config);
+            let potency = combo_multiplier(combo_window, &ability.name).unwrap_or(1.0);
+
+            let Some(mut casting) = Casting::begin(ability, skill_id, slot_index, target, player_transform.translation, cast_time_multiplier) else {
+                if let Some(mana) = mana.as_deref_mut() {
+                    mana.spend(ability.mana_cost);
+                }
+                cooldowns.trigger(ability);
+                if ability.requires_parry {
+                    commands.entity(player_entity).remove::<RiposteWindow>();
+                }
+                info!("✨ Used {}", ability.name);
+
+                ability_used_writer.write(AbilityUsed {
+                    caster: player_entity,
+                    ability_name: ability.name.clone(),
+                    skill_id: skill_id.to_string(),
+                    slot_index,
+                    target,
+                    potency,
+                    applies_effects: true,
+                });
+                return;
+            };
+            casting.potency = potency;
+
+            // Channels pay and go on cooldown up front, casts only when they complete
+            if casting.is_channel() {
+                if let Some(mana) = mana.as_deref_mut() {
+                    mana.spend(ability.mana_cost);
+                }
+                cooldowns.trigger(ability);
+                info!("🌀 Channelling {}", ability.name);
+            } else {
+                cooldowns.start_global_cooldown(ability);
+                info!("🌀 Casting {} ({:.1}s)", ability.name, casting.timer.duration().as_secs_f32());
+            }
+            commands.entity(player_entity).insert(casting);
+        }
+        Err(error) => {
+            info!("🚫 {}", error);
+            ability_failed_writer.write(AbilityFailed {
+                caster: player_entity,
+                slot_index,
+                error,
+            });
+        }
+    }
+}
+
+/// Damage for one use of an ability - the equipped weapon's damage from weapons.json times the
+/// ability's damage_multiplier, growing with the ability's skill like auto-attack swings do
+pub fn ability_damage(
+    ability: &AbilityUnlock,
+    skill_id: &str,
+    loadout: Option<&Loadout>,
+    skills: Option<&CharacterSkills>,
+    config: &ProgressionConfig,
+    combat_config: &CombatConfig,
+) -> f32 {
+    let weapon_damage = loadout
+        .and_then(|loadout| config.get_weapon_config_by_enum(loadout.primary_weapon))
+        .map_or(combat_config.base_player_damage, |weapon_config| weapon_config.base_damage);
+    let skill_level = config
+        .skill_id_to_enum(skill_id)
+        .zip(skills)
+        .map_or(1, |(skill, skills)| skills.get_skill_level(skill));
+
+    weapon_damage * ability.damage_multiplier * (1.0 + skill_level as f32 * SKILL_DAMAGE_PER_LEVEL)
+}
+
+// System to apply the effects of used abilities
+#[allow(clippy::too_many_arguments)]
+pub fn execute_abilities(
+    mut ability_used_events: EventReader<AbilityUsed>,
+    config: Res<ProgressionConfig>,
+    combat_config: Res<CombatConfig>,
+    caster_query: Query<(Option<&CharacterLoadouts>, Option<&CharacterSkills>)>,
+    attack_tables: AttackTables,
+    mut damage_writer: EventWriter<DamageEvent>,
+    mut heal_writer: EventWriter<HealEvent>,
+    mut taunt_writer: EventWriter<TauntEvent>,
+    mut status_writer: EventWriter<ApplyStatusEffect>,
+    mut dispel_writer: EventWriter<DispelEvent>,
+    mut projectile_writer: EventWriter<FireProjectile>,
+) {
+    let mut rng = thread_rng();
+
+    for event in ability_used_events.read() {
+        let Some((_, _, ability)) = config.find_ability(&event.ability_name) else {
+            continue;
+        };
+
+        let (loadouts, skills) = caster_query.get(event.caster).unwrap_or((None, None));
+        let loadout = loadouts.and_then(|l| l.active_loadout());
+
+        if let Some(target) = event.target {
+            if ability.damage_multiplier > 0.0 {
+                // Abilities with an explicit damage type (spells) override the weapon's type
+                let damage_type = ability.damage_type
+                    .as_deref()
+                    .and_then(|id| config.damage_type_id_to_enum(id))
+                    .or_else(|| loadout.map(|l| l.primary_weapon.damage_type()))
+                    .unwrap_or(DamageType::Bludgeoning);
+
+                // Weapon abilities roll the attack table like a swing - spells always land, from any side
+                let (outcome, position) = if ability.damage_type.is_none() {
+                    let outcome = attack_tables.roll(event.caster, target, loadout.map(|l| l.primary_weapon), &mut rng);
+                    (outcome, Some(attack_tables.position(event.caster, target)))
+                } else {
+                    (AttackOutcome::Hit, None)
+                };
+
+                let payload = DamageEvent {
+                    attacker: Some(event.caster),
+                    target,
+                    damage_type,
+                    amount: ability_damage(ability, &event.skill_id, loadout, skills, &config, &combat_config) * event.potency,
+                    source: DamageSource::Ability(ability.name.clone()),
+                    outcome,
+                    position,
+                    status_effect: ability.status_effect.clone().filter(|_| event.applies_effects),
+                };
+
+                // Travelling spells, and abilities of an equipped bow or thrown weapon, fire a projectile
+                let ranged_weapon = loadout
+                    .map(|l| l.primary_weapon)
+                    .filter(|weapon| config.skill_enum_to_id(weapon.associated_skill()) == event.skill_id);
+                let projectile = if ability.projectile_speed > 0.0 {
+                    Some((ProjectileFlight { speed: ability.projectile_speed, gravity: 0.0 }, ProjectileKind::Spell))
+                } else {
+                    ranged_weapon.and_then(|weapon| {
+                        let kind = if weapon.is_thrown() { ProjectileKind::Thrown } else { ProjectileKind::Arrow };
+                        weapon.projectile_flight().map(|flight| (flight, kind))
+                    })
+                };
+
+                match projectile {
+                    Some((flight, kind)) => {
+                        projectile_writer.write(FireProjectile { payload, flight, kind });
+                    }
+                    None => {
+                        damage_writer.write(payload);
+                    }
+                }
+            }
+
+            if ability.taunt && event.applies_effects {
+                let mut taunt = TauntEvent::new(event.caster, target);
+                if ability.duration > 0.0 {
+                    taunt.duration = ability.duration;
+                }
+                taunt_writer.write(taunt);
+            }
+        }
+
+        // Effects on damaging abilities ride on the hit and only apply if it lands - the rest apply straight away
+        let applied_on_hit = event.target.is_some() && ability.damage_multiplier > 0.0;
+        if let Some(effect_id) = ability.status_effect.as_ref().filter(|_| event.applies_effects && !applied_on_hit) {
+            status_writer.write(ApplyStatusEffect {
+                target: event.target.unwrap_or(event.caster),
+                effect_id: effect_id.clone(),
+                source: Some(event.caster),
+            });
+        }
+
+        // Heals and cleanses land on the friendly target - harmful abilities only ever help the caster
+        let beneficial_target = if ability.is_harmful() { event.caster } else { event.target.unwrap_or(event.caster) };
+
+        if !ability.dispels.is_empty() && event.applies_effects {
+            dispel_writer.write(DispelEvent {
+                target: beneficial_target,
+                categories: ability.dispels.clone(),
+            });
+        }
+
+        if ability.healing > 0.0 {
+            heal_writer.write(HealEvent {
+                healer: Some(event.caster),
+                target: beneficial_target,
+                amount: ability.healing * event.potency,
+                source: DamageSource::Ability(ability.name.clone()),
+            });
+        }
+    }
+}
+
+// Hotbar UI Components
+#[derive(Component)]
+pub struct CooldownOverlay {
+    pub slot_index: usize,
+}
+
+#[derive(Component)]
+pub struct AbilitySlotLabel {
+    pub slot_index: usize,
+}
+
+// System to show ability names and cooldown sweeps on the hotbar
+pub fn update_hotbar_ui(
+    config: Res<ProgressionConfig>,
+    player_query: Query<(&CharacterLoadouts, &AbilityCooldowns, Option<&ComboWindow>), With<Player>>,
+    mut overlay_query: Query<(&CooldownOverlay, &mut Node)>,
+    mut label_query: Query<(&AbilitySlotLabel, &mut Text)>,
+    mut slot_query: Query<(&AbilitySlot, &mut BorderColor)>,
+) {
+    let Ok((loadouts, cooldowns, combo_window)) = player_query.single() else {
+        return;
+    };
+
+    let abilities: Vec<Option<&AbilityUnlock>> = (0..HOTBAR_KEYS.len())
+        .map(|slot| {
+            loadouts
+                .active_loadout()
+                .and_then(|loadout| loadout.active_abilities.get(slot))
+                .and_then(|name| config.find_ability(name))
+                .map(|(_, _, ability)| ability)
+        })
+        .collect();
+
+    // Cooldown sweep shrinks from the top of the slot as the cooldown expires
+    for (overlay, mut node) in &mut overlay_query {
+        let fraction = abilities
+            .get(overlay.slot_index)
+            .copied()
+            .flatten()
+            .and_then(|ability| cooldowns.display_cooldown(ability))
+            .map(|cooldown| cooldown.fraction_remaining())
+            .unwrap_or(0.0);
+        node.height = Val::Percent(fraction * 100.0);
+    }
+
+    for (label, mut text) in &mut label_query {
+        let name = abilities
+            .get(label.slot_index)
+            .copied()
+            .flatten()
+            .map(|ability| ability.name.as_str())
+            .unwrap_or("");
+        let new_text = format!("{}\n{}", label.slot_index + 1, name);
+        if **text != new_text {
+            **text = new_text;
+        }
+    }
+
+    // Highlight slots that are ready to use, and combo follow-ups brighter still
+    for (slot, mut border) in &mut slot_query {
+        let ability = abilities.get(slot.slot_index).copied().flatten();
+        let follow_up = ability.is_some_and(|ability| combo_multiplier(combo_window, &ability.name).is_some());
+        let ready = ability.is_some_and(|ability| {
+            cooldowns.display_cooldown(ability).is_none() && (!ability.requires_combo || follow_up)
+        });
+
+        border.0 = match (ready, follow_up) {
+            (true, true) => Color::srgb(1.0, 0.45, 0.1),
+            (true, false) => Color::srgb(0.8, 0.7, 0.3),
+            (false, _) => Color::srgb(0.4, 0.4, 0.4),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ability(cooldown: f32, range: f32) -> AbilityUnlock {
+        AbilityUnlock {
+            name: "Heavy Strike".to_string(),
+            ability_type: "Active".to_string(),
+            description: String::new(),
+            requirements: Vec::new(),
+            cooldown,
+            range,
+            damage_multiplier: 1.5,
+            damage_type: None,
+            healing: 0.0,
+            taunt: false,
+            duration: 0.0,
+            off_global_cooldown: false,
+            status_effect: None,
+            dispels: Vec::new(),
+            projectile_speed: 0.0,
+            mana_cost: 0.0,
+            cast_time: 0.0,
+            channel_time: 0.0,
+            channel_ticks: 0,
+            requires_parry: false,
+            requires_position: None,
+            combo: None,
+            requires_combo: false,
+        }
+    }
+
+    #[test]
+    fn test_cooldown_and_global_cooldown() {
+        let ability = ability(4.0, 0.0);
+        let mut cooldowns = AbilityCooldowns::default();
+
+        assert!(validate_ability_use(&ability, "swordsmanship", 1, 1, &cooldowns, None).is_ok());
+
+        cooldowns.trigger(&ability);
+        assert!(matches!(validate_ability_use(&ability, "swordsmanship", 1, 1, &cooldowns, None), Err(AbilityError::OnCooldown(_))));
+
+        // GCD expires before the ability cooldown
+        cooldowns.tick(GLOBAL_COOLDOWN);
+        assert_eq!(cooldowns.global_remaining(), 0.0);
+        assert!(cooldowns.remaining("Heavy Strike") > 0.0);
+
+        cooldowns.tick(4.0);
+        assert!(validate_ability_use(&ability, "swordsmanship", 1, 1, &cooldowns, None).is_ok());
+    }
+
+    #[test]
+    fn test_range_and_unlock_checks() {
+        let ability = ability(0.0, 3.0);
+        let cooldowns = AbilityCooldowns::default();
+        let far_target = AbilityTargetInfo { distance: 10.0, is_alive: true, in_line_of_sight: true, is_facing: true, is_hostile: true, position: AttackPosition::Front };
+
+        assert_eq!(validate_ability_use(&ability, "swordsmanship", 1, 1, &cooldowns, None), Err(AbilityError::NoTarget));
+        assert!(matches!(
+            validate_ability_use(&ability, "swordsmanship", 1, 1, &cooldowns, Some(far_target)),
+            Err(AbilityError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            validate_ability_use(&ability, "swordsmanship", 5, 1, &cooldowns, None),
+            Err(AbilityError::NotUnlocked { required_level: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn test_sight_and_facing_checks() {
+        let ability = ability(0.0, 20.0);
+        let cooldowns = AbilityCooldowns::default();
+        let visible = AbilityTargetInfo { distance: 10.0, is_alive: true, in_line_of_sight: true, is_facing: true, is_hostile: true, position: AttackPosition::Front };
+
+        assert!(validate_ability_use(&ability, "fire_magic", 1, 1, &cooldowns, Some(visible)).is_ok());
+        assert_eq!(
+            validate_ability_use(&ability, "fire_magic", 1, 1, &cooldowns, Some(AbilityTargetInfo { in_line_of_sight: false, ..visible })),
+            Err(AbilityError::OutOfSight)
+        );
+        assert_eq!(
+            validate_ability_use(&ability, "fire_magic", 1, 1, &cooldowns, Some(AbilityTargetInfo { is_facing: false, ..visible })),
+            Err(AbilityError::NotFacing)
+        );
+
+        let backstab = AbilityUnlock { requires_position: Some(AttackPosition::Rear), range: 3.0, ..ability.clone() };
+        let behind = AbilityTargetInfo { distance: 2.0, position: AttackPosition::Rear, ..visible };
+        assert!(validate_ability_use(&backstab, "dagger_mastery", 1, 1, &cooldowns, Some(behind)).is_ok());
+        assert_eq!(
+            validate_ability_use(&backstab, "dagger_mastery", 1, 1, &cooldowns, Some(AbilityTargetInfo { position: AttackPosition::Flank, ..behind })),
+            Err(AbilityError::WrongPosition(AttackPosition::Rear))
+        );
+    }
+
+    #[test]
+    fn test_ability_damage_scales_with_weapon_and_skill() {
+        let config = crate::resources::load_progression_config();
+        let combat_config = CombatConfig::default();
+        let strike = ability(0.0, 3.0);
+        let loadouts = CharacterLoadouts::default();
+        let loadout = loadouts.active_loadout();
+        let mut skills = CharacterSkills::default();
+
+        let sword_damage = config.get_weapon_config_by_enum(loadout.unwrap().primary_weapon).unwrap().base_damage;
+        let novice = ability_damage(&strike, "swordsmanship", loadout, Some(&skills), &config, &combat_config);
+        assert_eq!(novice, sword_damage * 1.5 * (1.0 + SKILL_DAMAGE_PER_LEVEL));
+
+        skills.get_skill_line_mut(crate::components::SkillType::Swordsmanship).unwrap().level = 20;
+        assert!(ability_damage(&strike, "swordsmanship", loadout, Some(&skills), &config, &combat_config) > novice);
+
+        // Nothing equipped falls back to the base player damage
+        let unarmed = ability_damage(&strike, "swordsmanship", None, None, &config, &combat_config);
+        assert_eq!(unarmed, combat_config.base_player_damage * 1.5 * (1.0 + SKILL_DAMAGE_PER_LEVEL));
+    }
+
+    #[test]
+    fn test_beneficial_abilities_fall_back_to_the_caster() {
+        let caster = Entity::from_raw(1);
+        let enemy = Entity::from_raw(2);
+        let npc = Entity::from_raw(3);
+
+        let strike = ability(0.0, 3.0);
+        let heal = AbilityUnlock { damage_multiplier: 0.0, healing: 30.0, ..ability(0.0, 30.0) };
+
+        assert_eq!(ability_target_entity(&strike, Some((enemy, TargetDisposition::Hostile)), caster), Some(enemy));
+        assert_eq!(ability_target_entity(&strike, None, caster), None);
+        assert_eq!(ability_target_entity(&heal, Some((npc, TargetDisposition::Friendly)), caster), Some(npc));
+        assert_eq!(ability_target_entity(&heal, Some((enemy, TargetDisposition::Hostile)), caster), Some(caster));
+        assert_eq!(ability_target_entity(&heal, None, caster), Some(caster));
+
+        let friendly = AbilityTargetInfo { distance: 2.0, is_alive: true, in_line_of_sight: true, is_facing: true, is_hostile: false, position: AttackPosition::Front };
+        assert_eq!(
+            validate_ability_use(&strike, "swordsmanship", 1, 1, &AbilityCooldowns::default(), Some(friendly)),
+            Err(AbilityError::InvalidTarget)
+        );
+    }
+}
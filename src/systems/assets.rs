@@ -118,6 +118,13 @@ pub fn spawn_player_when_assets_loaded(
             crate::components::CharacterLevel::default(),
             crate::components::CharacterSkills::default(),
             crate::components::CharacterLoadouts::default(),
+            // Combat
+            crate::systems::combat::Health::new(100.0),
+            crate::systems::vitals::Mana::new(50.0),
+            crate::systems::vitals::Stamina::new(80.0),
+            crate::systems::vitals::VitalsRegen::default(),
+            crate::systems::abilities::AbilityCooldowns::default(),
+            crate::systems::status_effects::StatusEffects::default(),
             // Animation and model
             crate::components::AnimationController::default(),
             crate::components::CharacterModel::default(),
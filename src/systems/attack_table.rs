@@ -0,0 +1,374 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use rand::prelude::*;
+use serde::Serialize;
+use crate::components::{Player, CharacterLevel, CharacterLoadouts, CharacterSkills, ArmorType, AttackPosition, SkillType, WeaponType};
+use crate::resources::ProgressionConfig;
+use crate::systems::combat::Enemy;
+use crate::systems::damage::{DamageResistances, AttackAvoided};
+use crate::systems::line_of_sight::attack_position;
+use crate::systems::status_effects::StatusEffects;
+
+/// Critical hits deal double damage
+pub const CRITICAL_DAMAGE_MULTIPLIER: f32 = 2.0;
+/// Share of a blocked hit that the shield soaks up
+pub const BLOCK_DAMAGE_REDUCTION: f32 = 0.5;
+
+/// Chance for an even-level attack to miss outright
+const BASE_MISS_CHANCE: f32 = 0.05;
+/// Miss chance removed per level of the attacker's weapon skill
+const MISS_REDUCTION_PER_SKILL_LEVEL: f32 = 0.001;
+/// Each level the defender is above the attacker adds this to miss and dodge (and removes it from crit)
+const AVOIDANCE_PER_LEVEL_DIFFERENCE: f32 = 0.01;
+const MAX_MISS_CHANCE: f32 = 0.6;
+/// Parry chance for anyone holding a melee weapon, plus a little per weapon skill level
+const BASE_PARRY_CHANCE: f32 = 0.05;
+const PARRY_PER_SKILL_LEVEL: f32 = 0.001;
+/// Block chance with a shield, plus more per Shield Defense level
+const BASE_BLOCK_CHANCE: f32 = 0.1;
+const BLOCK_PER_SKILL_LEVEL: f32 = 0.002;
+/// Extra damage for weapon attacks landing on a target's side or back
+const FLANK_DAMAGE_BONUS: f32 = 0.15;
+const REAR_DAMAGE_BONUS: f32 = 0.25;
+/// Attacks from behind are easier to land cleanly
+const REAR_CRITICAL_BONUS: f32 = 0.1;
+/// Enemies have no weapon stats of their own
+const ENEMY_CRITICAL_CHANCE: f32 = 0.05;
+/// Seconds after parrying that Riposte can be used
+const RIPOSTE_WINDOW: f32 = 5.0;
+
+/// Where an attack landed on the attack table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttackOutcome {
+    #[default]
+    Hit,
+    Critical,
+    Miss,
+    Dodge,
+    Parry,
+    Block,
+}
+
+impl AttackOutcome {
+    /// Misses, dodges and parries deal no damage at all
+    pub fn is_avoided(&self) -> bool {
+        matches!(self, AttackOutcome::Miss | AttackOutcome::Dodge | AttackOutcome::Parry)
+    }
+
+    pub fn is_critical(&self) -> bool {
+        matches!(self, AttackOutcome::Critical)
+    }
+}
+
+/// Chances for each outcome of a single weapon attack
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AttackTable {
+    pub miss: f32,
+    pub dodge: f32,
+    pub parry: f32,
+    pub block: f32,
+    pub critical: f32,
+}
+
+impl AttackTable {
+    /// One roll in [0, 1) walks the table in order - miss, dodge, parry, block, crit, then hit
+    /// Once the earlier entries fill the table, the later ones get pushed off it
+    pub fn outcome(&self, roll: f32) -> AttackOutcome {
+        let entries = [
+            (self.miss, AttackOutcome::Miss),
+            (self.dodge, AttackOutcome::Dodge),
+            (self.parry, AttackOutcome::Parry),
+            (self.block, AttackOutcome::Block),
+            (self.critical, AttackOutcome::Critical),
+        ];
+
+        let mut ceiling = 0.0;
+        for (chance, outcome) in entries {
+            ceiling += chance.max(0.0);
+            if roll < ceiling {
+                return outcome;
+            }
+        }
+        AttackOutcome::Hit
+    }
+
+    pub fn roll(&self, rng: &mut impl Rng) -> AttackOutcome {
+        self.outcome(rng.gen())
+    }
+}
+
+/// The attacking side of an attack table
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttackerProfile {
+    pub level: u32,
+    pub weapon_skill: u32,
+    pub critical_chance: f32,
+    /// Ranged attacks can't be parried
+    pub ranged: bool,
+}
+
+/// The defending side of an attack table
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DefenderProfile {
+    pub level: u32,
+    pub armor_type: Option<ArmorType>,
+    /// Skill level of the melee weapon held - None can't parry
+    pub parry_skill: Option<u32>,
+    /// Shield Defense level with a shield equipped - None can't block
+    pub block_skill: Option<u32>,
+    /// Bonuses from status effects (Parry, Block)
+    pub dodge_bonus: f32,
+    pub parry_bonus: f32,
+    pub block_bonus: f32,
+    /// Stunned defenders can't avoid anything
+    pub incapacitated: bool,
+    /// Shields and weapons only turn aside attacks from the front
+    pub attacked_from: AttackPosition,
+}
+
+/// Lighter armour is easier to move in
+fn base_dodge_chance(armor_type: Option<ArmorType>) -> f32 {
+    match armor_type {
+        Some(ArmorType::Light) => 0.08,
+        Some(ArmorType::Medium) => 0.05,
+        Some(ArmorType::Heavy) => 0.02,
+        None => 0.04,
+    }
+}
+
+/// Damage multiplier for a weapon attack from the given side of its target
+pub fn positional_damage_multiplier(position: AttackPosition) -> f32 {
+    match position {
+        AttackPosition::Front => 1.0,
+        AttackPosition::Flank => 1.0 + FLANK_DAMAGE_BONUS,
+        AttackPosition::Rear => 1.0 + REAR_DAMAGE_BONUS,
+    }
+}
+
+/// Build the attack table for one attacker against one defender
+pub fn build_attack_table(attacker: &AttackerProfile, defender: &DefenderProfile) -> AttackTable {
+    let level_difference = defender.level as f32 - attacker.level as f32;
+    let level_avoidance = level_difference * AVOIDANCE_PER_LEVEL_DIFFERENCE;
+
+    let miss = (BASE_MISS_CHANCE + level_avoidance - attacker.weapon_skill as f32 * MISS_REDUCTION_PER_SKILL_LEVEL)
+        .clamp(0.0, MAX_MISS_CHANCE);
+    let rear_critical = if defender.attacked_from == AttackPosition::Rear { REAR_CRITICAL_BONUS } else { 0.0 };
+    let critical = (attacker.critical_chance + rear_critical - level_avoidance).max(0.0);
+
+    if defender.incapacitated {
+        return AttackTable { miss, critical, ..default() };
+    }
+
+    let frontal = defender.attacked_from == AttackPosition::Front;
+    let dodge = (base_dodge_chance(defender.armor_type) + level_avoidance + defender.dodge_bonus).max(0.0);
+    let parry = match defender.parry_skill {
+        Some(skill) if frontal && !attacker.ranged => BASE_PARRY_CHANCE + skill as f32 * PARRY_PER_SKILL_LEVEL + defender.parry_bonus,
+        _ => 0.0,
+    };
+    let block = match defender.block_skill {
+        Some(skill) if frontal => BASE_BLOCK_CHANCE + skill as f32 * BLOCK_PER_SKILL_LEVEL + defender.block_bonus,
+        _ => 0.0,
+    };
+
+    AttackTable { miss, dodge, parry, block, critical }
+}
+
+/// Builds attack tables from whatever the attacker and defender have - players use their
+/// level, skills and loadout, enemies their level and armour, and both where they stand
+#[derive(SystemParam)]
+pub struct AttackTables<'w, 's> {
+    config: Res<'w, ProgressionConfig>,
+    combatant_query: Query<'w, 's, (
+        Option<&'static CharacterLevel>,
+        Option<&'static CharacterSkills>,
+        Option<&'static CharacterLoadouts>,
+        Option<&'static Enemy>,
+        Option<&'static DamageResistances>,
+        Option<&'static StatusEffects>,
+    )>,
+    transform_query: Query<'w, 's, &'static Transform>,
+}
+
+impl AttackTables<'_, '_> {
+    /// Attack table for a weapon attack - enemies attack with None
+    pub fn table(&self, attacker: Entity, target: Entity, weapon: Option<WeaponType>) -> AttackTable {
+        let defender = DefenderProfile {
+            attacked_from: self.position(attacker, target),
+            ..self.defender_profile(target)
+        };
+        build_attack_table(&self.attacker_profile(attacker, weapon), &defender)
+    }
+
+    /// Which side of the target the attacker is on - front if either has no transform
+    pub fn position(&self, attacker: Entity, target: Entity) -> AttackPosition {
+        match (self.transform_query.get(attacker), self.transform_query.get(target)) {
+            (Ok(attacker), Ok(target)) => attack_position(target, attacker.translation),
+            _ => AttackPosition::Front,
+        }
+    }
+
+    pub fn roll(&self, attacker: Entity, target: Entity, weapon: Option<WeaponType>, rng: &mut impl Rng) -> AttackOutcome {
+        self.table(attacker, target, weapon).roll(rng)
+    }
+
+    fn attacker_profile(&self, attacker: Entity, weapon: Option<WeaponType>) -> AttackerProfile {
+        let Ok((level, skills, loadouts, enemy, ..)) = self.combatant_query.get(attacker) else {
+            return AttackerProfile { level: 1, weapon_skill: 1, critical_chance: 0.0, ranged: false };
+        };
+
+        if let Some(enemy) = enemy {
+            return AttackerProfile { level: enemy.level, weapon_skill: 1, critical_chance: ENEMY_CRITICAL_CHANCE, ranged: false };
+        }
+
+        let weapon_critical = weapon
+            .and_then(|weapon| self.config.get_weapon_config_by_enum(weapon))
+            .map_or(0.0, |weapon_config| weapon_config.critical_chance);
+        let role_critical = loadouts
+            .and_then(|loadouts| loadouts.active_loadout())
+            .and_then(|loadout| self.config.get_role_bonus_by_enum(loadout.primary_suggested_role(), "critical_chance"))
+            .unwrap_or(0.0);
+
+        AttackerProfile {
+            level: level.map_or(1, |level| level.level),
+            weapon_skill: weapon
+                .zip(skills)
+                .map_or(1, |(weapon, skills)| skills.get_skill_level(weapon.associated_skill())),
+            critical_chance: weapon_critical + role_critical,
+            ranged: weapon.is_some_and(|weapon| weapon.is_ranged()),
+        }
+    }
+
+    fn defender_profile(&self, target: Entity) -> DefenderProfile {
+        let Ok((level, skills, loadouts, enemy, resistances, status_effects)) = self.combatant_query.get(target) else {
+            return DefenderProfile::default();
+        };
+
+        let loadout = loadouts.and_then(|loadouts| loadouts.active_loadout());
+        let skill_level = |skill: SkillType| skills.map_or(1, |skills| skills.get_skill_level(skill));
+
+        // Ranged weapons and staves can't parry, a shield in the off hand blocks
+        let parry_skill = loadout
+            .map(|loadout| loadout.primary_weapon)
+            .filter(|weapon| !weapon.is_ranged() && !weapon.is_staff())
+            .map(|weapon| skill_level(weapon.associated_skill()));
+        let block_skill = loadout
+            .filter(|loadout| loadout.secondary_item == Some(WeaponType::Shield))
+            .map(|_| skill_level(SkillType::ShieldDefense));
+
+        DefenderProfile {
+            level: enemy.map(|enemy| enemy.level).or(level.map(|level| level.level)).unwrap_or(1),
+            armor_type: resistances
+                .and_then(|resistances| resistances.armor_type)
+                .or(loadout.map(|loadout| loadout.armor_type)),
+            parry_skill,
+            block_skill,
+            dodge_bonus: status_effects.map_or(0.0, |effects| effects.dodge_bonus()),
+            parry_bonus: status_effects.map_or(0.0, |effects| effects.parry_bonus()),
+            block_bonus: status_effects.map_or(0.0, |effects| effects.block_bonus()),
+            incapacitated: status_effects.is_some_and(|effects| effects.is_stunned()),
+            attacked_from: AttackPosition::Front,
+        }
+    }
+}
+
+// Riposte Components
+/// Opened by parrying an attack - Riposte can only be used while it lasts
+#[derive(Component, Debug)]
+pub struct RiposteWindow {
+    pub timer: Timer,
+}
+
+// System to open a riposte window whenever the player parries
+pub fn open_riposte_window(
+    mut commands: Commands,
+    mut avoided_events: EventReader<AttackAvoided>,
+    player_query: Query<Entity, With<Player>>,
+) {
+    let Ok(player_entity) = player_query.single() else {
+        return;
+    };
+
+    for event in avoided_events.read() {
+        if event.target == player_entity && event.outcome == AttackOutcome::Parry {
+            commands.entity(player_entity).insert(RiposteWindow {
+                timer: Timer::from_seconds(RIPOSTE_WINDOW, TimerMode::Once),
+            });
+            info!("⚔️ Parried! Riposte is ready");
+        }
+    }
+}
+
+// System to close riposte windows that weren't used in time
+pub fn tick_riposte_windows(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut window_query: Query<(Entity, &mut RiposteWindow)>,
+) {
+    for (entity, mut window) in &mut window_query {
+        window.timer.tick(time.delta());
+        if window.timer.finished() {
+            commands.entity(entity).remove::<RiposteWindow>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attacker() -> AttackerProfile {
+        AttackerProfile { level: 5, weapon_skill: 10, critical_chance: 0.05, ranged: false }
+    }
+
+    fn defender() -> DefenderProfile {
+        DefenderProfile {
+            level: 5,
+            armor_type: Some(ArmorType::Medium),
+            parry_skill: Some(10),
+            block_skill: Some(10),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_single_roll_walks_the_table_in_order() {
+        let table = AttackTable { miss: 0.1, dodge: 0.1, parry: 0.1, block: 0.1, critical: 0.1 };
+
+        assert_eq!(table.outcome(0.05), AttackOutcome::Miss);
+        assert_eq!(table.outcome(0.15), AttackOutcome::Dodge);
+        assert_eq!(table.outcome(0.25), AttackOutcome::Parry);
+        assert_eq!(table.outcome(0.35), AttackOutcome::Block);
+        assert_eq!(table.outcome(0.45), AttackOutcome::Critical);
+        assert_eq!(table.outcome(0.55), AttackOutcome::Hit);
+
+        // A full table pushes crits off the end
+        let capped = AttackTable { miss: 0.5, dodge: 0.6, critical: 0.5, ..default() };
+        assert!((0..100).all(|i| capped.outcome(i as f32 / 100.0) != AttackOutcome::Critical));
+    }
+
+    #[test]
+    fn test_table_respects_weapons_and_shields() {
+        let table = build_attack_table(&attacker(), &defender());
+        assert!(table.parry > 0.0 && table.block > 0.0);
+
+        // Arrows can't be parried, and nothing is avoided while stunned
+        let ranged = build_attack_table(&AttackerProfile { ranged: true, ..attacker() }, &defender());
+        assert_eq!(ranged.parry, 0.0);
+
+        let stunned = build_attack_table(&attacker(), &DefenderProfile { incapacitated: true, ..defender() });
+        assert_eq!((stunned.dodge, stunned.parry, stunned.block), (0.0, 0.0, 0.0));
+
+        // Shields and parries only work against the front, and backs get crit more
+        let rear = build_attack_table(&attacker(), &DefenderProfile { attacked_from: AttackPosition::Rear, ..defender() });
+        assert_eq!((rear.parry, rear.block), (0.0, 0.0));
+        assert!(rear.critical > table.critical);
+
+        let flank = build_attack_table(&attacker(), &DefenderProfile { attacked_from: AttackPosition::Flank, ..defender() });
+        assert_eq!((flank.parry, flank.block), (0.0, 0.0));
+
+        // Higher-level defenders are harder to hit
+        let tough = build_attack_table(&attacker(), &DefenderProfile { level: 10, ..defender() });
+        assert!(tough.miss > table.miss && tough.critical < table.critical);
+    }
+}
@@ -1,6 +1,23 @@
 use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
 use avian3d::prelude::*;
-use crate::components::Player;
+use rand::prelude::*;
+use eryndor_core::events::ProgressionEvent;
+use crate::components::{Player, NpcInfo, CharacterLevel, CharacterLoadouts, CharacterSkills, Loadout, DamageType, WeaponType};
+use crate::resources::{GameConfig, ProgressionConfig, EnemyDatabase, EnemyDefinition, EnemyStats, EnemyAiConfig, LevelRange};
+use crate::systems::damage::{DamageEvent, DamageDealt, DamageSource, DamageResistances};
+use crate::systems::enemy_ai::{EnemyAi, ENEMY_HALF_HEIGHT};
+use crate::systems::threat::ThreatTable;
+use crate::systems::status_effects::StatusEffects;
+use crate::systems::death::Dead;
+use crate::systems::projectiles::{FireProjectile, ProjectileKind};
+use crate::systems::spellcasting::Casting;
+use crate::systems::line_of_sight::{LineOfSight, is_facing};
+use crate::systems::abilities::AbilityError;
+use crate::systems::camera::GameCamera;
+use crate::systems::targeting::PartyMember;
+use crate::systems::attack_table::{AttackTables, AttackOutcome};
+use crate::systems::difficulty::con_color;
 
 // Combat Components
 #[derive(Component)]
@@ -38,82 +55,252 @@ impl Health {
     }
 }
 
-#[derive(Component)]
-pub struct Enemy {
-    pub enemy_type: EnemyType,
+/// Enemy id indexing the EnemyDatabase loaded from config/enemies.json
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnemyType(pub String);
+
+impl EnemyType {
+    pub fn new(enemy_id: impl Into<String>) -> Self {
+        Self(enemy_id.into())
+    }
+
+    pub fn id(&self) -> &str {
+        &self.0
+    }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub enum EnemyType {
-    ForestGuardian,
-    RockElemental,
-    WildBoar,
+/// A spawned enemy - a snapshot of its enemies.json definition, scaled to the level it spawned with
+#[derive(Component, Debug)]
+pub struct Enemy {
+    pub enemy_type: EnemyType,
+    pub name: String,
+    pub level: u32,
+    pub stats: EnemyStats,
+    pub ai: EnemyAiConfig,
+    pub damage_type: DamageType,
+    pub loot_table: Option<String>,
+    pub abilities: Vec<String>, // Status effect ids, applied in turn by attacks that land
 }
 
-impl EnemyType {
-    pub fn name(self) -> &'static str {
-        match self {
-            EnemyType::ForestGuardian => "Forest Guardian",
-            EnemyType::RockElemental => "Rock Elemental", 
-            EnemyType::WildBoar => "Wild Boar",
+impl Enemy {
+    pub fn from_definition(enemy_type: EnemyType, definition: &EnemyDefinition, level: u32, config: &ProgressionConfig) -> Self {
+        Self {
+            enemy_type,
+            name: definition.name.clone(),
+            level,
+            stats: definition.stats.scaled_to(level, definition.level_range.min, &definition.scaling),
+            ai: definition.ai.clone(),
+            damage_type: config.damage_type_id_to_enum(&definition.stats.damage_type).unwrap_or(DamageType::Bludgeoning),
+            loot_table: definition.loot_table.clone(),
+            abilities: definition.abilities.clone(),
         }
     }
+}
 
-    pub fn max_health(self) -> f32 {
-        match self {
-            EnemyType::ForestGuardian => 100.0,
-            EnemyType::RockElemental => 150.0,
-            EnemyType::WildBoar => 80.0,
-        }
+/// Armor and resistance keys from damage_types.json this enemy is affected by
+pub fn enemy_damage_resistances(definition: &EnemyDefinition) -> DamageResistances {
+    DamageResistances {
+        armor_type: definition.stats.armor_type(),
+        resistances: definition.resistances.iter().map(|key| (key.clone(), 1.0)).collect(),
     }
+}
 
-    pub fn damage(self) -> f32 {
-        match self {
-            EnemyType::ForestGuardian => 15.0,
-            EnemyType::RockElemental => 25.0,
-            EnemyType::WildBoar => 20.0,
-        }
+/// Everything needed to spawn an enemy from its enemies.json definition
+#[derive(SystemParam)]
+pub struct EnemySpawner<'w, 's> {
+    commands: Commands<'w, 's>,
+    meshes: ResMut<'w, Assets<Mesh>>,
+    materials: ResMut<'w, Assets<StandardMaterial>>,
+    asset_server: Res<'w, AssetServer>,
+    database: Res<'w, EnemyDatabase>,
+    config: Res<'w, ProgressionConfig>,
+}
+
+impl EnemySpawner<'_, '_> {
+    pub fn enemy_definition(&self, enemy_id: &str) -> Option<&EnemyDefinition> {
+        self.database.get(enemy_id)
     }
 
-    pub fn experience_reward(self) -> u64 {
-        match self {
-            EnemyType::ForestGuardian => 50,
-            EnemyType::RockElemental => 75,
-            EnemyType::WildBoar => 40,
+    /// Spawn an enemy at a random level from its definition's level range, or from a zone's level band
+    /// A wander radius overrides the definition's patrol radius (spawn points set their own)
+    pub fn spawn(&mut self, enemy_id: &str, position: Vec3, wander_radius: Option<f32>, level_band: Option<LevelRange>) -> Option<EntityCommands<'_>> {
+        let Some(definition) = self.database.get(enemy_id) else {
+            warn!("Unknown enemy id '{}' - not in enemies.json", enemy_id);
+            return None;
+        };
+
+        let level = level_band.unwrap_or(definition.level_range).roll(&mut thread_rng());
+        let mut enemy = Enemy::from_definition(EnemyType::new(enemy_id), definition, level, &self.config);
+        if let Some(wander_radius) = wander_radius {
+            enemy.ai.patrol_radius = wander_radius;
         }
-    }
 
-    pub fn color(self) -> Color {
-        match self {
-            EnemyType::ForestGuardian => Color::srgb(0.2, 0.8, 0.3),
-            EnemyType::RockElemental => Color::srgb(0.6, 0.6, 0.6),
-            EnemyType::WildBoar => Color::srgb(0.6, 0.4, 0.2),
+        let mut entity = self.commands.spawn((
+            Transform::from_translation(position),
+            RigidBody::Kinematic,
+            Collider::cuboid(1.0, ENEMY_HALF_HEIGHT, 1.0),
+            Health::new(enemy.stats.max_health),
+            enemy_damage_resistances(definition),
+            EnemyAi::new(position),
+            ThreatTable::default(),
+            StatusEffects::default(),
+        ));
+
+        match &definition.model.path {
+            Some(model_path) => {
+                // Scenes are authored with their origin at the feet, the collider is centred
+                let scene = self.asset_server.load(GltfAssetLabel::Scene(0).from_asset(model_path.clone()));
+                entity.insert(Visibility::default());
+                entity.with_children(|children| {
+                    children.spawn((SceneRoot(scene), Transform::from_xyz(0.0, -ENEMY_HALF_HEIGHT, 0.0)));
+                });
+            }
+            None => {
+                let mesh = self.meshes.add(Cuboid::new(2.0, ENEMY_HALF_HEIGHT * 2.0, 2.0));
+                let material = self.materials.add(StandardMaterial {
+                    base_color: definition.model.color(),
+                    ..default()
+                });
+                entity.insert((Mesh3d(mesh), MeshMaterial3d(material)));
+            }
         }
+
+        info!("Spawned level {} {} at {:?}", level, enemy.name, position);
+        entity.insert(enemy);
+        Some(entity)
     }
 }
 
 #[derive(Component)]
 pub struct CombatTarget;
 
-#[derive(Component)]
-pub struct AutoAttack {
+/// A dead enemy lingering in the world until its decay timer runs out
+#[derive(Component, Debug)]
+pub struct Corpse {
+    pub decay_timer: Timer,
+}
+
+/// Damage bonus per level in the weapon's or ability's associated skill
+pub const SKILL_DAMAGE_PER_LEVEL: f32 = 0.02;
+/// Off-hand swings deal reduced damage
+const OFF_HAND_DAMAGE_MULTIPLIER: f32 = 0.5;
+/// Extra kill experience per level the enemy is above the player
+const KILL_XP_BONUS_PER_LEVEL: f32 = 0.1;
+/// Kill experience stops growing five levels above the player
+const MAX_KILL_XP_MULTIPLIER: f32 = 1.5;
+/// Lost kill experience per level the enemy is below the player - nothing at five levels below
+const KILL_XP_PENALTY_PER_LEVEL: f32 = 0.2;
+/// Tab prefers enemies within this angle of the camera's view direction
+const TAB_TARGET_HALF_ARC_DEGREES: f32 = 45.0;
+
+/// One hand's worth of auto-attack swings, built from weapons.json stats
+#[derive(Debug)]
+pub struct WeaponSwing {
+    pub weapon: WeaponType,
     pub timer: Timer,
-    pub damage: f32,
+    pub base_damage: f32,
+    pub range: f32,
+    pub damage_type: DamageType,
+    pub damage_multiplier: f32,
 }
 
-impl AutoAttack {
-    pub fn new(attack_speed: f32, damage: f32) -> Self {
+impl WeaponSwing {
+    pub fn new(weapon: WeaponType, config: &ProgressionConfig, combat_config: &CombatConfig, damage_multiplier: f32) -> Self {
+        let (attack_speed, base_damage, range, damage_type) = match config.get_weapon_config_by_enum(weapon) {
+            Some(weapon_config) => (
+                weapon_config.attack_speed,
+                weapon_config.base_damage,
+                weapon_config.range,
+                config.damage_type_id_to_enum(&weapon_config.damage_type).unwrap_or(weapon.damage_type()),
+            ),
+            None => (1.8, combat_config.base_player_damage, 0.0, weapon.damage_type()),
+        };
+
+        // Start ready so the first swing lands as soon as the target is in range
+        let mut timer = Timer::from_seconds(attack_speed.max(0.1), TimerMode::Once);
+        timer.tick(timer.duration());
+
         Self {
-            timer: Timer::from_seconds(attack_speed, TimerMode::Repeating),
-            damage,
+            weapon,
+            timer,
+            base_damage,
+            // Weapon reach is measured from the body - never shorter than basic melee range
+            range: range.max(combat_config.auto_attack_range),
+            damage_type,
+            damage_multiplier,
         }
     }
+
+    /// Pick up where another swing's timer left off - the time left until the next swing carries over
+    fn carry_timer_from(&mut self, previous: &WeaponSwing) {
+        let elapsed = self.timer.duration().saturating_sub(previous.timer.remaining());
+        self.timer.reset();
+        self.timer.tick(elapsed);
+    }
+
+    /// Damage for one swing, scaled by the weapon's skill level
+    pub fn swing_damage(&self, skill_level: u32) -> f32 {
+        self.base_damage * self.damage_multiplier * (1.0 + skill_level as f32 * SKILL_DAMAGE_PER_LEVEL)
+    }
+}
+
+/// Player auto-attack - updated whenever the active loadout changes
+#[derive(Component, Debug)]
+pub struct AutoAttack {
+    pub main_hand: WeaponSwing,
+    pub off_hand: Option<WeaponSwing>,
+}
+
+impl AutoAttack {
+    pub fn from_loadout(loadout: &Loadout, config: &ProgressionConfig, combat_config: &CombatConfig) -> Self {
+        let main_hand = WeaponSwing::new(loadout.primary_weapon, config, combat_config, 1.0);
+
+        // Dual-wield: a one-handed weapon in each hand swings independently
+        let off_hand = loadout.secondary_item
+            .filter(|secondary| Self::can_dual_wield(loadout.primary_weapon, *secondary, config))
+            .map(|secondary| {
+                let mut swing = WeaponSwing::new(secondary, config, combat_config, OFF_HAND_DAMAGE_MULTIPLIER);
+                // Stagger the off-hand so both hands don't land on the same frame
+                swing.timer.reset();
+                swing.timer.tick(swing.timer.duration() / 2);
+                swing
+            });
+
+        Self { main_hand, off_hand }
+    }
+
+    /// Swap in a new loadout's weapons without resetting the swing timers - switching never grants a free swing
+    pub fn update_from_loadout(&mut self, loadout: &Loadout, config: &ProgressionConfig, combat_config: &CombatConfig) {
+        let mut updated = Self::from_loadout(loadout, config, combat_config);
+        updated.main_hand.carry_timer_from(&self.main_hand);
+        if let (Some(off_hand), Some(previous)) = (updated.off_hand.as_mut(), self.off_hand.as_ref()) {
+            off_hand.carry_timer_from(previous);
+        }
+        *self = updated;
+    }
+
+    fn can_dual_wield(primary: WeaponType, secondary: WeaponType, config: &ProgressionConfig) -> bool {
+        let is_one_handed_melee = |weapon: WeaponType| {
+            !weapon.is_two_handed() &&
+            config.get_weapon_config_by_enum(weapon).is_some_and(|w| w.weapon_class == "OneHanded")
+        };
+        is_one_handed_melee(primary) && is_one_handed_melee(secondary)
+    }
+
+    pub fn swings(&self) -> impl Iterator<Item = &WeaponSwing> {
+        std::iter::once(&self.main_hand).chain(self.off_hand.as_ref())
+    }
+
+    pub fn swings_mut(&mut self) -> impl Iterator<Item = &mut WeaponSwing> {
+        std::iter::once(&mut self.main_hand).chain(self.off_hand.as_mut())
+    }
 }
 
 // Combat Resources
 #[derive(Resource, Default)]
 pub struct CombatState {
-    pub player_target: Option<Entity>,
+    pub player_target: Option<Entity>, // Any targetable - enemies, friendly NPCs, party members or the player
+    pub focus_target: Option<Entity>,
     pub in_combat: bool,
 }
 
@@ -134,74 +321,42 @@ impl Default for CombatConfig {
     }
 }
 
-// System to spawn demo enemies
-pub fn spawn_demo_enemies(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    mut spawned: Local<bool>,
-) {
-    if *spawned {
-        return;
-    }
-
-    info!("🐺 Spawning demo enemies");
-
-    let enemy_positions = [
-        (Vec3::new(20.0, 5.0, 30.0), EnemyType::ForestGuardian),
-        (Vec3::new(-30.0, 8.0, 40.0), EnemyType::RockElemental),
-        (Vec3::new(50.0, 3.0, -20.0), EnemyType::WildBoar),
-        (Vec3::new(-15.0, 6.0, -35.0), EnemyType::ForestGuardian),
-        (Vec3::new(0.0, 10.0, 60.0), EnemyType::RockElemental),
-    ];
-
-    for (position, enemy_type) in enemy_positions {
-        let mesh = meshes.add(Cuboid::new(2.0, 3.0, 2.0));
-        let material = materials.add(StandardMaterial {
-            base_color: enemy_type.color(),
-            ..default()
-        });
-
-        let _enemy_entity = commands.spawn((
-            Mesh3d(mesh),
-            MeshMaterial3d(material),
-            Transform::from_translation(position),
-            RigidBody::Kinematic,
-            Collider::cuboid(1.0, 1.5, 1.0),
-            Enemy { enemy_type },
-            Health::new(enemy_type.max_health()),
-        )).id();
-
-        info!("Spawned {} at {:?}", enemy_type.name(), position);
-    }
-
-    *spawned = true;
-}
-
 // System to handle target selection (Tab key cycling)
+#[allow(clippy::too_many_arguments)]
 pub fn handle_target_selection(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut combat_state: ResMut<CombatState>,
-    player_query: Query<&Transform, With<Player>>,
+    player_query: Query<(Entity, &Transform), With<Player>>,
     enemy_query: Query<(Entity, &Transform, &Enemy, &Health), Without<Player>>,
+    camera_query: Query<&Transform, (With<GameCamera>, Without<Player>, Without<Enemy>)>,
+    friendly_query: Query<(), Or<(With<Player>, With<NpcInfo>, With<PartyMember>)>>,
+    line_of_sight: LineOfSight,
     combat_config: Res<CombatConfig>,
 ) {
     if keyboard.just_pressed(KeyCode::Tab) {
-        if let Ok(player_transform) = player_query.single() {
-            // Get all enemies within targeting range
+        if let Ok((player_entity, player_transform)) = player_query.single() {
+            // Get all visible enemies within targeting range
             let mut targets: Vec<_> = enemy_query
                 .iter()
-                .filter(|(_, enemy_transform, _, health)| {
+                .filter(|(entity, enemy_transform, _, health)| {
                     health.is_alive() && 
-                    player_transform.translation.distance(enemy_transform.translation) <= combat_config.target_selection_range
+                    player_transform.translation.distance(enemy_transform.translation) <= combat_config.target_selection_range &&
+                    line_of_sight.between(player_entity, *entity)
                 })
                 .collect();
 
-            // Sort by distance
+            // Targets in front of the camera come first, then sort by distance
+            let camera_transform = camera_query.single().ok();
+            let in_view = |transform: &Transform| {
+                camera_transform.is_some_and(|camera| {
+                    let to_target = (transform.translation - camera.translation).normalize_or_zero();
+                    camera.forward().dot(to_target) >= TAB_TARGET_HALF_ARC_DEGREES.to_radians().cos()
+                })
+            };
             targets.sort_by(|a, b| {
                 let dist_a = player_transform.translation.distance(a.1.translation);
                 let dist_b = player_transform.translation.distance(b.1.translation);
-                dist_a.partial_cmp(&dist_b).unwrap()
+                in_view(b.1).cmp(&in_view(a.1)).then(dist_a.total_cmp(&dist_b))
             });
 
             if !targets.is_empty() {
@@ -211,16 +366,16 @@ pub fn handle_target_selection(
                         // Cycle to next target
                         let next_index = (current_index + 1) % targets.len();
                         combat_state.player_target = Some(targets[next_index].0);
-                        info!("🎯 Target switched to {}", targets[next_index].2.enemy_type.name());
+                        info!("🎯 Target switched to {}", targets[next_index].2.name);
                     } else {
                         // Current target not in range, select closest
                         combat_state.player_target = Some(targets[0].0);
-                        info!("🎯 Target selected: {}", targets[0].2.enemy_type.name());
+                        info!("🎯 Target selected: {}", targets[0].2.name);
                     }
                 } else {
                     // No current target, select closest
                     combat_state.player_target = Some(targets[0].0);
-                    info!("🎯 Target selected: {}", targets[0].2.enemy_type.name());
+                    info!("🎯 Target selected: {}", targets[0].2.name);
                 }
                 
                 combat_state.in_combat = true;
@@ -234,7 +389,7 @@ pub fn handle_target_selection(
 
     // Clear target if it's dead or out of range
     if let Some(target_entity) = combat_state.player_target {
-        if let Ok(player_transform) = player_query.single() {
+        if let Ok((_, player_transform)) = player_query.single() {
             if let Ok((_, target_transform, _, target_health)) = enemy_query.get(target_entity) {
                 let distance = player_transform.translation.distance(target_transform.translation);
                 if !target_health.is_alive() || distance > combat_config.target_selection_range * 1.5 {
@@ -242,7 +397,8 @@ pub fn handle_target_selection(
                     combat_state.in_combat = false;
                     info!("Target lost - too far or dead");
                 }
-            } else {
+            } else if !friendly_query.contains(target_entity) {
+                // Friendly targets (NPCs, party members, yourself) are kept until they're gone
                 combat_state.player_target = None;
                 combat_state.in_combat = false;
             }
@@ -250,60 +406,260 @@ pub fn handle_target_selection(
     }
 }
 
+// System to rebuild auto-attack whenever the active loadout changes
+pub fn sync_auto_attack_with_loadout(
+    mut commands: Commands,
+    config: Res<ProgressionConfig>,
+    combat_config: Option<Res<CombatConfig>>,
+    mut player_query: Query<(Entity, &CharacterLoadouts, Option<&mut AutoAttack>), (With<Player>, Changed<CharacterLoadouts>)>,
+) {
+    let Some(combat_config) = combat_config else {
+        return; // Combat not initialized yet
+    };
+
+    for (entity, loadouts, auto_attack) in &mut player_query {
+        let Some(loadout) = loadouts.active_loadout() else {
+            commands.entity(entity).remove::<AutoAttack>();
+            continue;
+        };
+
+        let describe = |auto_attack: &AutoAttack| {
+            info!("⚔️ Auto-attack set to {:?}{} ({:.1}s swing)",
+                  auto_attack.main_hand.weapon,
+                  auto_attack.off_hand.as_ref().map(|o| format!(" + {:?}", o.weapon)).unwrap_or_default(),
+                  auto_attack.main_hand.timer.duration().as_secs_f32());
+        };
+
+        // Keep the running swing timers when switching weapons
+        match auto_attack {
+            Some(mut auto_attack) => {
+                auto_attack.update_from_loadout(loadout, &config, &combat_config);
+                describe(&auto_attack);
+            }
+            None => {
+                let auto_attack = AutoAttack::from_loadout(loadout, &config, &combat_config);
+                describe(&auto_attack);
+                commands.entity(entity).insert(auto_attack);
+            }
+        }
+    }
+}
+
 // System to handle player auto-attack
+#[allow(clippy::too_many_arguments)]
 pub fn handle_player_auto_attack(
     time: Res<Time>,
-    mut combat_state: ResMut<CombatState>,
-    combat_config: Res<CombatConfig>,
-    player_query: Query<&Transform, With<Player>>,
-    mut enemy_query: Query<(&Transform, &mut Health, &Enemy), Without<Player>>,
-    mut player_auto_attack: Local<Option<AutoAttack>>,
+    combat_state: Res<CombatState>,
+    line_of_sight: LineOfSight,
+    attack_tables: AttackTables,
+    mut last_blocked: Local<Option<AbilityError>>,
+    mut player_query: Query<(Entity, &Transform, &mut AutoAttack, Option<&CharacterSkills>, Option<&StatusEffects>, Has<Casting>, Has<Dead>), With<Player>>,
+    enemy_query: Query<(&Transform, &Health), (With<Enemy>, Without<Player>)>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut projectile_events: EventWriter<FireProjectile>,
 ) {
-    // Initialize auto-attack timer if needed
-    if player_auto_attack.is_none() {
-        *player_auto_attack = Some(AutoAttack::new(1.8, combat_config.base_player_damage));
+    let Ok((player_entity, player_transform, mut auto_attack, skills, status_effects, is_casting, is_dead)) = player_query.single_mut() else {
+        return;
+    };
+
+    for swing in auto_attack.swings_mut() {
+        swing.timer.tick(time.delta());
     }
 
-    if let Some(ref mut auto_attack) = player_auto_attack.as_mut() {
-        auto_attack.timer.tick(time.delta());
+    // No swings while dead, stunned or casting
+    if is_dead || is_casting || status_effects.is_some_and(|effects| effects.is_stunned()) {
+        return;
+    }
 
-        if let (Some(target_entity), Ok(player_transform)) = (combat_state.player_target, player_query.single()) {
-            if let Ok((target_transform, mut target_health, enemy)) = enemy_query.get_mut(target_entity) {
-                let distance = player_transform.translation.distance(target_transform.translation);
-                
-                if distance <= combat_config.auto_attack_range && auto_attack.timer.just_finished() {
-                    // Deal damage
-                    let damage_dealt = target_health.take_damage(auto_attack.damage);
-                    
-                    info!("⚔️ Player attacks {} for {:.1} damage! ({:.1}/{:.1} HP remaining)",
-                          enemy.enemy_type.name(), damage_dealt, target_health.current, target_health.max);
-
-                    if !target_health.is_alive() {
-                        info!("💀 {} defeated!", enemy.enemy_type.name());
-                        
-                        // Award experience - this would be better done through an event system
-                        let experience_reward = enemy.enemy_type.experience_reward();
-                        info!("📈 Gained {} experience from combat!", experience_reward);
-                        
-                        // Clear target
-                        combat_state.player_target = None;
-                        combat_state.in_combat = false;
-                    }
-                }
+    let Some(target_entity) = combat_state.player_target else {
+        return;
+    };
+
+    let Ok((target_transform, target_health)) = enemy_query.get(target_entity) else {
+        return;
+    };
+
+    if !target_health.is_alive() {
+        return;
+    }
+
+    let distance = player_transform.translation.distance(target_transform.translation);
+
+    // Swings also wait while the target is behind the player or out of sight - only raycast once one is ready
+    if auto_attack.swings().any(|swing| swing.timer.finished() && distance <= swing.range) {
+        let blocked = if !is_facing(player_transform, target_transform.translation) {
+            Some(AbilityError::NotFacing)
+        } else if !line_of_sight.between(player_entity, target_entity) {
+            Some(AbilityError::OutOfSight)
+        } else {
+            None
+        };
+
+        if blocked != *last_blocked {
+            if let Some(error) = &blocked {
+                info!("🚫 {}", error);
+            }
+            *last_blocked = blocked.clone();
+        }
+        if blocked.is_some() {
+            return;
+        }
+    }
+
+    let mut rng = thread_rng();
+
+    for swing in auto_attack.swings_mut() {
+        // Swings stay ready until the target is in range
+        if !swing.timer.finished() || distance > swing.range {
+            continue;
+        }
+
+        let skill_level = skills
+            .map(|skills| skills.get_skill_level(swing.weapon.associated_skill()))
+            .unwrap_or(1);
+
+        let payload = DamageEvent {
+            attacker: Some(player_entity),
+            target: target_entity,
+            damage_type: swing.damage_type,
+            amount: swing.swing_damage(skill_level),
+            source: DamageSource::AutoAttack,
+            outcome: attack_tables.roll(player_entity, target_entity, Some(swing.weapon), &mut rng),
+            position: Some(attack_tables.position(player_entity, target_entity)),
+            status_effect: None,
+        };
+
+        // Bows and thrown weapons loose a projectile - the hit lands when (and if) it arrives
+        match swing.weapon.projectile_flight() {
+            Some(flight) => {
+                let kind = if swing.weapon.is_thrown() { ProjectileKind::Thrown } else { ProjectileKind::Arrow };
+                projectile_events.write(FireProjectile { payload, flight, kind });
+            }
+            None => {
+                damage_events.write(payload);
+            }
+        }
+
+        swing.timer.reset();
+    }
+}
+
+// System to react to resolved damage against enemies
+pub fn handle_damage_dealt(
+    mut damage_dealt_events: EventReader<DamageDealt>,
+    mut combat_state: ResMut<CombatState>,
+    config: Res<ProgressionConfig>,
+    player_query: Query<(Entity, &CharacterLevel, Option<&CharacterLoadouts>), With<Player>>,
+    enemy_query: Query<(&Health, &Enemy)>,
+    mut progression_events: EventWriter<ProgressionEvent>,
+) {
+    let player = player_query.single().ok();
+    let player_entity = player.map(|(entity, _, _)| entity);
+
+    for event in damage_dealt_events.read() {
+        let Ok((target_health, enemy)) = enemy_query.get(event.target) else {
+            continue;
+        };
+
+        if event.attacker.is_some() && event.attacker == player_entity {
+            let verb = match event.outcome {
+                AttackOutcome::Critical => "CRITS",
+                AttackOutcome::Block => "hits (blocked)",
+                _ => "hits",
+            };
+            info!("⚔️ Player {} {} for {:.1} {:?} damage ({:.0}% resisted)! ({:.1}/{:.1} HP remaining)",
+                  verb, enemy.name, event.amount, event.damage_type, event.resistance * 100.0,
+                  target_health.current, target_health.max);
+        }
+
+        for effect in &event.special_effects {
+            info!("✨ {} triggered on {}", effect, enemy.name);
+        }
+
+        if event.killing_blow {
+            info!("💀 {} defeated!", enemy.name);
+
+            if let Some((player_entity, character_level, loadouts)) = player.filter(|(entity, _, _)| event.attacker == Some(*entity)) {
+                let amount = scale_kill_experience(enemy.stats.experience_reward, character_level.level, enemy.level);
+                // With no skill to train, an empty skill id still grants the character experience
+                let skill_id = kill_skill_id(&event.source, loadouts, &config).unwrap_or_else(|| {
+                    warn!("No skill to train for defeating {} - the main-hand weapon is missing from weapons.json", enemy.name);
+                    ""
+                });
+                progression_events.write(ProgressionEvent::ExperienceGained {
+                    player_entity,
+                    skill_id: skill_id.to_string(),
+                    amount,
+                    source: format!("Defeated {}", enemy.name),
+                    target_level: enemy.level,
+                });
+            }
+
+            // Clear target
+            if combat_state.player_target == Some(event.target) {
+                combat_state.player_target = None;
+                combat_state.in_combat = false;
             }
         }
     }
 }
 
-// System to remove dead enemies
-pub fn cleanup_dead_enemies(
+/// Kill experience scaled by the level difference - tougher enemies are worth more, trivial ones nothing
+pub fn scale_kill_experience(base_experience: u64, player_level: u32, enemy_level: u32) -> u64 {
+    let level_difference = enemy_level as f32 - player_level as f32;
+    let multiplier = if level_difference >= 0.0 {
+        (1.0 + level_difference * KILL_XP_BONUS_PER_LEVEL).min(MAX_KILL_XP_MULTIPLIER)
+    } else {
+        (1.0 + level_difference * KILL_XP_PENALTY_PER_LEVEL).max(0.0)
+    };
+    (base_experience as f32 * multiplier).round() as u64
+}
+
+/// The skill trained by a kill - the ability's skill, otherwise the main-hand weapon's from weapons.json
+fn kill_skill_id<'a>(source: &DamageSource, loadouts: Option<&CharacterLoadouts>, config: &'a ProgressionConfig) -> Option<&'a str> {
+    if let DamageSource::Ability(ability_name) = source {
+        if let Some((skill_id, _, _)) = config.find_ability(ability_name) {
+            return Some(skill_id);
+        }
+    }
+
+    let weapon = loadouts
+        .and_then(|loadouts| loadouts.active_loadout())
+        .map(|loadout| loadout.primary_weapon)
+        .unwrap_or(WeaponType::Sword);
+    config.get_weapon_config_by_enum(weapon).map(|weapon_config| weapon_config.associated_skill.as_str())
+}
+
+// System to turn dead enemies into corpses
+pub fn handle_dead_enemies(
     mut commands: Commands,
-    enemy_query: Query<(Entity, &Health, &Enemy)>,
+    mut enemy_query: Query<(Entity, &mut Transform, &Health, &Enemy), Without<Corpse>>,
+    game_config: Res<GameConfig>,
 ) {
-    for (entity, health, enemy) in &enemy_query {
+    for (entity, mut transform, health, enemy) in &mut enemy_query {
         if !health.is_alive() {
+            // Topple over onto its side where it fell - lying down, its 2-unit width becomes its height
+            transform.rotate_local_z(std::f32::consts::FRAC_PI_2);
+            transform.translation.y -= ENEMY_HALF_HEIGHT - 1.0;
+            commands.entity(entity).insert(Corpse {
+                decay_timer: Timer::from_seconds(game_config.gameplay.corpse_duration.max(0.0), TimerMode::Once),
+            });
+            info!("🪦 {} left a corpse", enemy.name);
+        }
+    }
+}
+
+// System to remove corpses once they have decayed
+pub fn cleanup_corpses(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut corpse_query: Query<(Entity, &mut Corpse, &Enemy)>,
+) {
+    for (entity, mut corpse, enemy) in &mut corpse_query {
+        corpse.decay_timer.tick(time.delta());
+        if corpse.decay_timer.finished() {
             commands.entity(entity).despawn();
-            info!("🗑️ Removed dead {} from world", enemy.enemy_type.name());
+            info!("🗑️ Removed {} corpse from world", enemy.name);
         }
     }
 }
@@ -312,10 +668,11 @@ pub fn cleanup_dead_enemies(
 pub fn display_target_health(
     combat_state: Res<CombatState>,
     mut gizmos: Gizmos,
+    player_query: Query<&CharacterLevel, With<Player>>,
     enemy_query: Query<(&Transform, &Health, &Enemy)>,
 ) {
     if let Some(target_entity) = combat_state.player_target {
-        if let Ok((transform, health, _enemy)) = enemy_query.get(target_entity) {
+        if let Ok((transform, health, enemy)) = enemy_query.get(target_entity) {
             let position = transform.translation + Vec3::Y * 4.0;
             
             // Draw health bar background (red)
@@ -334,11 +691,15 @@ pub fn display_target_health(
                 Color::srgb(0.2, 0.8, 0.2),
             );
             
-            // Draw target indicator
+            // Draw target indicator in the enemy's con colour
+            let indicator_color = player_query
+                .single()
+                .map(|character_level| con_color(character_level.level, enemy.level).color())
+                .unwrap_or(Color::srgb(1.0, 1.0, 0.0));
             gizmos.cuboid(
                 Transform::from_translation(transform.translation + Vec3::Y * 0.1)
                     .with_scale(Vec3::new(3.0, 0.1, 3.0)),
-                Color::srgb(1.0, 1.0, 0.0), // Yellow target indicator
+                indicator_color,
             );
         }
     }
@@ -349,4 +710,127 @@ pub fn setup_combat_system(mut commands: Commands) {
     commands.insert_resource(CombatState::default());
     commands.insert_resource(CombatConfig::default());
     info!("⚔️ Combat system initialized");
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kill_experience_scales_with_level_difference() {
+        assert_eq!(scale_kill_experience(100, 5, 5), 100);
+        assert_eq!(scale_kill_experience(100, 5, 7), 120);
+        assert_eq!(scale_kill_experience(100, 5, 20), 150);
+        assert_eq!(scale_kill_experience(100, 5, 3), 60);
+        assert_eq!(scale_kill_experience(100, 10, 3), 0);
+    }
+
+    #[test]
+    fn test_kills_train_the_ability_or_main_hand_skill() {
+        let config = crate::resources::load_progression_config();
+        let loadouts = CharacterLoadouts {
+            loadouts: vec![loadout(WeaponType::Dagger, None)],
+            active_loadout_index: 0,
+            can_switch_loadouts: false,
+        };
+
+        assert_eq!(kill_skill_id(&DamageSource::AutoAttack, Some(&loadouts), &config), Some("dagger_mastery"));
+        assert_eq!(kill_skill_id(&DamageSource::Ability("Spark".to_string()), Some(&loadouts), &config), Some("fire_magic"));
+        assert_eq!(kill_skill_id(&DamageSource::AutoAttack, None, &config), Some("swordsmanship"));
+    }
+
+    #[test]
+    fn test_kills_with_a_missing_weapon_still_grant_character_experience() {
+        let mut config = crate::resources::load_progression_config();
+        config.weapons.remove("sword");
+        assert_eq!(kill_skill_id(&DamageSource::AutoAttack, None, &config), None);
+
+        let enemies = crate::resources::load_enemy_database(&crate::resources::load_status_effect_config(), &config, &crate::resources::load_loot_tables());
+        let definition = enemies.get("wild_boar").unwrap();
+        let enemy = Enemy::from_definition(EnemyType::new("wild_boar"), definition, definition.level_range.min, &config);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(config)
+            .init_resource::<CombatState>()
+            .add_event::<DamageDealt>()
+            .add_event::<ProgressionEvent>()
+            .add_systems(Update, (handle_damage_dealt, crate::systems::progression::apply_experience_gained).chain());
+
+        // No loadouts, so the kill falls back to the sword that weapons.json no longer has
+        let player = app.world_mut().spawn((Player, CharacterLevel::default(), CharacterSkills::default())).id();
+        let target = app.world_mut().spawn((Health::new(0.0), enemy)).id();
+
+        app.world_mut().send_event(DamageDealt {
+            attacker: Some(player),
+            target,
+            damage_type: DamageType::Piercing,
+            source: DamageSource::AutoAttack,
+            raw_amount: 10.0,
+            amount: 10.0,
+            resistance: 0.0,
+            special_effects: Vec::new(),
+            outcome: AttackOutcome::Hit,
+            position: None,
+            status_effect: None,
+            killing_blow: true,
+        });
+        app.update();
+
+        assert!(app.world().get::<CharacterLevel>(player).unwrap().experience > 0);
+    }
+
+    fn loadout(primary_weapon: WeaponType, secondary_item: Option<WeaponType>) -> Loadout {
+        Loadout {
+            name: "Test".to_string(),
+            primary_weapon,
+            secondary_item,
+            armor_type: crate::components::ArmorType::Medium,
+            active_abilities: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_only_one_handed_melee_pairs_dual_wield() {
+        let config = crate::resources::load_progression_config();
+
+        assert!(AutoAttack::can_dual_wield(WeaponType::Sword, WeaponType::Dagger, &config));
+        assert!(!AutoAttack::can_dual_wield(WeaponType::Sword, WeaponType::Shield, &config));
+        assert!(!AutoAttack::can_dual_wield(WeaponType::TwoHandedSword, WeaponType::Dagger, &config));
+        assert!(!AutoAttack::can_dual_wield(WeaponType::Bow, WeaponType::Dagger, &config));
+    }
+
+    #[test]
+    fn test_auto_attack_from_loadout_penalises_the_off_hand() {
+        let config = crate::resources::load_progression_config();
+        let combat_config = CombatConfig::default();
+
+        let sword_and_board = AutoAttack::from_loadout(&loadout(WeaponType::Sword, Some(WeaponType::Shield)), &config, &combat_config);
+        assert!(sword_and_board.off_hand.is_none());
+        assert!(sword_and_board.main_hand.timer.finished());
+
+        let dual_wield = AutoAttack::from_loadout(&loadout(WeaponType::Sword, Some(WeaponType::Dagger)), &config, &combat_config);
+        let off_hand = dual_wield.off_hand.as_ref().unwrap();
+        assert_eq!(off_hand.weapon, WeaponType::Dagger);
+        assert_eq!(off_hand.damage_multiplier, OFF_HAND_DAMAGE_MULTIPLIER);
+        assert!(!off_hand.timer.finished()); // Staggered behind the main hand
+
+        let dagger = WeaponSwing::new(WeaponType::Dagger, &config, &combat_config, 1.0);
+        assert_eq!(off_hand.swing_damage(10), dagger.swing_damage(10) * OFF_HAND_DAMAGE_MULTIPLIER);
+    }
+
+    #[test]
+    fn test_switching_loadouts_keeps_swing_timers() {
+        let config = crate::resources::load_progression_config();
+        let combat_config = CombatConfig::default();
+
+        let mut auto_attack = AutoAttack::from_loadout(&loadout(WeaponType::Sword, None), &config, &combat_config);
+        // Just swung - the sword needs its full swing time again
+        auto_attack.main_hand.timer.reset();
+
+        auto_attack.update_from_loadout(&loadout(WeaponType::Axe, None), &config, &combat_config);
+        assert_eq!(auto_attack.main_hand.weapon, WeaponType::Axe);
+        assert!(!auto_attack.main_hand.timer.finished());
+        assert!(auto_attack.main_hand.timer.remaining_secs() > 1.0);
+    }
+}
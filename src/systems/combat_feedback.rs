@@ -0,0 +1,358 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use crate::components::Player;
+use crate::resources::{GameConfig, ProgressionConfig, StatusEffectConfig};
+use crate::systems::attack_table::AttackOutcome;
+use crate::systems::camera::GameCamera;
+use crate::systems::combat::Enemy;
+use crate::systems::damage::{DamageDealt, HealingDone, AttackAvoided};
+use crate::systems::line_of_sight::aim_point;
+use crate::systems::status_effects::StatusEffectApplied;
+
+/// Seconds floating text stays on screen
+const FLOATING_TEXT_LIFETIME: f32 = 1.2;
+/// How fast floating text rises, in world units per second
+const FLOATING_TEXT_RISE_SPEED: f32 = 1.5;
+/// How far above the aim point floating text starts
+const FLOATING_TEXT_HEIGHT: f32 = 1.2;
+/// Random sideways spread so numbers landing together don't overlap
+const FLOATING_TEXT_SPREAD: f32 = 0.5;
+const FLOATING_TEXT_FONT_SIZE: f32 = 22.0;
+/// Critical hits are drawn this much larger
+const CRITICAL_FONT_SCALE: f32 = 1.5;
+/// Real seconds an enemy flashes after being damaged
+const HIT_FLASH_DURATION: f32 = 0.1;
+/// Game speed while a hit-stop is active
+const HIT_STOP_TIME_SCALE: f32 = 0.05;
+
+// Combat Feedback Components
+/// Text that floats up from a point in the world, fading out as it goes
+#[derive(Component, Debug)]
+pub struct FloatingCombatText {
+    pub world_position: Vec3,
+    pub color: Color,
+    pub timer: Timer,
+}
+
+/// A mesh showing the hit-flash material, and the material to restore when the flash ends
+#[derive(Component, Debug)]
+pub struct HitFlash {
+    pub original: Handle<StandardMaterial>,
+    pub timer: Timer,
+}
+
+/// The bright material enemies flash to when they take damage
+#[derive(Resource)]
+pub struct HitFlashMaterial(pub Handle<StandardMaterial>);
+
+impl FromWorld for HitFlashMaterial {
+    fn from_world(world: &mut World) -> Self {
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        Self(materials.add(StandardMaterial {
+            base_color: Color::WHITE,
+            emissive: LinearRgba::new(3.0, 3.0, 3.0, 1.0),
+            unlit: true,
+            ..default()
+        }))
+    }
+}
+
+/// Real time left on the current hit-stop - virtual time crawls until it runs out
+#[derive(Resource, Debug, Default)]
+pub struct HitStop {
+    pub remaining: f32,
+}
+
+impl HitStop {
+    /// Start a hit-stop, or stretch the current one if the new one lasts longer
+    pub fn trigger(&mut self, duration: f32) {
+        self.remaining = self.remaining.max(duration);
+    }
+
+    /// Advance by real time - true while the hit-stop is still running
+    pub fn tick(&mut self, real_delta_secs: f32) -> bool {
+        self.remaining = (self.remaining - real_delta_secs).max(0.0);
+        self.remaining > 0.0
+    }
+}
+
+/// Floating text for a hit - criticals get an exclamation mark, blocked hits say so
+pub fn damage_text(amount: f32, outcome: AttackOutcome) -> String {
+    match outcome {
+        AttackOutcome::Critical => format!("{:.0}!", amount),
+        AttackOutcome::Block => format!("{:.0} (blocked)", amount),
+        _ => format!("{:.0}", amount),
+    }
+}
+
+fn avoidance_text(outcome: AttackOutcome) -> &'static str {
+    match outcome {
+        AttackOutcome::Dodge => "Dodge",
+        AttackOutcome::Parry => "Parry",
+        _ => "Miss",
+    }
+}
+
+fn spawn_floating_text(commands: &mut Commands, world_position: Vec3, text: String, color: Color, font_size: f32) {
+    let spread = thread_rng().gen_range(-FLOATING_TEXT_SPREAD..=FLOATING_TEXT_SPREAD);
+
+    commands.spawn((
+        Text::new(text),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextColor(color),
+        Node {
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        // Hidden until it has been placed on screen
+        Visibility::Hidden,
+        FloatingCombatText {
+            world_position: world_position + Vec3::new(spread, FLOATING_TEXT_HEIGHT, 0.0),
+            color,
+            timer: Timer::from_seconds(FLOATING_TEXT_LIFETIME, TimerMode::Once),
+        },
+    ));
+}
+
+// System to spawn floating text for damage, healing, avoided attacks and status effects involving the player
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_combat_text(
+    mut commands: Commands,
+    game_config: Res<GameConfig>,
+    config: Res<ProgressionConfig>,
+    status_config: Res<StatusEffectConfig>,
+    mut damage_dealt_events: EventReader<DamageDealt>,
+    mut avoided_events: EventReader<AttackAvoided>,
+    mut healing_done_events: EventReader<HealingDone>,
+    mut effect_applied_events: EventReader<StatusEffectApplied>,
+    player_query: Query<Entity, With<Player>>,
+    position_query: Query<(&Transform, Has<Enemy>)>,
+) {
+    let Ok(player_entity) = player_query.single() else {
+        return;
+    };
+    let feedback = &game_config.combat_feedback;
+
+    let involves_player = |source: Option<Entity>, target: Entity| target == player_entity || source == Some(player_entity);
+    let position_of = |entity: Entity| position_query.get(entity).ok().map(|(transform, is_enemy)| aim_point(transform, is_enemy));
+
+    for event in damage_dealt_events.read() {
+        if !feedback.damage_numbers || !involves_player(event.attacker, event.target) {
+            continue;
+        }
+        let Some(position) = position_of(event.target) else {
+            continue;
+        };
+
+        let color = config
+            .get_damage_type_config_by_enum(event.damage_type)
+            .map(|damage_type| damage_type.color())
+            .unwrap_or(Color::WHITE);
+        let font_size = if event.outcome.is_critical() {
+            FLOATING_TEXT_FONT_SIZE * CRITICAL_FONT_SCALE
+        } else {
+            FLOATING_TEXT_FONT_SIZE
+        };
+        spawn_floating_text(&mut commands, position, damage_text(event.amount, event.outcome), color, font_size);
+    }
+
+    for event in avoided_events.read() {
+        if !feedback.avoidance_text || !involves_player(event.attacker, event.target) {
+            continue;
+        }
+        if let Some(position) = position_of(event.target) {
+            let text = avoidance_text(event.outcome).to_string();
+            spawn_floating_text(&mut commands, position, text, Color::srgb(0.75, 0.75, 0.75), FLOATING_TEXT_FONT_SIZE);
+        }
+    }
+
+    for event in healing_done_events.read() {
+        if !feedback.healing_numbers || event.amount <= 0.0 || !involves_player(event.healer, event.target) {
+            continue;
+        }
+        if let Some(position) = position_of(event.target) {
+            let text = format!("+{:.0}", event.amount);
+            spawn_floating_text(&mut commands, position, text, Color::srgb(0.3, 1.0, 0.4), FLOATING_TEXT_FONT_SIZE);
+        }
+    }
+
+    for event in effect_applied_events.read() {
+        if !feedback.status_effect_text || !involves_player(event.source, event.target) {
+            continue;
+        }
+        let (Some(position), Some(effect)) = (position_of(event.target), status_config.get_effect(&event.effect_id)) else {
+            continue;
+        };
+
+        let text = if event.stacks > 1 {
+            format!("{} x{}", effect.display_name, event.stacks)
+        } else {
+            effect.display_name.clone()
+        };
+        let color = if effect.beneficial { Color::srgb(1.0, 0.85, 0.3) } else { Color::srgb(0.8, 0.5, 1.0) };
+        spawn_floating_text(&mut commands, position, text, color, FLOATING_TEXT_FONT_SIZE * 0.8);
+    }
+}
+
+// System to float combat text upwards, keep it over its point in the world and fade it out
+pub fn update_combat_text(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    mut text_query: Query<(Entity, &mut FloatingCombatText, &mut Node, &mut TextColor, &mut Visibility)>,
+) {
+    let camera = camera_query.single().ok();
+
+    for (entity, mut text, mut node, mut color, mut visibility) in &mut text_query {
+        text.timer.tick(time.delta());
+        if text.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        text.world_position.y += FLOATING_TEXT_RISE_SPEED * time.delta_secs();
+
+        // Text behind the camera has no place on screen
+        let screen_position = camera.and_then(|(camera, camera_transform)| camera.world_to_viewport(camera_transform, text.world_position).ok());
+        let Some(screen_position) = screen_position else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        node.left = Val::Px(screen_position.x);
+        node.top = Val::Px(screen_position.y);
+        *visibility = Visibility::Inherited;
+
+        // Hold full opacity for the first half, then fade out
+        let alpha = (2.0 - 2.0 * text.timer.fraction()).min(1.0);
+        color.0 = text.color.with_alpha(alpha);
+    }
+}
+
+// System to flash enemies (and every mesh of their model) when they take damage
+pub fn trigger_hit_flash(
+    mut commands: Commands,
+    game_config: Res<GameConfig>,
+    flash_material: Res<HitFlashMaterial>,
+    mut damage_dealt_events: EventReader<DamageDealt>,
+    enemy_query: Query<(), With<Enemy>>,
+    children_query: Query<&Children>,
+    mut mesh_query: Query<(&mut MeshMaterial3d<StandardMaterial>, Option<&mut HitFlash>)>,
+) {
+    for event in damage_dealt_events.read() {
+        if !game_config.combat_feedback.hit_flash || event.amount <= 0.0 || enemy_query.get(event.target).is_err() {
+            continue;
+        }
+
+        for entity in std::iter::once(event.target).chain(children_query.iter_descendants(event.target)) {
+            let Ok((mut material, flash)) = mesh_query.get_mut(entity) else {
+                continue;
+            };
+
+            // Already flashing - keep the original material and just restart the flash
+            if let Some(mut flash) = flash {
+                flash.timer.reset();
+                continue;
+            }
+            if material.0 == flash_material.0 {
+                continue;
+            }
+
+            commands.entity(entity).insert(HitFlash {
+                original: material.0.clone(),
+                timer: Timer::from_seconds(HIT_FLASH_DURATION, TimerMode::Once),
+            });
+            material.0 = flash_material.0.clone();
+        }
+    }
+}
+
+// System to restore enemy materials once their hit flash ends
+pub fn update_hit_flashes(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut flash_query: Query<(Entity, &mut HitFlash, &mut MeshMaterial3d<StandardMaterial>)>,
+) {
+    for (entity, mut flash, mut material) in &mut flash_query {
+        flash.timer.tick(time.delta());
+        if flash.timer.finished() {
+            material.0 = flash.original.clone();
+            commands.entity(entity).remove::<HitFlash>();
+        }
+    }
+}
+
+// System to briefly slow the game when the player lands a hit on an enemy
+pub fn apply_hit_stop(
+    game_config: Res<GameConfig>,
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut hit_stop: ResMut<HitStop>,
+    mut damage_dealt_events: EventReader<DamageDealt>,
+    player_query: Query<Entity, With<Player>>,
+    enemy_query: Query<(), With<Enemy>>,
+) {
+    let feedback = &game_config.combat_feedback;
+    let player_entity = player_query.single().ok();
+
+    for event in damage_dealt_events.read() {
+        let player_hit_enemy = event.attacker.is_some() && event.attacker == player_entity && enemy_query.get(event.target).is_ok();
+        if feedback.hit_stop && player_hit_enemy && event.amount > 0.0 {
+            let multiplier = if event.outcome.is_critical() { 2.0 } else { 1.0 };
+            hit_stop.trigger(feedback.hit_stop_duration * multiplier);
+        }
+    }
+
+    let speed = if hit_stop.tick(real_time.delta_secs()) { HIT_STOP_TIME_SCALE } else { 1.0 };
+    if virtual_time.relative_speed() != speed {
+        virtual_time.set_relative_speed(speed);
+    }
+}
+
+// System to drop any running hit-stop when leaving the game so menus don't run in slow motion
+pub fn reset_hit_stop(mut virtual_time: ResMut<Time<Virtual>>, mut hit_stop: ResMut<HitStop>) {
+    *hit_stop = HitStop::default();
+    virtual_time.set_relative_speed(1.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_damage_text_marks_criticals_and_blocks() {
+        assert_eq!(damage_text(42.4, AttackOutcome::Hit), "42");
+        assert_eq!(damage_text(84.6, AttackOutcome::Critical), "85!");
+        assert_eq!(damage_text(21.0, AttackOutcome::Block), "21 (blocked)");
+        assert_eq!(avoidance_text(AttackOutcome::Dodge), "Dodge");
+    }
+
+    #[test]
+    fn test_hit_stop_keeps_the_longest_trigger() {
+        let mut hit_stop = HitStop::default();
+        assert!(!hit_stop.tick(0.016));
+
+        hit_stop.trigger(0.08);
+        hit_stop.trigger(0.04);
+        assert!(hit_stop.tick(0.05));
+        assert!(!hit_stop.tick(0.05));
+        assert_eq!(hit_stop.remaining, 0.0);
+    }
+
+    #[test]
+    fn test_reset_hit_stop_restores_normal_speed() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(HitStop { remaining: 0.5 })
+            .add_systems(Update, reset_hit_stop);
+        app.world_mut().resource_mut::<Time<Virtual>>().set_relative_speed(HIT_STOP_TIME_SCALE);
+
+        app.update();
+
+        assert_eq!(app.world().resource::<HitStop>().remaining, 0.0);
+        assert_eq!(app.world().resource::<Time<Virtual>>().relative_speed(), 1.0);
+    }
+}
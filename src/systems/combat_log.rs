@@ -0,0 +1,383 @@
+use bevy::prelude::*;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::io::Write;
+use std::path::Path;
+use crate::components::Player;
+use crate::systems::combat::Enemy;
+use crate::systems::damage::{DamageDealt, DamageSource, HealingDone, AttackAvoided};
+use crate::systems::attack_table::AttackOutcome;
+use crate::systems::status_effects::StatusEffectApplied;
+
+/// Oldest entries are dropped past this so long sessions don't grow without bound
+const MAX_COMBAT_LOG_ENTRIES: usize = 20_000;
+/// Seconds without any combat activity before the meter starts a new encounter
+const ENCOUNTER_TIMEOUT: f64 = 6.0;
+/// Where Ctrl+L writes exported encounters
+const COMBAT_LOG_EXPORT_DIR: &str = "combat_logs";
+
+// Combat Log Types
+/// Who was involved in a log entry - the entity bits keep two enemies with the same name apart
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CombatLogActor {
+    pub entity: u64,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum CombatLogEvent {
+    Hit {
+        amount: f32,
+        raw_amount: f32,
+        damage_type: String,
+        critical: bool,
+        blocked: bool,
+    },
+    /// Missed, dodged or parried
+    Miss {
+        outcome: AttackOutcome,
+    },
+    Heal {
+        amount: f32,
+        overheal: f32,
+    },
+    EffectApplied {
+        effect_id: String,
+        stacks: u32,
+    },
+    Death,
+}
+
+/// One line of the combat log - serialized as a single JSON object per line when exported
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CombatLogEntry {
+    pub timestamp: f64, // Seconds since app start
+    pub source: Option<CombatLogActor>,
+    pub target: CombatLogActor,
+    pub ability: Option<String>, // "auto_attack", ability id or status effect id
+    #[serde(flatten)]
+    pub event: CombatLogEvent,
+}
+
+/// Damage and healing done by one actor during the current encounter
+#[derive(Debug, Clone, Default)]
+pub struct MeterTotals {
+    pub name: String,
+    pub damage: f32,
+    pub healing: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeterRow {
+    pub name: String,
+    pub damage: f32,
+    pub dps: f32,
+    pub healing: f32,
+    pub hps: f32,
+}
+
+/// Every resolved hit, miss, heal, effect application and death, plus the DPS/HPS meter built from them
+#[derive(Resource, Debug, Default)]
+pub struct CombatLog {
+    pub entries: VecDeque<CombatLogEntry>,
+    pub encounter_start: Option<f64>,
+    pub last_activity: f64,
+    pub meter: HashMap<u64, MeterTotals>,
+    pub meter_visible: bool,
+}
+
+impl CombatLog {
+    pub fn record(&mut self, entry: CombatLogEntry) {
+        // A quiet spell ends the encounter - the next entry starts a fresh meter
+        if self.encounter_start.is_none() || entry.timestamp - self.last_activity > ENCOUNTER_TIMEOUT {
+            self.encounter_start = Some(entry.timestamp);
+            self.meter.clear();
+        }
+        self.last_activity = entry.timestamp;
+
+        if let Some(source) = &entry.source {
+            let totals = self.meter.entry(source.entity).or_insert_with(|| MeterTotals {
+                name: source.name.clone(),
+                ..default()
+            });
+            match &entry.event {
+                CombatLogEvent::Hit { amount, .. } => totals.damage += amount,
+                CombatLogEvent::Heal { amount, .. } => totals.healing += amount,
+                _ => {}
+            }
+        }
+
+        self.entries.push_back(entry);
+        while self.entries.len() > MAX_COMBAT_LOG_ENTRIES {
+            self.entries.pop_front();
+        }
+    }
+
+    /// Length of the current encounter, at least a second so a single hit doesn't read as huge DPS
+    pub fn encounter_duration(&self) -> f64 {
+        self.encounter_start
+            .map_or(0.0, |start| self.last_activity - start)
+            .max(1.0)
+    }
+
+    /// Meter rows for the current encounter, biggest contributors first
+    pub fn meter_rows(&self) -> Vec<MeterRow> {
+        let duration = self.encounter_duration() as f32;
+        let mut rows: Vec<MeterRow> = self
+            .meter
+            .values()
+            .filter(|totals| totals.damage > 0.0 || totals.healing > 0.0)
+            .map(|totals| MeterRow {
+                name: totals.name.clone(),
+                damage: totals.damage,
+                dps: totals.damage / duration,
+                healing: totals.healing,
+                hps: totals.healing / duration,
+            })
+            .collect();
+        rows.sort_by(|a, b| (b.damage + b.healing).total_cmp(&(a.damage + a.healing)));
+        rows
+    }
+
+    /// Write every retained entry as JSON Lines
+    pub fn write_jsonl(&self, mut writer: impl Write) -> std::io::Result<()> {
+        for entry in &self.entries {
+            serde_json::to_writer(&mut writer, entry)?;
+            writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    pub fn export_jsonl(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = std::fs::File::create(path)?;
+        self.write_jsonl(std::io::BufWriter::new(file))
+    }
+}
+
+// Combat Meter UI Components
+#[derive(Component)]
+pub struct CombatMeterPanel;
+
+#[derive(Component)]
+pub struct CombatMeterText;
+
+/// Label for the ability column of a log entry
+fn ability_label(source: &DamageSource) -> String {
+    match source {
+        DamageSource::AutoAttack => "auto_attack".to_string(),
+        DamageSource::Ability(id) | DamageSource::StatusEffect(id) | DamageSource::Environment(id) => id.clone(),
+    }
+}
+
+fn combat_log_actor(entity: Entity, actor_query: &Query<(Option<&Enemy>, Option<&Name>, Has<Player>)>) -> CombatLogActor {
+    let name = match actor_query.get(entity) {
+        Ok((Some(enemy), _, _)) => enemy.name.clone(),
+        Ok((None, Some(name), _)) => name.to_string(),
+        Ok((None, None, true)) => "Player".to_string(),
+        _ => "Unknown".to_string(),
+    };
+    CombatLogActor { entity: entity.to_bits(), name }
+}
+
+// System to record resolved combat events in the combat log
+pub fn record_combat_log(
+    time: Res<Time>,
+    mut combat_log: ResMut<CombatLog>,
+    mut damage_dealt_events: EventReader<DamageDealt>,
+    mut avoided_events: EventReader<AttackAvoided>,
+    mut healing_done_events: EventReader<HealingDone>,
+    mut effect_applied_events: EventReader<StatusEffectApplied>,
+    actor_query: Query<(Option<&Enemy>, Option<&Name>, Has<Player>)>,
+) {
+    let timestamp = time.elapsed_secs_f64();
+
+    for event in damage_dealt_events.read() {
+        let source = event.attacker.map(|attacker| combat_log_actor(attacker, &actor_query));
+        let target = combat_log_actor(event.target, &actor_query);
+
+        combat_log.record(CombatLogEntry {
+            timestamp,
+            source: source.clone(),
+            target: target.clone(),
+            ability: Some(ability_label(&event.source)),
+            event: CombatLogEvent::Hit {
+                amount: event.amount,
+                raw_amount: event.raw_amount,
+                damage_type: format!("{:?}", event.damage_type).to_lowercase(),
+                critical: event.outcome == AttackOutcome::Critical,
+                blocked: event.outcome == AttackOutcome::Block,
+            },
+        });
+
+        if event.killing_blow {
+            combat_log.record(CombatLogEntry {
+                timestamp,
+                source,
+                target,
+                ability: Some(ability_label(&event.source)),
+                event: CombatLogEvent::Death,
+            });
+        }
+    }
+
+    for event in avoided_events.read() {
+        combat_log.record(CombatLogEntry {
+            timestamp,
+            source: event.attacker.map(|attacker| combat_log_actor(attacker, &actor_query)),
+            target: combat_log_actor(event.target, &actor_query),
+            ability: Some(ability_label(&event.source)),
+            event: CombatLogEvent::Miss { outcome: event.outcome },
+        });
+    }
+
+    for event in healing_done_events.read() {
+        combat_log.record(CombatLogEntry {
+            timestamp,
+            source: event.healer.map(|healer| combat_log_actor(healer, &actor_query)),
+            target: combat_log_actor(event.target, &actor_query),
+            ability: Some(ability_label(&event.source)),
+            event: CombatLogEvent::Heal {
+                amount: event.amount,
+                overheal: event.overheal,
+            },
+        });
+    }
+
+    for event in effect_applied_events.read() {
+        combat_log.record(CombatLogEntry {
+            timestamp,
+            source: event.source.map(|source| combat_log_actor(source, &actor_query)),
+            target: combat_log_actor(event.target, &actor_query),
+            ability: Some(event.effect_id.clone()),
+            event: CombatLogEvent::EffectApplied {
+                effect_id: event.effect_id.clone(),
+                stacks: event.stacks,
+            },
+        });
+    }
+}
+
+// System to toggle the meter (L) and export the combat log (Ctrl+L)
+pub fn handle_combat_log_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut combat_log: ResMut<CombatLog>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyL) {
+        return;
+    }
+
+    if keyboard.pressed(KeyCode::ControlLeft) {
+        let seconds = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let path = Path::new(COMBAT_LOG_EXPORT_DIR).join(format!("combat_log_{}.jsonl", seconds));
+
+        match combat_log.export_jsonl(&path) {
+            Ok(()) => info!("📜 Exported {} combat log entries to {}", combat_log.entries.len(), path.display()),
+            Err(e) => error!("Failed to export combat log: {}", e),
+        }
+        return;
+    }
+
+    combat_log.meter_visible = !combat_log.meter_visible;
+    info!("📊 Combat meter {}", if combat_log.meter_visible { "shown" } else { "hidden" });
+}
+
+// System to refresh the DPS/HPS meter panel
+pub fn update_combat_meter_ui(
+    combat_log: Res<CombatLog>,
+    mut panel_query: Query<&mut Node, With<CombatMeterPanel>>,
+    mut text_query: Query<&mut Text, With<CombatMeterText>>,
+) {
+    if let Ok(mut panel) = panel_query.single_mut() {
+        let display = if combat_log.meter_visible { Display::Flex } else { Display::None };
+        if panel.display != display {
+            panel.display = display;
+        }
+    }
+
+    if !combat_log.meter_visible || !combat_log.is_changed() {
+        return;
+    }
+
+    let Ok(mut text) = text_query.single_mut() else {
+        return;
+    };
+
+    let rows = combat_log.meter_rows();
+    if rows.is_empty() {
+        **text = "No combat yet".to_string();
+        return;
+    }
+
+    let mut lines = vec![format!("Encounter: {:.0}s", combat_log.encounter_duration())];
+    for row in rows {
+        lines.push(format!(
+            "{}: {:.0} dmg ({:.1} DPS) | {:.0} heal ({:.1} HPS)",
+            row.name, row.damage, row.dps, row.healing, row.hps
+        ));
+    }
+    **text = lines.join("\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn actor(entity: u64, name: &str) -> CombatLogActor {
+        CombatLogActor { entity, name: name.to_string() }
+    }
+
+    fn hit(timestamp: f64, amount: f32) -> CombatLogEntry {
+        CombatLogEntry {
+            timestamp,
+            source: Some(actor(1, "Player")),
+            target: actor(2, "Wild Boar"),
+            ability: Some("auto_attack".to_string()),
+            event: CombatLogEvent::Hit { amount, raw_amount: amount, damage_type: "slashing".to_string(), critical: false, blocked: false },
+        }
+    }
+
+    #[test]
+    fn test_meter_tracks_dps_per_encounter() {
+        let mut combat_log = CombatLog::default();
+        combat_log.record(hit(10.0, 20.0));
+        combat_log.record(hit(12.0, 20.0));
+        combat_log.record(hit(14.0, 40.0));
+
+        let rows = combat_log.meter_rows();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].damage, 80.0);
+        assert_eq!(rows[0].dps, 20.0);
+
+        // A long gap starts a new encounter
+        combat_log.record(hit(30.0, 10.0));
+        assert_eq!(combat_log.meter_rows()[0].damage, 10.0);
+        assert_eq!(combat_log.entries.len(), 4);
+    }
+
+    #[test]
+    fn test_jsonl_export_writes_one_object_per_line() {
+        let mut combat_log = CombatLog::default();
+        combat_log.record(hit(1.0, 15.0));
+        combat_log.record(CombatLogEntry { event: CombatLogEvent::Death, ..hit(1.0, 0.0) });
+
+        let mut output = Vec::new();
+        combat_log.write_jsonl(&mut output).unwrap();
+        let lines: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["event"], "hit");
+        assert_eq!(lines[0]["amount"], 15.0);
+        assert_eq!(lines[1]["event"], "death");
+        assert_eq!(lines[1]["target"]["name"], "Wild Boar");
+    }
+}
@@ -0,0 +1,125 @@
+use bevy::prelude::*;
+use crate::resources::{ProgressionConfig, AbilityUnlock, ComboFollowUp};
+use crate::systems::abilities::AbilityUsed;
+
+// Combo Components
+/// Opened by using a combo opener - its follow-ups are enabled or empowered until the timer runs out
+#[derive(Component, Debug)]
+pub struct ComboWindow {
+    pub opener: String,
+    pub follow_ups: Vec<ComboFollowUp>,
+    pub timer: Timer,
+}
+
+impl ComboWindow {
+    /// The window an ability opens, or None if it doesn't start a combo
+    pub fn open(ability: &AbilityUnlock) -> Option<Self> {
+        let combo = ability.combo.as_ref()?;
+        Some(Self {
+            opener: ability.name.clone(),
+            follow_ups: combo.follow_ups.clone(),
+            timer: Timer::from_seconds(combo.window, TimerMode::Once),
+        })
+    }
+
+    pub fn follow_up(&self, ability_name: &str) -> Option<&ComboFollowUp> {
+        if self.timer.finished() {
+            return None;
+        }
+        self.follow_ups.iter().find(|follow_up| follow_up.ability == ability_name)
+    }
+}
+
+/// Damage and healing multiplier an ability gets from the caster's open combo window
+pub fn combo_multiplier(window: Option<&ComboWindow>, ability_name: &str) -> Option<f32> {
+    window
+        .and_then(|window| window.follow_up(ability_name))
+        .map(|follow_up| follow_up.damage_multiplier)
+}
+
+// System to close combo windows that weren't followed up in time
+pub fn tick_combo_windows(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut window_query: Query<(Entity, &mut ComboWindow)>,
+) {
+    for (entity, mut window) in &mut window_query {
+        window.timer.tick(time.delta());
+        if window.timer.finished() {
+            commands.entity(entity).remove::<ComboWindow>();
+        }
+    }
+}
+
+// System to open combo windows as openers are used and spend them on follow-ups
+pub fn update_combo_windows(
+    mut commands: Commands,
+    config: Res<ProgressionConfig>,
+    mut ability_used_events: EventReader<AbilityUsed>,
+    window_query: Query<&ComboWindow>,
+) {
+    for event in ability_used_events.read() {
+        let Some((_, _, ability)) = config.find_ability(&event.ability_name) else {
+            continue;
+        };
+
+        let window = window_query.get(event.caster).ok();
+        let followed_up = window.is_some_and(|window| window.follow_up(&ability.name).is_some());
+        if let Some(window) = window.filter(|_| followed_up) {
+            info!("🔗 Combo: {} → {}", window.opener, ability.name);
+        }
+
+        // Other abilities leave the window open - only a follow-up spends it
+        if let Some(window) = ComboWindow::open(ability) {
+            commands.entity(event.caster).insert(window);
+        } else if followed_up {
+            commands.entity(event.caster).remove::<ComboWindow>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(seconds: f32) -> ComboWindow {
+        ComboWindow {
+            opener: "Basic Slash".to_string(),
+            follow_ups: vec![ComboFollowUp { ability: "Heavy Strike".to_string(), damage_multiplier: 1.25 }],
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+
+    #[test]
+    fn test_follow_ups_are_empowered_until_the_window_closes() {
+        let mut window = window(3.0);
+
+        assert_eq!(combo_multiplier(Some(&window), "Heavy Strike"), Some(1.25));
+        assert_eq!(combo_multiplier(Some(&window), "Blade Flurry"), None);
+        assert_eq!(combo_multiplier(None, "Heavy Strike"), None);
+
+        window.timer.tick(std::time::Duration::from_secs_f32(3.5));
+        assert_eq!(combo_multiplier(Some(&window), "Heavy Strike"), None);
+    }
+
+    #[test]
+    fn test_only_openers_open_a_window() {
+        let slash: AbilityUnlock = serde_json::from_str(r#"{
+            "name": "Basic Slash",
+            "ability_type": "Active",
+            "description": "",
+            "requirements": [],
+            "combo": { "window": 3.0, "follow_ups": [{ "ability": "Heavy Strike" }] }
+        }"#)
+        .unwrap();
+
+        let window = ComboWindow::open(&slash).unwrap();
+        assert_eq!(window.opener, "Basic Slash");
+        assert_eq!(window.timer.duration().as_secs_f32(), 3.0);
+        // Follow-ups without a multiplier are enabled but not empowered
+        assert_eq!(combo_multiplier(Some(&window), "Heavy Strike"), Some(1.0));
+
+        let plain = AbilityUnlock { combo: None, ..slash };
+        assert!(ComboWindow::open(&plain).is_none());
+    }
+}
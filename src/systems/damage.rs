@@ -0,0 +1,342 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::collections::HashMap;
+use crate::components::{CharacterLoadouts, CharacterSkills, ArmorType, AttackPosition, DamageType, GodMode};
+use crate::resources::{ProgressionConfig, DamageTypeConfig, StatusEffectConfig};
+use crate::systems::combat::Health;
+use crate::systems::attack_table::{AttackOutcome, BLOCK_DAMAGE_REDUCTION, CRITICAL_DAMAGE_MULTIPLIER, positional_damage_multiplier};
+
+/// Resistance can never remove more than 100% of a hit
+const MAX_RESISTANCE: f32 = 1.0;
+/// Stacked weaknesses are capped at triple damage
+const MIN_RESISTANCE: f32 = -2.0;
+
+/// Special effect that ignores the armor portion of a hit's resistances
+const ARMOR_PENETRATION_EFFECT: &str = "armor_penetration";
+
+/// Whether a special effect does anything when it procs - armor penetration, or a status effect it triggers
+pub fn is_handled_special_effect(special_effect: &str, status_config: &StatusEffectConfig) -> bool {
+    special_effect == ARMOR_PENETRATION_EFFECT || status_config.effect_for_special(special_effect).is_some()
+}
+
+/// Where a piece of damage originated - used for logging and downstream systems
+#[derive(Debug, Clone, PartialEq)]
+pub enum DamageSource {
+    AutoAttack,
+    Ability(String),
+    StatusEffect(String),
+    Environment(String),
+}
+
+/// Request to deal damage - every source of damage goes through this event
+#[derive(Event, Debug, Clone)]
+pub struct DamageEvent {
+    pub attacker: Option<Entity>,
+    pub target: Entity,
+    pub damage_type: DamageType,
+    pub amount: f32,
+    pub source: DamageSource,
+    /// Result of the attack table roll - spells and damage over time always hit
+    pub outcome: AttackOutcome,
+    /// Side of the target a weapon attack came from - None for damage with no direction (spells, damage over time)
+    pub position: Option<AttackPosition>,
+    /// Status effect applied to the target only if this hit lands (harmful abilities)
+    pub status_effect: Option<String>,
+}
+
+/// Damage after resistances and special effects have been resolved and applied
+#[derive(Event, Debug, Clone)]
+pub struct DamageDealt {
+    pub attacker: Option<Entity>,
+    pub target: Entity,
+    pub damage_type: DamageType,
+    pub source: DamageSource,
+    /// Damage after crit and positional bonuses, before block, armor and resistances
+    pub raw_amount: f32,
+    /// Damage actually removed from the target's health
+    pub amount: f32,
+    /// Total resistance applied (negative values are weaknesses)
+    pub resistance: f32,
+    /// Special effects from damage_types.json that procced on this hit
+    pub special_effects: Vec<String>,
+    /// Critical or Block - avoided attacks never deal damage
+    pub outcome: AttackOutcome,
+    pub position: Option<AttackPosition>,
+    pub status_effect: Option<String>,
+    pub killing_blow: bool,
+}
+
+/// An attack that was missed, dodged or parried and dealt no damage
+#[derive(Event, Debug, Clone)]
+pub struct AttackAvoided {
+    pub attacker: Option<Entity>,
+    pub target: Entity,
+    pub source: DamageSource,
+    pub outcome: AttackOutcome,
+}
+
+/// Request to restore health - every source of healing goes through this event
+#[derive(Event, Debug, Clone)]
+pub struct HealEvent {
+    pub healer: Option<Entity>,
+    pub target: Entity,
+    pub amount: f32,
+    pub source: DamageSource,
+}
+
+/// Healing after it has been applied to the target
+#[derive(Event, Debug, Clone)]
+pub struct HealingDone {
+    pub healer: Option<Entity>,
+    pub target: Entity,
+    pub source: DamageSource,
+    /// Health actually restored
+    pub amount: f32,
+    /// Healing wasted because the target was already at full health
+    pub overheal: f32,
+}
+
+/// Per-entity resistances layered on top of armor type
+#[derive(Component, Debug, Clone, Default)]
+pub struct DamageResistances {
+    /// Armor worn by the entity - players fall back to their active loadout
+    pub armor_type: Option<ArmorType>,
+    /// Resistance keys from damage_types.json (e.g. "fire_resistance") -> strength (1.0 = full value)
+    pub resistances: HashMap<String, f32>,
+}
+
+/// Outcome of running a hit through damage_types.json
+#[derive(Debug, Clone, PartialEq)]
+pub struct DamageResolution {
+    pub amount: f32,
+    pub resistance: f32,
+    pub special_effects: Vec<String>,
+}
+
+/// Resolve a hit against a target's armor and resistances
+/// Special effects are rolled first so armor penetration can affect the same hit
+/// The armor value scales how much of the armor's listed resistance applies
+pub fn resolve_damage(
+    damage_config: Option<&DamageTypeConfig>,
+    amount: f32,
+    armor_type: Option<ArmorType>,
+    armor_value: f32,
+    resistances: &HashMap<String, f32>,
+    rng: &mut impl Rng,
+) -> DamageResolution {
+    let Some(damage_config) = damage_config else {
+        // Unknown damage types pass straight through
+        return DamageResolution {
+            amount: amount.max(0.0),
+            resistance: 0.0,
+            special_effects: Vec::new(),
+        };
+    };
+
+    // Roll special effects
+    let special_effects: Vec<String> = damage_config.special_effects
+        .iter()
+        .filter(|effect| {
+            let chance = damage_config.special_effect_chances.get(*effect).copied().unwrap_or(0.0);
+            chance > 0.0 && rng.gen::<f32>() < chance
+        })
+        .cloned()
+        .collect();
+
+    let ignores_armor = special_effects.iter().any(|effect| effect == ARMOR_PENETRATION_EFFECT);
+
+    // Armor resistance
+    let mut resistance = 0.0;
+    if let Some(armor_type) = armor_type {
+        if !ignores_armor {
+            resistance += damage_config.resistances.get(armor_type.resistance_key()).copied().unwrap_or(0.0) * armor_value;
+        }
+    }
+
+    // Creature-specific resistances and weaknesses
+    for (key, strength) in resistances {
+        if let Some(value) = damage_config.resistances.get(key) {
+            resistance += value * strength;
+        }
+    }
+
+    let resistance = resistance.clamp(MIN_RESISTANCE, MAX_RESISTANCE);
+
+    DamageResolution {
+        amount: (amount * (1.0 - resistance)).max(0.0),
+        resistance,
+        special_effects,
+    }
+}
+
+/// System to resolve damage requests into applied damage
+pub fn resolve_damage_events(
+    mut damage_events: EventReader<DamageEvent>,
+    mut damage_dealt_writer: EventWriter<DamageDealt>,
+    mut avoided_writer: EventWriter<AttackAvoided>,
+    config: Res<ProgressionConfig>,
+    mut target_query: Query<(&mut Health, Option<&DamageResistances>, Option<&CharacterLoadouts>, Option<&CharacterSkills>, Has<GodMode>)>,
+) {
+    let mut rng = thread_rng();
+    let no_resistances = HashMap::new();
+
+    for event in damage_events.read() {
+        let Ok((mut health, resistances, loadouts, skills, god_mode)) = target_query.get_mut(event.target) else {
+            continue; // Target can't take damage
+        };
+
+        // God mode (dev console) ignores all incoming damage
+        if god_mode || !health.is_alive() {
+            continue;
+        }
+
+        if event.outcome.is_avoided() {
+            avoided_writer.write(AttackAvoided {
+                attacker: event.attacker,
+                target: event.target,
+                source: event.source.clone(),
+                outcome: event.outcome,
+            });
+            continue;
+        }
+
+        // Crits and flank or rear attacks hit harder, and a blocked hit is mitigated before armor gets a say
+        let critical = if event.outcome.is_critical() { CRITICAL_DAMAGE_MULTIPLIER } else { 1.0 };
+        let raw_amount = event.amount * critical * event.position.map_or(1.0, positional_damage_multiplier);
+        let unblocked = if event.outcome == AttackOutcome::Block { raw_amount * (1.0 - BLOCK_DAMAGE_REDUCTION) } else { raw_amount };
+
+        // Armor comes from explicit resistances first, then the active loadout
+        let armor_type = resistances
+            .and_then(|r| r.armor_type)
+            .or_else(|| loadouts.and_then(|l| l.active_loadout()).map(|l| l.armor_type));
+        let armor_value = armor_type.map_or(1.0, |armor_type| config.armor_value(armor_type, skills));
+
+        let resolution = resolve_damage(
+            config.get_damage_type_config_by_enum(event.damage_type),
+            unblocked,
+            armor_type,
+            armor_value,
+            resistances.map(|r| &r.resistances).unwrap_or(&no_resistances),
+            &mut rng,
+        );
+
+        let damage_taken = health.take_damage(resolution.amount);
+
+        damage_dealt_writer.write(DamageDealt {
+            attacker: event.attacker,
+            target: event.target,
+            damage_type: event.damage_type,
+            source: event.source.clone(),
+            raw_amount,
+            amount: damage_taken,
+            resistance: resolution.resistance,
+            special_effects: resolution.special_effects,
+            outcome: event.outcome,
+            position: event.position,
+            status_effect: event.status_effect.clone(),
+            killing_blow: !health.is_alive(),
+        });
+    }
+}
+
+/// System to apply healing requests
+pub fn resolve_heal_events(
+    mut heal_events: EventReader<HealEvent>,
+    mut healing_done_writer: EventWriter<HealingDone>,
+    mut target_query: Query<&mut Health>,
+) {
+    for event in heal_events.read() {
+        let Ok(mut health) = target_query.get_mut(event.target) else {
+            continue;
+        };
+
+        // Dead targets can't be healed back up
+        if !health.is_alive() {
+            continue;
+        }
+
+        let healed = health.heal(event.amount.max(0.0));
+
+        healing_done_writer.write(HealingDone {
+            healer: event.healer,
+            target: event.target,
+            source: event.source.clone(),
+            amount: healed,
+            overheal: (event.amount - healed).max(0.0),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+
+    fn slashing_config(bleed_chance: f32) -> DamageTypeConfig {
+        DamageTypeConfig {
+            display_name: "Slashing".to_string(),
+            description: "Test".to_string(),
+            damage_category: "Physical".to_string(),
+            color_hex: "#FFFFFF".to_string(),
+            resistances: HashMap::from([
+                ("heavy_armor".to_string(), 0.4),
+                ("fire_resistance".to_string(), 0.5),
+            ]),
+            special_effects: vec!["bleeding_chance".to_string()],
+            special_effect_chances: HashMap::from([("bleeding_chance".to_string(), bleed_chance)]),
+        }
+    }
+
+    #[test]
+    fn test_armor_resistance_reduces_damage() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let config = slashing_config(0.0);
+
+        let unarmored = resolve_damage(Some(&config), 100.0, None, 1.0, &HashMap::new(), &mut rng);
+        let heavy = resolve_damage(Some(&config), 100.0, Some(ArmorType::Heavy), 1.0, &HashMap::new(), &mut rng);
+
+        assert_eq!(unarmored.amount, 100.0);
+        assert!((heavy.amount - 60.0).abs() < 0.001);
+        assert!(heavy.special_effects.is_empty());
+
+        // Trained armor applies more of its listed resistance
+        let trained = resolve_damage(Some(&config), 100.0, Some(ArmorType::Heavy), 1.5, &HashMap::new(), &mut rng);
+        assert!((trained.amount - 40.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_creature_resistances_stack_with_armor() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let config = slashing_config(0.0);
+        let resistances = HashMap::from([("fire_resistance".to_string(), 1.0)]);
+
+        let result = resolve_damage(Some(&config), 100.0, Some(ArmorType::Heavy), 1.0, &resistances, &mut rng);
+
+        assert!((result.resistance - 0.9).abs() < 0.001);
+        assert!((result.amount - 10.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_guaranteed_special_effect_procs() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let config = slashing_config(1.0);
+
+        let result = resolve_damage(Some(&config), 10.0, None, 1.0, &HashMap::new(), &mut rng);
+        assert_eq!(result.special_effects, vec!["bleeding_chance".to_string()]);
+    }
+
+    #[test]
+    fn test_every_special_effect_that_can_proc_is_handled() {
+        let config = crate::resources::load_progression_config();
+        let status_config = crate::resources::load_status_effect_config();
+
+        for (damage_type_id, damage_type) in &config.damage_types {
+            for (special_effect, chance) in &damage_type.special_effect_chances {
+                assert!(
+                    *chance <= 0.0 || is_handled_special_effect(special_effect, &status_config),
+                    "{} procs '{}' but nothing handles it", damage_type_id, special_effect
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use bevy::prelude::*;
+use crate::components::{Player, PlayerMovementState, CharacterLevel};
+use crate::resources::{GameConfig, ProgressionConfig, ExperienceCurve};
+use crate::systems::combat::{CombatState, Health};
+use crate::systems::damage::DamageDealt;
+use crate::systems::status_effects::StatusEffects;
+use crate::systems::vitals::{Mana, Stamina};
+
+// Death Components
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RespawnPointKind {
+    Graveyard,
+    RestPoint,
+}
+
+/// A place the player can respawn at after dying
+#[derive(Component, Debug)]
+pub struct RespawnPoint {
+    pub name: String,
+    pub kind: RespawnPointKind,
+}
+
+/// The player is dead and waiting to respawn
+#[derive(Component, Debug)]
+pub struct Dead {
+    pub respawn_timer: Timer,
+}
+
+// Death Events
+#[derive(Event, Debug)]
+pub struct PlayerDied {
+    pub player: Entity,
+    pub killer: Option<Entity>,
+    pub experience_lost: u64,
+}
+
+#[derive(Event, Debug)]
+pub struct PlayerRespawned {
+    pub player: Entity,
+    pub respawn_point: Option<Entity>,
+}
+
+/// Experience lost on death - only progress within the current level, so dying never de-levels
+pub fn death_experience_penalty(character_level: &CharacterLevel, penalty_fraction: f32, curve: &ExperienceCurve) -> u64 {
+    let level_start = curve.experience_for_level(character_level.level);
+    let level_progress = character_level.experience.saturating_sub(level_start);
+    (level_progress as f32 * penalty_fraction.clamp(0.0, 1.0)) as u64
+}
+
+/// Find the respawn point closest to where the player died
+fn nearest_respawn_point<'a>(
+    position: Vec3,
+    respawn_points: impl Iterator<Item = (Entity, &'a Transform, &'a RespawnPoint)>,
+) -> Option<(Entity, &'a Transform, &'a RespawnPoint)> {
+    respawn_points.min_by(|a, b| {
+        let dist_a = position.distance_squared(a.1.translation);
+        let dist_b = position.distance_squared(b.1.translation);
+        dist_a.total_cmp(&dist_b)
+    })
+}
+
+// System to spawn graveyards and rest points
+pub fn spawn_respawn_points(
+    mut commands: Commands,
+    mut spawned: Local<bool>,
+) {
+    if *spawned {
+        return;
+    }
+
+    // Spawned high above the terrain - the character controller drops the player onto the ground
+    let respawn_points = [
+        ("Starting Camp", RespawnPointKind::RestPoint, Vec3::new(-70.0, 15.0, -70.0)),
+        ("Forest Graveyard", RespawnPointKind::Graveyard, Vec3::new(10.0, 15.0, -60.0)),
+        ("Hillside Shrine", RespawnPointKind::RestPoint, Vec3::new(-40.0, 15.0, 70.0)),
+        ("Old Barrow Graveyard", RespawnPointKind::Graveyard, Vec3::new(70.0, 15.0, 20.0)),
+    ];
+
+    for (name, kind, position) in respawn_points {
+        commands.spawn((
+            Name::new(name),
+            Transform::from_translation(position),
+            RespawnPoint { name: name.to_string(), kind },
+        ));
+    }
+
+    info!("🪦 Spawned {} respawn points", respawn_points.len());
+    *spawned = true;
+}
+
+// System to detect player death and apply the death penalty
+#[allow(clippy::too_many_arguments)]
+pub fn detect_player_death(
+    mut commands: Commands,
+    game_config: Res<GameConfig>,
+    config: Res<ProgressionConfig>,
+    mut combat_state: ResMut<CombatState>,
+    mut last_attacker: Local<Option<Entity>>,
+    mut damage_dealt_events: EventReader<DamageDealt>,
+    mut player_query: Query<(Entity, &Health, Option<&mut CharacterLevel>, Option<&mut StatusEffects>, &mut PlayerMovementState), (With<Player>, Without<Dead>)>,
+    mut death_writer: EventWriter<PlayerDied>,
+) {
+    let Ok((player_entity, health, character_level, status_effects, mut movement_state)) = player_query.single_mut() else {
+        damage_dealt_events.clear();
+        return;
+    };
+
+    for event in damage_dealt_events.read() {
+        if event.target == player_entity && event.killing_blow {
+            *last_attacker = event.attacker;
+        }
+    }
+
+    if health.is_alive() {
+        return;
+    }
+
+    let mut experience_lost = 0;
+    if let Some(mut character_level) = character_level {
+        experience_lost = death_experience_penalty(&character_level, game_config.gameplay.death_experience_penalty, &config.character_progression.experience_curve);
+        character_level.experience -= experience_lost;
+    }
+
+    if let Some(mut status_effects) = status_effects {
+        status_effects.clear();
+    }
+
+    movement_state.current_speed = 0.0;
+    movement_state.is_running = false;
+
+    combat_state.player_target = None;
+    combat_state.in_combat = false;
+
+    commands.entity(player_entity).insert(Dead {
+        respawn_timer: Timer::from_seconds(game_config.gameplay.respawn_delay.max(0.0), TimerMode::Once),
+    });
+
+    info!("💀 You have died! Lost {} experience. Respawning in {:.0}s...",
+          experience_lost, game_config.gameplay.respawn_delay);
+
+    death_writer.write(PlayerDied {
+        player: player_entity,
+        killer: last_attacker.take(),
+        experience_lost,
+    });
+}
+
+// System to respawn the player at the nearest graveyard or rest point
+pub fn handle_player_respawn(
+    mut commands: Commands,
+    time: Res<Time>,
+    game_config: Res<GameConfig>,
+    mut player_query: Query<(Entity, &mut Transform, &mut Dead, &mut Health, Option<&mut Mana>, Option<&mut Stamina>, &mut PlayerMovementState), With<Player>>,
+    respawn_point_query: Query<(Entity, &Transform, &RespawnPoint), Without<Player>>,
+    mut respawn_writer: EventWriter<PlayerRespawned>,
+) {
+    let Ok((player_entity, mut transform, mut dead, mut health, mana, stamina, mut movement_state)) = player_query.single_mut() else {
+        return;
+    };
+
+    dead.respawn_timer.tick(time.delta());
+    if !dead.respawn_timer.finished() {
+        return;
+    }
+
+    let respawn_point = nearest_respawn_point(transform.translation, respawn_point_query.iter());
+    if let Some((_, respawn_transform, point)) = respawn_point {
+        transform.translation = respawn_transform.translation;
+        info!("✨ Respawned at {} ({:?})", point.name, point.kind);
+    } else {
+        warn!("No respawn points found - respawning where the player died");
+    }
+
+    let gameplay = &game_config.gameplay;
+    health.current = (health.max * gameplay.respawn_health_fraction).clamp(1.0, health.max);
+    if let Some(mut mana) = mana {
+        mana.current = (mana.max * gameplay.respawn_mana_fraction).clamp(0.0, mana.max);
+    }
+    if let Some(mut stamina) = stamina {
+        stamina.current = stamina.max;
+    }
+
+    movement_state.vertical_velocity = 0.0;
+    movement_state.current_speed = 0.0;
+
+    commands.entity(player_entity).remove::<Dead>();
+    respawn_writer.write(PlayerRespawned {
+        player: player_entity,
+        respawn_point: respawn_point.map(|(entity, _, _)| entity),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_death_penalty_never_delevels() {
+        let curve = ExperienceCurve::character_default();
+        let level_start = curve.experience_for_level(3);
+        let character_level = CharacterLevel {
+            level: 3,
+            experience: level_start + 200,
+            experience_to_next_level: curve.experience_for_level(4),
+        };
+
+        assert_eq!(death_experience_penalty(&character_level, 0.1, &curve), 20);
+        assert_eq!(death_experience_penalty(&character_level, 5.0, &curve), 200);
+    }
+
+    #[test]
+    fn test_nearest_respawn_point() {
+        let graveyard = (
+            Entity::from_raw(1),
+            Transform::from_xyz(50.0, 0.0, 0.0),
+            RespawnPoint { name: "Graveyard".to_string(), kind: RespawnPointKind::Graveyard },
+        );
+        let rest_point = (
+            Entity::from_raw(2),
+            Transform::from_xyz(-10.0, 0.0, 0.0),
+            RespawnPoint { name: "Camp".to_string(), kind: RespawnPointKind::RestPoint },
+        );
+        let points = [graveyard, rest_point];
+
+        let nearest = nearest_respawn_point(Vec3::ZERO, points.iter().map(|(e, t, p)| (*e, t, p)));
+        assert_eq!(nearest.map(|(_, _, point)| point.kind), Some(RespawnPointKind::RestPoint));
+    }
+}
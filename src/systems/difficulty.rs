@@ -0,0 +1,207 @@
+use bevy::prelude::*;
+use crate::components::{Player, CharacterLevel};
+use crate::resources::GameConfig;
+use crate::systems::combat::{Enemy, Health};
+use crate::systems::damage::DamageDealt;
+use crate::systems::death::PlayerDied;
+
+/// Enemies this many levels above the player con red - everything in between scales down to grey
+const RED_LEVEL_DIFFERENCE: i32 = 5;
+const ORANGE_LEVEL_DIFFERENCE: i32 = 3;
+/// Matches the level difference at which kills stop giving experience
+const GREY_LEVEL_DIFFERENCE: i32 = -5;
+const GREEN_LEVEL_DIFFERENCE: i32 = -3;
+
+/// Deaths in a row before enemies ease off
+const DEATH_STREAK_THRESHOLD: u32 = 2;
+/// Easy wins in a row before enemies press harder
+const EASY_WIN_STREAK_THRESHOLD: u32 = 3;
+/// Kills that leave the player above this much health count as easy
+const EASY_WIN_HEALTH_FRACTION: f32 = 0.8;
+const AGGRESSION_STEP: f32 = 0.1;
+const MIN_AGGRESSION: f32 = 0.6;
+const MAX_AGGRESSION: f32 = 1.4;
+
+/// How dangerous an enemy is compared to the player, by level difference
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConColor {
+    Grey,   // Trivial - no experience
+    Green,  // Easy
+    Yellow, // Even match
+    Orange, // Tough
+    Red,    // Deadly
+}
+
+impl ConColor {
+    pub fn color(&self) -> Color {
+        match self {
+            ConColor::Grey => Color::srgb(0.6, 0.6, 0.6),
+            ConColor::Green => Color::srgb(0.25, 0.85, 0.25),
+            ConColor::Yellow => Color::srgb(1.0, 1.0, 0.0),
+            ConColor::Orange => Color::srgb(1.0, 0.5, 0.0),
+            ConColor::Red => Color::srgb(1.0, 0.15, 0.15),
+        }
+    }
+}
+
+/// Con colour of an enemy at `enemy_level` to a player at `player_level`
+pub fn con_color(player_level: u32, enemy_level: u32) -> ConColor {
+    let level_difference = enemy_level as i32 - player_level as i32;
+
+    if level_difference >= RED_LEVEL_DIFFERENCE {
+        ConColor::Red
+    } else if level_difference >= ORANGE_LEVEL_DIFFERENCE {
+        ConColor::Orange
+    } else if level_difference <= GREY_LEVEL_DIFFERENCE {
+        ConColor::Grey
+    } else if level_difference <= GREEN_LEVEL_DIFFERENCE {
+        ConColor::Green
+    } else {
+        ConColor::Yellow
+    }
+}
+
+/// Enemy aggression adapted to how the player is doing - only moves when dynamic difficulty is enabled
+/// Scales how far enemies notice the player from and how often they attack
+#[derive(Resource, Debug)]
+pub struct DynamicDifficulty {
+    pub aggression: f32,
+    pub death_streak: u32,
+    pub easy_win_streak: u32,
+}
+
+impl Default for DynamicDifficulty {
+    fn default() -> Self {
+        Self {
+            aggression: 1.0,
+            death_streak: 0,
+            easy_win_streak: 0,
+        }
+    }
+}
+
+impl DynamicDifficulty {
+    pub fn record_death(&mut self) {
+        self.easy_win_streak = 0;
+        self.death_streak += 1;
+        if self.death_streak >= DEATH_STREAK_THRESHOLD {
+            self.death_streak = 0;
+            self.aggression = (self.aggression - AGGRESSION_STEP).max(MIN_AGGRESSION);
+        }
+    }
+
+    /// A hard-fought win breaks both streaks - the player is being challenged about right
+    pub fn record_win(&mut self, easy: bool) {
+        self.death_streak = 0;
+        if !easy {
+            self.easy_win_streak = 0;
+            return;
+        }
+
+        self.easy_win_streak += 1;
+        if self.easy_win_streak >= EASY_WIN_STREAK_THRESHOLD {
+            self.easy_win_streak = 0;
+            self.aggression = (self.aggression + AGGRESSION_STEP).min(MAX_AGGRESSION);
+        }
+    }
+
+    /// Multiplier on the distance enemies notice the player from
+    pub fn aggro_radius_multiplier(&self) -> f32 {
+        self.aggression
+    }
+
+    /// Multiplier on the time between enemy attacks - aggressive enemies swing faster
+    pub fn attack_interval_multiplier(&self) -> f32 {
+        1.0 / self.aggression
+    }
+}
+
+// System to adjust enemy aggression as the player keeps dying or keeps winning easily
+pub fn update_dynamic_difficulty(
+    game_config: Res<GameConfig>,
+    mut difficulty: ResMut<DynamicDifficulty>,
+    mut player_died_events: EventReader<PlayerDied>,
+    mut damage_dealt_events: EventReader<DamageDealt>,
+    player_query: Query<(Entity, &Health, &CharacterLevel), With<Player>>,
+    enemy_query: Query<&Enemy>,
+) {
+    if !game_config.gameplay.dynamic_difficulty {
+        player_died_events.clear();
+        damage_dealt_events.clear();
+        if difficulty.aggression != 1.0 {
+            *difficulty = DynamicDifficulty::default();
+        }
+        return;
+    }
+
+    for _ in player_died_events.read() {
+        difficulty.record_death();
+        info!("🎚️ Enemy aggression now {:.0}%", difficulty.aggression * 100.0);
+    }
+
+    let Ok((player_entity, player_health, character_level)) = player_query.single() else {
+        return;
+    };
+
+    for event in damage_dealt_events.read() {
+        if !event.killing_blow || event.attacker != Some(player_entity) {
+            continue;
+        }
+        let Ok(enemy) = enemy_query.get(event.target) else {
+            continue;
+        };
+
+        // Grey enemies prove nothing either way
+        if con_color(character_level.level, enemy.level) == ConColor::Grey {
+            continue;
+        }
+
+        let aggression = difficulty.aggression;
+        difficulty.record_win(player_health.health_percentage() >= EASY_WIN_HEALTH_FRACTION);
+        if difficulty.aggression != aggression {
+            info!("🎚️ Enemy aggression now {:.0}%", difficulty.aggression * 100.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_con_colors_follow_level_difference() {
+        assert_eq!(con_color(10, 15), ConColor::Red);
+        assert_eq!(con_color(10, 13), ConColor::Orange);
+        assert_eq!(con_color(10, 12), ConColor::Yellow);
+        assert_eq!(con_color(10, 8), ConColor::Yellow);
+        assert_eq!(con_color(10, 7), ConColor::Green);
+        assert_eq!(con_color(10, 5), ConColor::Grey);
+        assert_eq!(con_color(1, 1), ConColor::Yellow);
+    }
+
+    #[test]
+    fn test_aggression_follows_death_and_easy_win_streaks() {
+        let mut difficulty = DynamicDifficulty::default();
+
+        difficulty.record_death();
+        assert_eq!(difficulty.aggression, 1.0);
+        difficulty.record_death();
+        assert!((difficulty.aggression - 0.9).abs() < 0.001);
+
+        // A hard win breaks the easy streak
+        difficulty.record_win(true);
+        difficulty.record_win(true);
+        difficulty.record_win(false);
+        difficulty.record_win(true);
+        assert!((difficulty.aggression - 0.9).abs() < 0.001);
+        difficulty.record_win(true);
+        difficulty.record_win(true);
+        assert!((difficulty.aggression - 1.0).abs() < 0.001);
+
+        for _ in 0..20 {
+            difficulty.record_death();
+        }
+        assert_eq!(difficulty.aggression, MIN_AGGRESSION);
+        assert!(difficulty.attack_interval_multiplier() > 1.0);
+    }
+}
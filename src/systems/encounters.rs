@@ -0,0 +1,418 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use crate::components::Player;
+use crate::resources::{BossAbility, AddSpawn, EncounterDatabase, EncounterDefinition, LevelRange, ProgressionConfig};
+use crate::systems::combat::{Enemy, EnemySpawner, Health};
+use crate::systems::damage::{DamageEvent, DamageSource};
+use crate::systems::death::Dead;
+use crate::systems::enemy_ai::EnemyAi;
+use crate::systems::status_effects::{ApplyStatusEffect, StatusEffects};
+use crate::systems::threat::ThreatTable;
+use crate::systems::attack_table::AttackOutcome;
+use crate::systems::line_of_sight::attack_position;
+
+/// How far from the boss adds appear
+const ADD_SPAWN_RADIUS: f32 = 6.0;
+
+// Encounter Components
+/// Runtime state of a boss encounter, attached to the boss itself
+#[derive(Component, Debug)]
+pub struct BossEncounter {
+    pub encounter_id: String,
+    pub definition: EncounterDefinition,
+    pub active: bool,
+    pub phase: usize,
+    pub elapsed: f32,
+    pub rotation_index: usize,
+    pub ability_timer: Timer,
+    pub enraged: bool,
+    /// Melee damage before enrage - restored when the fight resets
+    pub base_damage: f32,
+    /// Living adds spawned during this attempt - despawned when the encounter ends
+    pub adds: Vec<Entity>,
+}
+
+impl BossEncounter {
+    pub fn new(encounter_id: &str, definition: EncounterDefinition, base_damage: f32) -> Self {
+        Self {
+            encounter_id: encounter_id.to_string(),
+            definition,
+            active: false,
+            phase: 0,
+            elapsed: 0.0,
+            rotation_index: 0,
+            ability_timer: Timer::from_seconds(0.0, TimerMode::Once),
+            enraged: false,
+            base_damage,
+            adds: Vec::new(),
+        }
+    }
+
+    pub fn phase_id(&self) -> &str {
+        &self.definition.phases[self.phase].id
+    }
+
+    /// Restart the ability rotation for the current phase
+    fn start_rotation(&mut self) {
+        self.rotation_index = 0;
+        let first_cooldown = self.definition.phases[self.phase].rotation.first().map_or(0.0, |ability| ability.cooldown);
+        self.ability_timer = Timer::from_seconds(first_cooldown, TimerMode::Once);
+    }
+
+    fn damage_multiplier(&self) -> f32 {
+        match self.definition.enrage {
+            Some(enrage) if self.enraged => enrage.damage_multiplier,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Marks an enemy spawned as part of a boss fight
+#[derive(Component, Debug)]
+pub struct EncounterAdd {
+    pub boss: Entity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WipeReason {
+    PlayerDied,
+    LeftArena,
+    BossLeashed,
+}
+
+// Encounter Events
+#[derive(Event, Debug)]
+pub struct EncounterStarted {
+    pub boss: Entity,
+    pub encounter_id: String,
+}
+
+#[derive(Event, Debug)]
+pub struct PhaseChanged {
+    pub boss: Entity,
+    pub encounter_id: String,
+    pub from_phase: String,
+    pub to_phase: String,
+}
+
+#[derive(Event, Debug)]
+pub struct EncounterWiped {
+    pub boss: Entity,
+    pub encounter_id: String,
+    pub reason: WipeReason,
+}
+
+#[derive(Event, Debug)]
+pub struct EncounterDefeated {
+    pub boss: Entity,
+    pub encounter_id: String,
+    pub duration: f32,
+}
+
+/// Despawn the adds still alive when an encounter ends - dead ones are left as corpses
+fn despawn_adds(commands: &mut Commands, adds: &mut Vec<Entity>) {
+    for add in adds.drain(..) {
+        if let Ok(mut add_commands) = commands.get_entity(add) {
+            add_commands.despawn();
+        }
+    }
+}
+
+fn horizontal_distance(a: Vec3, b: Vec3) -> f32 {
+    Vec2::new(a.x - b.x, a.z - b.z).length()
+}
+
+/// Spawn adds around the boss, already aggroed onto its target
+fn spawn_adds(
+    spawner: &mut EnemySpawner,
+    boss: Entity,
+    boss_position: Vec3,
+    target: Option<Entity>,
+    adds: &[AddSpawn],
+    spawned: &mut Vec<Entity>,
+) {
+    let mut rng = thread_rng();
+
+    for add in adds {
+        for _ in 0..add.count {
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            let position = boss_position + Vec3::new(angle.cos(), 0.0, angle.sin()) * ADD_SPAWN_RADIUS;
+
+            let Some(mut entity) = spawner.spawn(&add.enemy_id, position, Some(0.0), None) else {
+                continue;
+            };
+
+            let mut threat_table = ThreatTable::default();
+            if let Some(target) = target {
+                threat_table.add_threat(target, 1.0);
+                threat_table.current_target = Some(target);
+            }
+            entity.insert((threat_table, EncounterAdd { boss }));
+            spawned.push(entity.id());
+        }
+    }
+}
+
+// System to place the bosses from encounters.json
+pub fn spawn_encounter_bosses(
+    mut spawner: EnemySpawner,
+    encounter_database: Res<EncounterDatabase>,
+    mut spawned: Local<bool>,
+) {
+    if *spawned {
+        return;
+    }
+
+    for (encounter_id, definition) in &encounter_database.encounters {
+        let Some(boss_definition) = spawner.enemy_definition(&definition.boss) else {
+            warn!("Encounter '{}' uses unknown boss '{}'", encounter_id, definition.boss);
+            continue;
+        };
+
+        // Roll the level up front so wipes reset the boss to its scaled damage
+        let level = boss_definition.level_range.roll(&mut thread_rng());
+        let base_damage = boss_definition.stats.scaled_to(level, boss_definition.level_range.min, &boss_definition.scaling).damage;
+
+        // Bosses hold their ground instead of patrolling
+        if let Some(mut boss) = spawner.spawn(&definition.boss, definition.position(), Some(0.0), Some(LevelRange { min: level, max: level })) {
+            boss.insert((
+                Name::new(definition.name.clone()),
+                BossEncounter::new(encounter_id, definition.clone(), base_damage),
+            ));
+            info!("👑 {} awaits at {:?}", definition.name, definition.position());
+        }
+    }
+
+    *spawned = true;
+}
+
+// System to run boss encounters - start, phases, ability rotation, enrage, wipes and victory
+#[allow(clippy::too_many_arguments)]
+pub fn update_boss_encounters(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<ProgressionConfig>,
+    mut spawner: EnemySpawner,
+    mut boss_query: Query<(Entity, &Transform, &Health, &mut Enemy, &mut EnemyAi, &mut ThreatTable, &mut BossEncounter, Option<&StatusEffects>)>,
+    player_query: Query<(Entity, &Transform, Has<Dead>), (With<Player>, Without<Enemy>)>,
+    add_query: Query<&Health, (With<EncounterAdd>, Without<BossEncounter>)>,
+    mut damage_writer: EventWriter<DamageEvent>,
+    mut status_writer: EventWriter<ApplyStatusEffect>,
+    mut started_writer: EventWriter<EncounterStarted>,
+    mut phase_writer: EventWriter<PhaseChanged>,
+    mut wiped_writer: EventWriter<EncounterWiped>,
+    mut defeated_writer: EventWriter<EncounterDefeated>,
+) {
+    let player = player_query.single().ok();
+
+    for (boss_entity, transform, health, mut enemy, mut ai, mut threat_table, mut encounter, status_effects) in &mut boss_query {
+        let boss_position = transform.translation;
+
+        if !encounter.active {
+            if health.is_alive() && ai.is_engaged() {
+                encounter.active = true;
+                encounter.elapsed = 0.0;
+                encounter.phase = 0;
+                encounter.start_rotation();
+
+                let adds = encounter.definition.phases[0].adds.clone();
+                spawn_adds(&mut spawner, boss_entity, boss_position, ai.target, &adds, &mut encounter.adds);
+
+                info!("👑 {} has begun! ({})", encounter.definition.name, encounter.definition.phases[0].name);
+                started_writer.write(EncounterStarted { boss: boss_entity, encounter_id: encounter.encounter_id.clone() });
+            }
+            continue;
+        }
+
+        // Dead adds no longer count towards the fight
+        encounter.adds.retain(|add| add_query.get(*add).is_ok_and(|add_health| add_health.is_alive()));
+
+        // Victory
+        if !health.is_alive() {
+            despawn_adds(&mut commands, &mut encounter.adds);
+            encounter.active = false;
+            info!("🏆 {} defeated in {:.0}s!", encounter.definition.name, encounter.elapsed);
+            defeated_writer.write(EncounterDefeated {
+                boss: boss_entity,
+                encounter_id: encounter.encounter_id.clone(),
+                duration: encounter.elapsed,
+            });
+            continue;
+        }
+
+        // Wipe: the player died, left the arena, or the boss was dragged past its leash
+        let arena_center = encounter.definition.position();
+        let wipe_reason = match player {
+            Some((_, _, true)) => Some(WipeReason::PlayerDied),
+            Some((_, player_transform, false))
+                if horizontal_distance(player_transform.translation, arena_center) > encounter.definition.arena_radius =>
+            {
+                Some(WipeReason::LeftArena)
+            }
+            _ if !ai.is_engaged() => Some(WipeReason::BossLeashed),
+            _ => None,
+        };
+
+        if let Some(reason) = wipe_reason {
+            despawn_adds(&mut commands, &mut encounter.adds);
+            enemy.stats.damage = encounter.base_damage;
+            encounter.active = false;
+            encounter.enraged = false;
+            encounter.phase = 0;
+
+            // Walking home restores the boss to full health
+            ai.leash();
+            threat_table.clear();
+
+            info!("☠️ {} reset ({:?})", encounter.definition.name, reason);
+            wiped_writer.write(EncounterWiped {
+                boss: boss_entity,
+                encounter_id: encounter.encounter_id.clone(),
+                reason,
+            });
+            continue;
+        }
+
+        encounter.elapsed += time.delta_secs();
+
+        // Enrage
+        if let Some(enrage) = encounter.definition.enrage {
+            if !encounter.enraged && encounter.elapsed >= enrage.after_seconds {
+                encounter.enraged = true;
+                enemy.stats.damage = encounter.base_damage * enrage.damage_multiplier;
+                info!("🔥 {} is enraged!", encounter.definition.name);
+            }
+        }
+
+        // Phase transitions
+        if let Some(next_phase) = encounter.definition.next_phase(encounter.phase, health.health_percentage(), encounter.elapsed) {
+            let from_phase = encounter.phase_id().to_string();
+            encounter.phase = next_phase;
+            encounter.start_rotation();
+
+            let phase = encounter.definition.phases[next_phase].clone();
+            spawn_adds(&mut spawner, boss_entity, boss_position, ai.target, &phase.adds, &mut encounter.adds);
+
+            info!("👑 {} enters {}", encounter.definition.name, phase.name);
+            phase_writer.write(PhaseChanged {
+                boss: boss_entity,
+                encounter_id: encounter.encounter_id.clone(),
+                from_phase,
+                to_phase: phase.id,
+            });
+        }
+
+        // Ability rotation - stuns hold the next cast
+        if status_effects.is_some_and(|effects| effects.is_stunned()) {
+            continue;
+        }
+
+        encounter.ability_timer.tick(time.delta());
+        if !encounter.ability_timer.finished() {
+            continue;
+        }
+
+        let rotation = &encounter.definition.phases[encounter.phase].rotation;
+        if rotation.is_empty() {
+            continue;
+        }
+        let next_index = (encounter.rotation_index + 1) % rotation.len();
+        let ability: BossAbility = rotation[encounter.rotation_index % rotation.len()].clone();
+        let next_cooldown = rotation[next_index].cooldown;
+        encounter.rotation_index = next_index;
+        encounter.ability_timer = Timer::from_seconds(next_cooldown, TimerMode::Once);
+
+        info!("👑 {} casts {}!", encounter.definition.name, ability.name);
+
+        let targets: Vec<Entity> = if ability.radius > 0.0 {
+            player
+                .filter(|(_, player_transform, dead)| {
+                    !dead && horizontal_distance(player_transform.translation, boss_position) <= ability.radius &&
+                        ability.arc.is_none_or(|arc| attack_position(transform, player_transform.translation) == arc)
+                })
+                .map(|(player_entity, _, _)| player_entity)
+                .into_iter()
+                .collect()
+        } else {
+            ai.target.into_iter().collect()
+        };
+
+        let damage_type = ability
+            .damage_type
+            .as_deref()
+            .and_then(|damage_type| config.damage_type_id_to_enum(damage_type))
+            .unwrap_or(enemy.damage_type);
+
+        for target in targets {
+            if ability.damage > 0.0 {
+                damage_writer.write(DamageEvent {
+                    attacker: Some(boss_entity),
+                    target,
+                    damage_type,
+                    amount: ability.damage * encounter.damage_multiplier(),
+                    source: DamageSource::Ability(ability.id.clone()),
+                    outcome: AttackOutcome::Hit, // Boss abilities can't be avoided
+                    position: None,
+                    status_effect: None,
+                });
+            }
+
+            if let Some(effect_id) = &ability.status_effect {
+                status_writer.write(ApplyStatusEffect {
+                    target,
+                    effect_id: effect_id.clone(),
+                    source: Some(boss_entity),
+                });
+            }
+        }
+
+        spawn_adds(&mut spawner, boss_entity, boss_position, ai.target, &ability.adds, &mut encounter.adds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::{EncounterPhase, EnrageConfig};
+
+    fn ability(id: &str, cooldown: f32) -> BossAbility {
+        BossAbility {
+            id: id.to_string(),
+            name: id.to_string(),
+            cooldown,
+            damage: 10.0,
+            damage_type: None,
+            radius: 0.0,
+            arc: None,
+            status_effect: None,
+            adds: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_rotation_starts_on_first_cooldown_and_enrage_multiplies_damage() {
+        let definition = EncounterDefinition {
+            name: "Test".to_string(),
+            boss: "wild_boar".to_string(),
+            position: [0.0; 3],
+            arena_radius: 20.0,
+            enrage: Some(EnrageConfig { after_seconds: 60.0, damage_multiplier: 3.0 }),
+            phases: vec![EncounterPhase {
+                id: "one".to_string(),
+                name: "One".to_string(),
+                trigger: None,
+                rotation: vec![ability("slam", 4.0), ability("roar", 9.0)],
+                adds: Vec::new(),
+            }],
+        };
+
+        let mut encounter = BossEncounter::new("test", definition, 20.0);
+        encounter.start_rotation();
+        assert_eq!(encounter.ability_timer.duration().as_secs_f32(), 4.0);
+        assert_eq!(encounter.phase_id(), "one");
+
+        assert_eq!(encounter.damage_multiplier(), 1.0);
+        encounter.enraged = true;
+        assert_eq!(encounter.damage_multiplier(), 3.0);
+    }
+}
@@ -0,0 +1,506 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use avian3d::prelude::*;
+use rand::prelude::*;
+use crate::components::Player;
+use crate::systems::combat::{Enemy, Health};
+use crate::systems::threat::ThreatTable;
+use crate::systems::status_effects::StatusEffects;
+use crate::systems::damage::{DamageEvent, DamageSource};
+use crate::systems::death::Dead;
+use crate::systems::attack_table::AttackTables;
+use crate::systems::difficulty::DynamicDifficulty;
+use crate::systems::terrain::Terrain;
+use crate::systems::world_objects::WorldObject;
+
+/// Half the height of an enemy's collider - enemies stand this far above the ground
+pub const ENEMY_HALF_HEIGHT: f32 = 1.5;
+/// How far above an enemy we start the ground ray (handles walking up slopes)
+const GROUND_RAY_HEADROOM: f32 = 2.0;
+/// Maximum ground ray length below the headroom
+const GROUND_RAY_DISTANCE: f32 = 10.0;
+/// Distance at which a movement destination counts as reached
+const ARRIVAL_DISTANCE: f32 = 0.5;
+/// Returning enemies move faster so players can't re-pull them mid-walk
+const RETURN_SPEED_MULTIPLIER: f32 = 1.5;
+/// Threat given to a player for walking into an enemy's aggro radius
+const PROXIMITY_THREAT: f32 = 1.0;
+
+// Enemy AI Components
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnemyAiState {
+    /// Standing at home waiting for the idle timer to expire
+    Idle,
+    /// Wandering to a random point around the spawn position
+    Patrol,
+    /// Moving towards an aggroed target
+    Chase,
+    /// In attack range of the target
+    Attack,
+    /// Leashed - walking back to spawn, ignoring aggro until reset
+    Returning,
+}
+
+#[derive(Component, Debug)]
+pub struct EnemyAi {
+    pub state: EnemyAiState,
+    pub spawn_position: Vec3,
+    pub target: Option<Entity>,
+    pub patrol_destination: Option<Vec3>,
+    pub idle_timer: Timer,
+    /// Time until the next melee attack lands - starts ready so the first hit is immediate
+    pub attack_timer: Timer,
+    /// Time until an attack carries one of the enemy's abilities - starts ready so the opening hit does
+    pub ability_timer: Timer,
+    /// Index of the ability the next ability attack uses - abilities are used in turn
+    pub next_ability: usize,
+}
+
+impl EnemyAi {
+    pub fn new(spawn_position: Vec3) -> Self {
+        Self {
+            state: EnemyAiState::Idle,
+            spawn_position,
+            target: None,
+            patrol_destination: None,
+            idle_timer: Timer::from_seconds(random_idle_duration(), TimerMode::Once),
+            attack_timer: Timer::from_seconds(0.0, TimerMode::Once),
+            ability_timer: Timer::from_seconds(0.0, TimerMode::Once),
+            next_ability: 0,
+        }
+    }
+
+    /// Aggro onto a target unless the enemy is resetting
+    pub fn engage(&mut self, target: Entity) -> bool {
+        if self.state == EnemyAiState::Returning || self.target.is_some() {
+            return false;
+        }
+        self.target = Some(target);
+        self.patrol_destination = None;
+        self.state = EnemyAiState::Chase;
+        true
+    }
+
+    /// Drop the target and walk back to spawn
+    pub fn leash(&mut self) {
+        self.target = None;
+        self.patrol_destination = None;
+        self.state = EnemyAiState::Returning;
+    }
+
+    pub fn is_engaged(&self) -> bool {
+        matches!(self.state, EnemyAiState::Chase | EnemyAiState::Attack)
+    }
+
+    /// The ability this attack carries, if the ability cooldown is ready - starts the cooldown again
+    pub fn take_ability(&mut self, abilities: &[String], cooldown: f32) -> Option<String> {
+        if abilities.is_empty() || !self.ability_timer.finished() {
+            return None;
+        }
+
+        let ability = abilities[self.next_ability % abilities.len()].clone();
+        self.next_ability = (self.next_ability + 1) % abilities.len();
+        self.ability_timer = Timer::from_seconds(cooldown, TimerMode::Once);
+        Some(ability)
+    }
+}
+
+fn random_idle_duration() -> f32 {
+    thread_rng().gen_range(3.0..8.0)
+}
+
+/// Pick a random point within the patrol radius of spawn
+fn random_patrol_point(spawn_position: Vec3, patrol_radius: f32) -> Vec3 {
+    let mut rng = thread_rng();
+    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+    let distance = rng.gen_range(0.0..patrol_radius.max(0.1));
+    spawn_position + Vec3::new(angle.cos() * distance, 0.0, angle.sin() * distance)
+}
+
+/// Bodies enemies can stand on
+type GroundFilter = Or<(With<Terrain>, With<WorldObject>)>;
+
+/// Downward raycasts that only stand enemies on the terrain and world objects
+/// Other characters, projectiles and the enemy's own model never count as ground
+#[derive(SystemParam)]
+pub struct EnemyGround<'w, 's> {
+    spatial_query: SpatialQuery<'w, 's>,
+    children_query: Query<'w, 's, &'static Children>,
+    collider_query: Query<'w, 's, &'static ColliderOf>,
+    ground_query: Query<'w, 's, (), GroundFilter>,
+}
+
+impl EnemyGround<'_, '_> {
+    /// Height of the ground below an enemy centred at `position`, with the player controller's ray lengths
+    fn height_at(&self, position: Vec3, enemy_entity: Entity) -> Option<f32> {
+        let ray_origin = Vec3::new(position.x, position.y - ENEMY_HALF_HEIGHT + GROUND_RAY_HEADROOM, position.z);
+        let filter = SpatialQueryFilter::default()
+            .with_excluded_entities(std::iter::once(enemy_entity).chain(self.children_query.iter_descendants(enemy_entity)));
+
+        let is_ground = |entity: Entity| {
+            let body = self.collider_query.get(entity).map_or(entity, |collider| collider.body);
+            self.ground_query.contains(body)
+        };
+
+        self.spatial_query
+            .cast_ray_predicate(ray_origin, Dir3::NEG_Y, GROUND_RAY_HEADROOM + GROUND_RAY_DISTANCE, true, &filter, &is_ground)
+            .map(|hit| ray_origin.y - hit.distance)
+    }
+}
+
+/// Move horizontally towards a destination, keeping the enemy on the ground and facing its movement
+/// Returns true once the destination is reached
+fn move_towards(
+    transform: &mut Transform,
+    destination: Vec3,
+    speed: f32,
+    stop_distance: f32,
+    delta_secs: f32,
+    ground: &EnemyGround,
+    enemy_entity: Entity,
+) -> bool {
+    let offset = Vec3::new(destination.x - transform.translation.x, 0.0, destination.z - transform.translation.z);
+    let distance = offset.length();
+    let direction = offset.normalize_or_zero();
+
+    // Keep turning towards the destination even when close enough not to move
+    face_direction(transform, direction);
+    if distance <= stop_distance {
+        return true;
+    }
+
+    let step = (speed * delta_secs).min(distance - stop_distance);
+    let mut new_position = transform.translation + direction * step;
+
+    if let Some(ground_height) = ground.height_at(new_position, enemy_entity) {
+        new_position.y = ground_height + ENEMY_HALF_HEIGHT;
+    }
+
+    transform.translation = new_position;
+
+    distance - step <= stop_distance
+}
+
+/// Rotate to face a horizontal direction (+Z forward, matching the player)
+fn face_direction(transform: &mut Transform, direction: Vec3) {
+    if direction.x.abs() > f32::EPSILON || direction.z.abs() > f32::EPSILON {
+        transform.rotation = Quat::from_rotation_y(direction.x.atan2(direction.z));
+    }
+}
+
+fn horizontal_distance(a: Vec3, b: Vec3) -> f32 {
+    Vec2::new(a.x - b.x, a.z - b.z).length()
+}
+
+// System to run the enemy AI state machine
+pub fn update_enemy_ai(
+    time: Res<Time>,
+    difficulty: Res<DynamicDifficulty>,
+    ground: EnemyGround,
+    player_query: Query<(Entity, &Transform), (With<Player>, Without<Dead>)>,
+    target_query: Query<&Transform, Without<Enemy>>,
+    mut enemy_query: Query<(Entity, &mut Transform, &mut EnemyAi, &mut ThreatTable, &mut Health, &Enemy, Option<&StatusEffects>), Without<Player>>,
+) {
+    let delta_secs = time.delta_secs();
+    let player = player_query.single().ok();
+
+    for (entity, mut transform, mut ai, mut threat_table, mut health, enemy, status_effects) in &mut enemy_query {
+        if !health.is_alive() {
+            continue;
+        }
+
+        // Stunned enemies do nothing; slows and roots scale movement below
+        if status_effects.is_some_and(|effects| effects.is_stunned()) {
+            continue;
+        }
+        let movement_multiplier = status_effects.map(|effects| effects.movement_multiplier()).unwrap_or(1.0);
+
+        let position = transform.translation;
+
+        // Detect: idle and patrolling enemies aggro on players who come too close
+        if matches!(ai.state, EnemyAiState::Idle | EnemyAiState::Patrol) {
+            if let Some((player_entity, player_transform)) = player {
+                if horizontal_distance(position, player_transform.translation) <= enemy.ai.aggro_radius * difficulty.aggro_radius_multiplier() {
+                    threat_table.add_threat(player_entity, PROXIMITY_THREAT);
+                    if ai.engage(player_entity) {
+                        info!("😠 {} noticed the player!", enemy.name);
+                    }
+                }
+            }
+
+            // Pulled from range - damage or healing put someone on the threat table
+            if let Some(target) = threat_table.current_target {
+                if ai.engage(target) {
+                    info!("😠 {} was pulled into combat!", enemy.name);
+                }
+            }
+        }
+
+        // Engaged enemies always attack whoever holds aggro on the threat table
+        if ai.is_engaged() {
+            if threat_table.current_target.is_none() {
+                // Freshly detected - nobody has been assigned aggro yet
+                threat_table.select_target(|_| false);
+            }
+            ai.target = threat_table.current_target;
+        }
+
+        // Leash: engaged enemies give up when dragged too far from home or the target disappears
+        if ai.is_engaged() {
+            let target_position = ai.target.and_then(|target| target_query.get(target).ok()).map(|t| t.translation);
+
+            match target_position {
+                Some(target_position) if horizontal_distance(position, ai.spawn_position) <= enemy.ai.leash_distance => {
+                    let in_range = horizontal_distance(position, target_position) <= enemy.stats.attack_range;
+                    ai.state = if in_range { EnemyAiState::Attack } else { EnemyAiState::Chase };
+                }
+                _ => {
+                    info!("🔗 {} leashed and is returning home", enemy.name);
+                    ai.leash();
+                    threat_table.clear();
+                }
+            }
+        }
+
+        match ai.state {
+            EnemyAiState::Idle => {
+                ai.idle_timer.tick(time.delta());
+                if ai.idle_timer.finished() {
+                    ai.patrol_destination = Some(random_patrol_point(ai.spawn_position, enemy.ai.patrol_radius));
+                    ai.state = EnemyAiState::Patrol;
+                }
+            }
+            EnemyAiState::Patrol => {
+                let Some(destination) = ai.patrol_destination else {
+                    ai.state = EnemyAiState::Idle;
+                    continue;
+                };
+
+                let patrol_speed = enemy.stats.move_speed * 0.5 * movement_multiplier;
+                if move_towards(&mut transform, destination, patrol_speed, ARRIVAL_DISTANCE, delta_secs, &ground, entity) {
+                    ai.patrol_destination = None;
+                    ai.idle_timer = Timer::from_seconds(random_idle_duration(), TimerMode::Once);
+                    ai.state = EnemyAiState::Idle;
+                }
+            }
+            EnemyAiState::Chase => {
+                if let Some(target_transform) = ai.target.and_then(|target| target_query.get(target).ok()) {
+                    let stop_distance = enemy.stats.attack_range * 0.8;
+                    move_towards(&mut transform, target_transform.translation, enemy.stats.move_speed * movement_multiplier, stop_distance, delta_secs, &ground, entity);
+                }
+            }
+            EnemyAiState::Attack => {
+                // Hold position and keep facing the target
+                if let Some(target_transform) = ai.target.and_then(|target| target_query.get(target).ok()) {
+                    let direction = target_transform.translation - transform.translation;
+                    face_direction(&mut transform, Vec3::new(direction.x, 0.0, direction.z).normalize_or_zero());
+                }
+            }
+            EnemyAiState::Returning => {
+                let home = ai.spawn_position;
+                let return_speed = enemy.stats.move_speed * RETURN_SPEED_MULTIPLIER * movement_multiplier;
+                if move_towards(&mut transform, home, return_speed, ARRIVAL_DISTANCE, delta_secs, &ground, entity) {
+                    // Reset: back at spawn with full health
+                    health.current = health.max;
+                    ai.idle_timer = Timer::from_seconds(random_idle_duration(), TimerMode::Once);
+                    ai.state = EnemyAiState::Idle;
+                    info!("🏠 {} reset at its spawn point", enemy.name);
+                }
+            }
+        }
+    }
+}
+
+// System to let enemies in attack range hit their target
+pub fn handle_enemy_attacks(
+    time: Res<Time>,
+    difficulty: Res<DynamicDifficulty>,
+    target_query: Query<(&Transform, &Health), Without<Enemy>>,
+    mut enemy_query: Query<(Entity, &Transform, &mut EnemyAi, &Health, &Enemy, Option<&StatusEffects>)>,
+    attack_tables: AttackTables,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    let mut rng = thread_rng();
+
+    for (entity, transform, mut ai, health, enemy, status_effects) in &mut enemy_query {
+        ai.attack_timer.tick(time.delta());
+        ai.ability_timer.tick(time.delta());
+
+        if !health.is_alive() || ai.state != EnemyAiState::Attack {
+            continue;
+        }
+
+        if status_effects.is_some_and(|effects| effects.is_stunned()) {
+            continue;
+        }
+
+        let Some(target) = ai.target else {
+            continue;
+        };
+
+        let Ok((target_transform, target_health)) = target_query.get(target) else {
+            continue;
+        };
+
+        if !target_health.is_alive() || !ai.attack_timer.finished() ||
+           horizontal_distance(transform.translation, target_transform.translation) > enemy.stats.attack_range {
+            continue;
+        }
+
+        // Abilities ride on the swing, so a dodged or parried attack wastes them
+        let ability = ai.take_ability(&enemy.abilities, enemy.ai.ability_cooldown);
+
+        damage_events.write(DamageEvent {
+            attacker: Some(entity),
+            target,
+            damage_type: enemy.damage_type,
+            amount: enemy.stats.damage,
+            source: DamageSource::AutoAttack,
+            outcome: attack_tables.roll(entity, target, None, &mut rng),
+            position: Some(attack_tables.position(entity, target)),
+            status_effect: ability,
+        });
+
+        ai.attack_timer = Timer::from_seconds(enemy.stats.attack_speed * difficulty.attack_interval_multiplier(), TimerMode::Once);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::AttackPosition;
+    use crate::components::DamageType;
+    use crate::resources::{EnemyStats, EnemyAiConfig};
+    use crate::systems::combat::EnemyType;
+    use crate::systems::line_of_sight::attack_position;
+
+    fn test_enemy() -> Enemy {
+        Enemy {
+            enemy_type: EnemyType::new("test_enemy"),
+            name: "Test Enemy".to_string(),
+            level: 1,
+            stats: EnemyStats {
+                max_health: 100.0,
+                damage: 10.0,
+                damage_type: "slashing".to_string(),
+                attack_speed: 2.0,
+                attack_range: 3.0,
+                move_speed: 4.0,
+                armor_type: None,
+                experience_reward: 10,
+            },
+            ai: EnemyAiConfig {
+                aggro_radius: 10.0,
+                leash_distance: 30.0,
+                patrol_radius: 5.0,
+                ability_cooldown: 10.0,
+            },
+            damage_type: DamageType::Slashing,
+            loot_table: None,
+            abilities: Vec::new(),
+        }
+    }
+
+    /// A headless app running only the enemy AI, with a player and an enemy at the given positions
+    fn ai_app(player_position: Vec3, enemy_position: Vec3) -> (App, Entity, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<SpatialQueryPipeline>()
+            .init_resource::<DynamicDifficulty>()
+            .add_systems(Update, update_enemy_ai);
+
+        let player = app.world_mut().spawn((Player, Transform::from_translation(player_position))).id();
+        let enemy = app.world_mut().spawn((
+            test_enemy(),
+            Transform::from_translation(enemy_position),
+            EnemyAi::new(enemy_position),
+            ThreatTable::default(),
+            Health::new(100.0),
+        )).id();
+
+        (app, player, enemy)
+    }
+
+    #[test]
+    fn test_attacking_enemy_turns_to_face_a_circling_target() {
+        // The enemy faces +Z while the player stands at its side, well inside attack range
+        let (mut app, player, enemy) = ai_app(Vec3::new(2.0, 0.0, 0.0), Vec3::new(0.0, ENEMY_HALF_HEIGHT, 0.0));
+
+        app.update();
+
+        let world = app.world();
+        let ai = world.get::<EnemyAi>(enemy).unwrap();
+        assert_eq!(ai.state, EnemyAiState::Attack);
+        assert_eq!(ai.target, Some(player));
+        let enemy_transform = world.get::<Transform>(enemy).unwrap();
+        assert_eq!(attack_position(enemy_transform, Vec3::new(2.0, 0.0, 0.0)), AttackPosition::Front);
+    }
+
+    #[test]
+    fn test_engage_only_takes_a_target_when_free() {
+        let first = Entity::from_raw(1);
+        let second = Entity::from_raw(2);
+        let mut ai = EnemyAi::new(Vec3::ZERO);
+
+        assert!(ai.engage(first));
+        assert_eq!(ai.state, EnemyAiState::Chase);
+        assert!(ai.is_engaged());
+
+        // Already fighting - the threat table decides who gets hit, not engage
+        assert!(!ai.engage(second));
+        assert_eq!(ai.target, Some(first));
+
+        // Resetting enemies can't be pulled again
+        ai.leash();
+        assert_eq!(ai.state, EnemyAiState::Returning);
+        assert!(!ai.engage(first));
+        assert_eq!(ai.target, None);
+    }
+
+    #[test]
+    fn test_enemy_leashes_when_dragged_past_leash_distance() {
+        let (mut app, _, enemy) = ai_app(Vec3::new(42.0, 0.0, 0.0), Vec3::new(0.0, ENEMY_HALF_HEIGHT, 0.0));
+        // The player is right next to the enemy, but the enemy is 40 units from its spawn
+        app.world_mut().get_mut::<Transform>(enemy).unwrap().translation = Vec3::new(40.0, ENEMY_HALF_HEIGHT, 0.0);
+
+        app.update();
+
+        let world = app.world();
+        let ai = world.get::<EnemyAi>(enemy).unwrap();
+        assert_eq!(ai.state, EnemyAiState::Returning);
+        assert_eq!(ai.target, None);
+        assert!(world.get::<ThreatTable>(enemy).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_returning_enemy_resets_to_idle_at_spawn() {
+        let spawn = Vec3::new(0.0, ENEMY_HALF_HEIGHT, 0.0);
+        let (mut app, _, enemy) = ai_app(Vec3::new(100.0, 0.0, 0.0), spawn);
+        app.world_mut().get_mut::<EnemyAi>(enemy).unwrap().leash();
+        app.world_mut().get_mut::<Health>(enemy).unwrap().current = 40.0;
+        app.world_mut().get_mut::<Transform>(enemy).unwrap().translation = spawn + Vec3::new(0.3, 0.0, 0.0);
+
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.get::<EnemyAi>(enemy).unwrap().state, EnemyAiState::Idle);
+        let health = world.get::<Health>(enemy).unwrap();
+        assert_eq!(health.current, health.max);
+    }
+
+    #[test]
+    fn test_abilities_take_turns_on_their_cooldown() {
+        let abilities = vec!["bleeding".to_string(), "stunned".to_string()];
+        let mut ai = EnemyAi::new(Vec3::ZERO);
+
+        assert_eq!(ai.take_ability(&abilities, 5.0).as_deref(), Some("bleeding"));
+        assert_eq!(ai.take_ability(&abilities, 5.0), None);
+
+        ai.ability_timer.tick(std::time::Duration::from_secs(5));
+        assert_eq!(ai.take_ability(&abilities, 5.0).as_deref(), Some("stunned"));
+
+        ai.ability_timer.tick(std::time::Duration::from_secs(5));
+        assert_eq!(ai.take_ability(&abilities, 5.0).as_deref(), Some("bleeding"));
+        assert_eq!(EnemyAi::new(Vec3::ZERO).take_ability(&[], 5.0), None);
+    }
+}
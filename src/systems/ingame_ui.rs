@@ -2,6 +2,10 @@ use bevy::prelude::*;
 use crate::components::{Player, CharacterLevel, CharacterSkills, CharacterLoadouts};
 use crate::components::progression::RoleType;
 use crate::states::GameState;
+use crate::systems::abilities::{CooldownOverlay, AbilitySlotLabel};
+use crate::systems::combat_log::{CombatMeterPanel, CombatMeterText};
+use crate::systems::spellcasting::{CastBar, CastBarFill, CastBarText};
+use crate::systems::targeting::{TargetFrame, TargetFrameText};
 
 // In-game UI Components
 #[derive(Component)]
@@ -277,19 +281,151 @@ pub fn setup_ingame_ui(mut commands: Commands) {
                     BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
                     AbilitySlot { slot_index: i },
                 )).with_children(|parent| {
+                    // Cooldown sweep overlay - height tracks remaining cooldown
+                    parent.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            top: Val::Px(0.0),
+                            left: Val::Px(0.0),
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(0.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+                        CooldownOverlay { slot_index: i },
+                    ));
                     // Ability name/icon placeholder
                     parent.spawn((
                         Text::new(format!("{}", i + 1)),
                         TextFont {
-                            font_size: 18.0,
+                            font_size: 10.0,
                             ..default()
                         },
                         TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        AbilitySlotLabel { slot_index: i },
                     ));
                 });
             }
         });
 
+        // Target frame (top center) - target, target of target and focus
+        parent.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(20.0),
+                left: Val::Percent(50.0),
+                width: Val::Px(360.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                // Center the target frame horizontally (margin offset)
+                margin: UiRect::left(Val::Px(-180.0)),
+                display: Display::None,
+                ..default()
+            },
+            BorderColor(Color::srgb(0.6, 0.4, 0.2)),
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.9)),
+            TargetFrame,
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.95, 0.95, 0.9)),
+                TargetFrameText,
+            ));
+        });
+
+        // Cast bar (above the hotbar) - only shown while casting or channelling
+        parent.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(95.0),
+                left: Val::Percent(50.0),
+                width: Val::Px(240.0),
+                height: Val::Px(20.0),
+                border: UiRect::all(Val::Px(2.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                // Center the cast bar horizontally (margin offset)
+                margin: UiRect::left(Val::Px(-120.0)),
+                display: Display::None,
+                ..default()
+            },
+            BorderColor(Color::srgb(0.6, 0.4, 0.2)),
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.9)),
+            CastBar,
+        )).with_children(|parent| {
+            // Cast bar fill - width tracks cast progress
+            parent.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(0.0),
+                    left: Val::Px(0.0),
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.9, 0.7, 0.2)),
+                CastBarFill,
+            ));
+            // Spell name and time remaining
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(JustifyText::Center),
+                CastBarText,
+            ));
+        });
+
+        // Combat meter (right side, below skills) - hidden until toggled with L
+        parent.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(20.0),
+                top: Val::Px(440.0),
+                width: Val::Px(320.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                display: Display::None,
+                ..default()
+            },
+            BorderColor(Color::srgb(0.8, 0.3, 0.3)),
+            BackgroundColor(Color::srgba(0.1, 0.05, 0.05, 0.9)),
+            CombatMeterPanel,
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new("Combat Meter"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.8, 0.8)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(6.0)),
+                    ..default()
+                },
+            ));
+
+            parent.spawn((
+                Text::new("No combat yet"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                CombatMeterText,
+            ));
+        });
+
         // Quest Objective Tracker (left side)
         parent.spawn((
             Node {
@@ -413,6 +549,19 @@ pub fn setup_ingame_ui(mut commands: Commands) {
                     ..default()
                 },
             ));
+
+            parent.spawn((
+                Text::new("Tab = Target | 1-4 = Abilities"),
+                TextFont {
+                    font_size: 10.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                Node {
+                    margin: UiRect::top(Val::Px(5.0)),
+                    ..default()
+                },
+            ));
         });
     });
 }
@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use avian3d::prelude::*;
+use crate::components::AttackPosition;
+use crate::systems::combat::Enemy;
+use crate::systems::terrain::Terrain;
+use crate::systems::world_objects::{WorldObject, WorldObjectType};
+
+/// Height above the feet that characters see, shoot from and get aimed at
+pub const CHEST_HEIGHT: f32 = 1.4;
+/// Half-angle of the arc in front of a character that counts as facing (180 degree cone)
+const FACING_HALF_ARC_DEGREES: f32 = 90.0;
+/// Attacks within this angle of where the target faces come from the front
+const FRONT_HALF_ARC_DEGREES: f32 = 60.0;
+/// Attacks within this angle of the target's back come from the rear - everything between is the flank
+const REAR_HALF_ARC_DEGREES: f32 = 45.0;
+
+/// Enemies are positioned at their centre, everything else at the feet
+pub fn aim_point(transform: &Transform, is_enemy: bool) -> Vec3 {
+    if is_enemy {
+        transform.translation
+    } else {
+        transform.translation + Vec3::Y * CHEST_HEIGHT
+    }
+}
+
+/// Whether `point` lies within the arc in front of the transform - only the horizontal plane matters
+pub fn is_facing(transform: &Transform, point: Vec3) -> bool {
+    let forward = (transform.rotation * Vec3::Z).xz().normalize_or_zero();
+    let to_point = (point - transform.translation).xz().normalize_or_zero();
+
+    // Standing on top of the target counts as facing it
+    to_point == Vec2::ZERO || forward.dot(to_point) >= FACING_HALF_ARC_DEGREES.to_radians().cos() - f32::EPSILON
+}
+
+/// Which side of the target an attacker standing at `attacker` is on - only the horizontal plane matters
+pub fn attack_position(target: &Transform, attacker: Vec3) -> AttackPosition {
+    let forward = (target.rotation * Vec3::Z).xz().normalize_or_zero();
+    let to_attacker = (attacker - target.translation).xz().normalize_or_zero();
+
+    // Standing inside the target counts as in front of it
+    if to_attacker == Vec2::ZERO {
+        return AttackPosition::Front;
+    }
+
+    let cos_angle = forward.dot(to_attacker);
+    if cos_angle >= FRONT_HALF_ARC_DEGREES.to_radians().cos() - f32::EPSILON {
+        AttackPosition::Front
+    } else if cos_angle <= -REAR_HALF_ARC_DEGREES.to_radians().cos() + f32::EPSILON {
+        AttackPosition::Rear
+    } else {
+        AttackPosition::Flank
+    }
+}
+
+/// Raycasts between characters against the terrain and world objects
+/// Characters never block each other, and bushes are low enough to see over
+#[derive(SystemParam)]
+pub struct LineOfSight<'w, 's> {
+    spatial_query: SpatialQuery<'w, 's>,
+    character_query: Query<'w, 's, (&'static Transform, Has<Enemy>)>,
+    collider_query: Query<'w, 's, &'static ColliderOf>,
+    blocker_query: Query<'w, 's, Option<&'static WorldObjectType>, Or<(With<Terrain>, With<WorldObject>)>>,
+}
+
+impl LineOfSight<'_, '_> {
+    /// Whether the viewer can see the target - false if either is missing
+    pub fn between(&self, viewer: Entity, target: Entity) -> bool {
+        let (Ok(viewer_info), Ok(target_info)) = (self.character_query.get(viewer), self.character_query.get(target)) else {
+            return false;
+        };
+
+        self.is_clear(aim_point(viewer_info.0, viewer_info.1), aim_point(target_info.0, target_info.1))
+    }
+
+    /// Whether nothing solid in the world lies between two points
+    pub fn is_clear(&self, from: Vec3, to: Vec3) -> bool {
+        let Ok(direction) = Dir3::new(to - from) else {
+            return true;
+        };
+
+        let blocks_sight = |entity: Entity| {
+            let body = self.collider_query.get(entity).map_or(entity, |collider| collider.body);
+            self.blocker_query
+                .get(body)
+                .is_ok_and(|object_type| !matches!(object_type, Some(WorldObjectType::Bush(_) | WorldObjectType::Grass(_))))
+        };
+
+        self.spatial_query
+            .cast_ray_predicate(from, direction, from.distance(to), true, &SpatialQueryFilter::default(), &blocks_sight)
+            .is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_facing_arc() {
+        // Characters face +Z
+        let transform = Transform::from_rotation(Quat::from_rotation_y(0.0));
+
+        assert!(is_facing(&transform, Vec3::new(0.0, 0.0, 5.0)));
+        assert!(is_facing(&transform, Vec3::new(5.0, 3.0, 0.5)));
+        assert!(!is_facing(&transform, Vec3::new(0.0, 0.0, -5.0)));
+        assert!(!is_facing(&transform, Vec3::new(-5.0, 0.0, -0.5)));
+        assert!(is_facing(&transform, Vec3::new(0.0, 2.0, 0.0)));
+    }
+
+    #[test]
+    fn test_attack_position_arcs() {
+        let target = Transform::from_rotation(Quat::from_rotation_y(0.0));
+
+        assert_eq!(attack_position(&target, Vec3::new(1.0, 0.0, 5.0)), AttackPosition::Front);
+        assert_eq!(attack_position(&target, Vec3::new(5.0, 0.0, 0.0)), AttackPosition::Flank);
+        assert_eq!(attack_position(&target, Vec3::new(-5.0, 0.0, -1.0)), AttackPosition::Flank);
+        assert_eq!(attack_position(&target, Vec3::new(1.0, 0.0, -5.0)), AttackPosition::Rear);
+        assert!(AttackPosition::Rear.satisfies(AttackPosition::Flank));
+        assert!(!AttackPosition::Flank.satisfies(AttackPosition::Rear));
+    }
+}
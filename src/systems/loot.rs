@@ -0,0 +1,172 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::collections::HashMap;
+use eryndor_dev_console::ConsoleState;
+use crate::components::{Player, QuestLog};
+use crate::resources::{LootDrop, LootTableDatabase, QuestRequirement};
+use crate::systems::combat::{Corpse, Enemy};
+use crate::systems::death::Dead;
+
+/// How close the player has to be to loot a corpse
+const LOOT_RANGE: f32 = 4.0;
+
+// Loot Components
+/// Items waiting on a corpse until the player loots them
+#[derive(Component, Debug, Default)]
+pub struct Lootable {
+    pub items: Vec<LootDrop>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InventoryStack {
+    pub name: String,
+    pub quantity: u32,
+}
+
+/// Items the player is carrying, keyed by item id
+#[derive(Component, Debug, Default)]
+pub struct Inventory {
+    pub items: HashMap<String, InventoryStack>,
+}
+
+impl Inventory {
+    pub fn add(&mut self, drop: &LootDrop) {
+        self.items
+            .entry(drop.item_id.clone())
+            .or_insert_with(|| InventoryStack { name: drop.name.clone(), quantity: 0 })
+            .quantity += drop.quantity;
+    }
+
+    pub fn count(&self, item_id: &str) -> u32 {
+        self.items.get(item_id).map_or(0, |stack| stack.quantity)
+    }
+}
+
+// Loot Events
+#[derive(Event, Debug)]
+pub struct LootPickedUp {
+    pub player: Entity,
+    pub source: Entity,
+    pub items: Vec<LootDrop>,
+}
+
+/// Whether a quest-only drop is wanted right now - the quest must be active and in the matching phase
+fn quest_phase_active(quest_log: Option<&QuestLog>, requirement: &QuestRequirement) -> bool {
+    quest_log
+        .and_then(|quest_log| quest_log.active_quests.get(&requirement.quest_id))
+        .is_some_and(|progress| progress.current_phase == requirement.phase)
+}
+
+// System to give new players somewhere to put their loot
+pub fn initialize_inventory(
+    mut commands: Commands,
+    player_query: Query<Entity, (With<Player>, Without<Inventory>)>,
+) {
+    for player_entity in &player_query {
+        commands.entity(player_entity).insert(Inventory::default());
+    }
+}
+
+// System to roll an enemy's loot table when it becomes a corpse
+pub fn generate_corpse_loot(
+    mut commands: Commands,
+    loot_tables: Res<LootTableDatabase>,
+    corpse_query: Query<(Entity, &Enemy), Added<Corpse>>,
+    player_query: Query<Option<&QuestLog>, With<Player>>,
+) {
+    let quest_log = player_query.single().ok().flatten();
+    let mut rng = thread_rng();
+
+    for (entity, enemy) in &corpse_query {
+        let Some(table_id) = &enemy.loot_table else {
+            continue;
+        };
+
+        let items = loot_tables.roll(table_id, enemy.level, |quest| quest_phase_active(quest_log, quest), &mut rng);
+        if items.is_empty() {
+            continue;
+        }
+
+        info!("💰 {} dropped {} item(s) - press F nearby to loot", enemy.name, items.len());
+        commands.entity(entity).insert(Lootable { items });
+    }
+}
+
+// System to loot the nearest corpse in range (F key)
+pub fn loot_nearby_corpse(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    console: Option<Res<ConsoleState>>,
+    mut player_query: Query<(Entity, &Transform, &mut Inventory), (With<Player>, Without<Dead>)>,
+    lootable_query: Query<(Entity, &Transform, &Lootable, &Enemy), Without<Player>>,
+    mut loot_writer: EventWriter<LootPickedUp>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+
+    // F is just a letter while typing in the dev console
+    if console.is_some_and(|console| console.is_visible) {
+        return;
+    }
+
+    let Ok((player_entity, player_transform, mut inventory)) = player_query.single_mut() else {
+        return;
+    };
+
+    let nearest = lootable_query
+        .iter()
+        .map(|(entity, transform, lootable, enemy)| {
+            (entity, player_transform.translation.distance(transform.translation), lootable, enemy)
+        })
+        .filter(|(_, distance, _, _)| *distance <= LOOT_RANGE)
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+
+    let Some((corpse_entity, _, lootable, enemy)) = nearest else {
+        info!("Nothing to loot nearby");
+        return;
+    };
+
+    for item in &lootable.items {
+        inventory.add(item);
+        info!("🎒 Looted {} x{} from {}", item.name, item.quantity, enemy.name);
+    }
+
+    commands.entity(corpse_entity).remove::<Lootable>();
+    loot_writer.write(LootPickedUp {
+        player: player_entity,
+        source: corpse_entity,
+        items: lootable.items.clone(),
+    });
+}
+
+// System to mark corpses that still have loot on them
+pub fn highlight_lootable_corpses(
+    mut gizmos: Gizmos,
+    lootable_query: Query<&Transform, (With<Lootable>, With<Corpse>)>,
+) {
+    for transform in &lootable_query {
+        gizmos.cuboid(
+            Transform::from_translation(transform.translation + Vec3::Y * 2.0)
+                .with_scale(Vec3::splat(0.4)),
+            Color::srgb(1.0, 0.85, 0.2),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inventory_merges_stacks() {
+        let mut inventory = Inventory::default();
+        let hide = LootDrop { item_id: "boar_hide".to_string(), name: "Boar Hide".to_string(), quantity: 2 };
+
+        inventory.add(&hide);
+        inventory.add(&hide);
+
+        assert_eq!(inventory.count("boar_hide"), 4);
+        assert_eq!(inventory.count("boar_tusk"), 0);
+    }
+}
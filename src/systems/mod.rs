@@ -18,6 +18,25 @@ pub mod quest_rewards;
 pub mod main_menu;
 pub mod ingame_ui;
 pub mod combat;
+pub mod damage;
+pub mod line_of_sight;
+pub mod projectiles;
+pub mod spellcasting;
+pub mod targeting;
+pub mod attack_table;
+pub mod combos;
+pub mod combat_log;
+pub mod combat_feedback;
+pub mod difficulty;
+pub mod enemy_ai;
+pub mod threat;
+pub mod abilities;
+pub mod status_effects;
+pub mod vitals;
+pub mod death;
+pub mod spawning;
+pub mod loot;
+pub mod encounters;
 pub mod quest;
 pub mod quest_journal_ui;
 // pub mod dialogue; // REMOVED - obsolete hard-coded dialogue system
@@ -45,6 +64,25 @@ pub use quest_rewards::*;
 pub use main_menu::*;
 pub use ingame_ui::*;
 pub use combat::*;
+pub use damage::*;
+pub use line_of_sight::*;
+pub use projectiles::*;
+pub use spellcasting::*;
+pub use targeting::*;
+pub use attack_table::*;
+pub use combos::*;
+pub use combat_log::*;
+pub use combat_feedback::*;
+pub use difficulty::*;
+pub use enemy_ai::*;
+pub use threat::*;
+pub use abilities::*;
+pub use status_effects::*;
+pub use vitals::*;
+pub use death::*;
+pub use spawning::*;
+pub use loot::*;
+pub use encounters::*;
 pub use quest::*;
 pub use quest_journal_ui::*;
 // pub use dialogue::*; // REMOVED - obsolete hard-coded dialogue system
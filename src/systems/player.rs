@@ -2,6 +2,9 @@ use bevy::prelude::*;
 use avian3d::prelude::*;
 use crate::resources::InputResource;
 use crate::components::{Player, PlayerMovementConfig, PlayerMovementState};
+use crate::systems::status_effects::StatusEffects;
+use crate::systems::vitals::Stamina;
+use crate::systems::death::Dead;
 
 
 
@@ -10,13 +13,27 @@ use crate::components::{Player, PlayerMovementConfig, PlayerMovementState};
 pub fn kinematic_character_controller(
     time: Res<Time>,
     input: Res<InputResource>,
-    mut player_query: Query<(Entity, &mut Transform, &PlayerMovementConfig, &mut PlayerMovementState, &Children), With<Player>>,
+    mut player_query: Query<(Entity, &mut Transform, &PlayerMovementConfig, &mut PlayerMovementState, &Children, Option<&StatusEffects>, Option<&mut Stamina>, Has<Dead>), With<Player>>,
     camera_query: Query<(&Transform, &crate::systems::camera::GameCamera), (With<crate::systems::camera::GameCamera>, Without<Player>)>,
     spatial_query: SpatialQuery,
 ) {
-    let Ok((player_entity, mut player_transform, movement_config, mut movement_state, children)) = player_query.single_mut() else {
+    let Ok((player_entity, mut player_transform, movement_config, mut movement_state, children, status_effects, mut stamina, is_dead)) = player_query.single_mut() else {
         return;
     };
+
+    // Letting go of Shift lets an exhausted player sprint again straight away
+    if !input.up {
+        if let Some(stamina) = stamina.as_mut() {
+            stamina.release_sprint();
+        }
+    }
+
+    // Death, stuns and roots stop movement entirely, slows scale it down
+    let movement_multiplier = status_effects.map(|effects| effects.movement_multiplier()).unwrap_or(1.0);
+    let can_move = movement_multiplier > 0.0 && !is_dead;
+    if !can_move {
+        movement_state.current_speed = 0.0;
+    }
     
     let Ok((camera_transform, _camera)) = camera_query.single() else {
         return;
@@ -40,10 +57,12 @@ pub fn kinematic_character_controller(
     // Classic WoW behavior: both mouse buttons = move forward
     let both_mouse_forward = input.mouse_left_held && input.mouse_right_held;
     
-    if input.forward || both_mouse_forward { movement_dir.z += 1.0; }
-    if input.backward { movement_dir.z -= 1.0; }
-    if input.left { movement_dir.x -= 1.0; }
-    if input.right { movement_dir.x += 1.0; }
+    if can_move {
+        if input.forward || both_mouse_forward { movement_dir.z += 1.0; }
+        if input.backward { movement_dir.z -= 1.0; }
+        if input.left { movement_dir.x -= 1.0; }
+        if input.right { movement_dir.x += 1.0; }
+    }
 
     // Handle movement with direct velocity control (no physics conflicts)
     if movement_dir.length() > 0.0 {
@@ -60,12 +79,13 @@ pub fn kinematic_character_controller(
         // Calculate world movement direction
         let world_movement = horizontal_forward * movement_dir.z + horizontal_right * movement_dir.x;
         
-        // Determine target speed (running vs walking)
-        let target_speed = if input.up { // Shift for running
+        // Determine target speed (running vs walking) - running needs stamina
+        movement_state.is_running = input.up && !stamina.as_ref().is_some_and(|stamina| stamina.is_exhausted());
+        let target_speed = if movement_state.is_running { // Shift for running
             movement_config.run_speed
         } else {
             movement_config.walk_speed
-        };
+        } * movement_multiplier;
         
         // Update movement state for animation system
         movement_state.target_speed = target_speed;
@@ -119,6 +139,7 @@ pub fn kinematic_character_controller(
          }
     } else {
         // Smooth deceleration when no input
+        movement_state.is_running = false;
         movement_state.target_speed = 0.0;
         movement_state.target_direction = Vec3::ZERO;
         
@@ -155,7 +176,7 @@ pub fn kinematic_character_controller(
     }
     
     // Handle jumping (Space key)
-    if input.down && !movement_state.is_jumping {
+    if input.down && !movement_state.is_jumping && can_move {
         // Check if on ground using spatial query
         if is_grounded(player_transform.translation, &spatial_query, player_entity, children) {
             // Start jump with initial velocity
@@ -186,11 +207,7 @@ fn collide_and_slide(current_pos: Vec3, movement_delta: Vec3, spatial_query: &Sp
     let capsule_shape = Collider::capsule(0.4, 1.8); // Match character collider size
     
     // Create filter to exclude player entity AND all child colliders
-    let mut excluded_entities = vec![player_entity];
-    for child in children.iter() {
-        excluded_entities.push(child);
-    }
-    let filter = SpatialQueryFilter::default().with_excluded_entities(excluded_entities);
+    let filter = SpatialQueryFilter::default().with_excluded_entities(player_and_children(player_entity, children));
 
     for iteration in 0..MAX_ITERATIONS {
         let movement_length = remaining_movement.length();
@@ -276,27 +293,32 @@ fn project_on_plane(vector: Vec3, plane_normal: Vec3) -> Vec3 {
     vector - plane_normal * vector.dot(plane_normal)
 }
 
-/// Check if character is on ground using downward raycast
-fn is_grounded(pos: Vec3, spatial_query: &SpatialQuery, player_entity: Entity, children: &Children) -> bool {
-    let ray_origin = pos;
-    let ray_direction = Dir3::NEG_Y;
-    let max_distance = 3.0; // Increased to ensure we can detect ground from spawn height
-    
-    // Create filter to exclude player entity AND all child colliders  
+/// Cast straight down from `origin` looking for ground, ignoring the given entities
+fn ground_hit_below(
+    origin: Vec3,
+    max_distance: f32,
+    spatial_query: &SpatialQuery,
+    excluded_entities: impl IntoIterator<Item = Entity>,
+) -> Option<RayHitData> {
+    let filter = SpatialQueryFilter::default().with_excluded_entities(excluded_entities);
+    spatial_query.cast_ray(origin, Dir3::NEG_Y, max_distance, true, &filter)
+}
+
+/// The player entity and all of its child colliders
+fn player_and_children(player_entity: Entity, children: &Children) -> Vec<Entity> {
     let mut excluded_entities = vec![player_entity];
     for child in children.iter() {
         excluded_entities.push(child);
     }
+    excluded_entities
+}
+
+/// Check if character is on ground using downward raycast
+fn is_grounded(pos: Vec3, spatial_query: &SpatialQuery, player_entity: Entity, children: &Children) -> bool {
+    let max_distance = 3.0; // Increased to ensure we can detect ground from spawn height
     
-    let filter = SpatialQueryFilter::default().with_excluded_entities(excluded_entities);
-    
-    if let Some(hit) = spatial_query.cast_ray(
-        ray_origin, 
-        ray_direction, 
-        max_distance, 
-        true, 
-        &filter
-    ) {
+    // Exclude player entity AND all child colliders
+    if let Some(hit) = ground_hit_below(pos, max_distance, spatial_query, player_and_children(player_entity, children)) {
         // Consider grounded only if we hit something at a reasonable distance
         // Distance should be > 0.1 (to avoid self-collision) and < 1.2 (reasonable ground distance)
         hit.distance > 0.1 && hit.distance <= 1.2
@@ -312,21 +334,8 @@ fn apply_ground_snapping(position: Vec3, spatial_query: &SpatialQuery, player_en
     const MIN_SNAP_DISTANCE: f32 = 0.05; // Minimum gap before snapping kicks in
     const MAX_WALKABLE_ANGLE: f32 = 45.0_f32.to_radians(); // 45 degrees
     
-    // Create filter to exclude player entity AND all child colliders
-    let mut excluded_entities = vec![player_entity];
-    for child in children.iter() {
-        excluded_entities.push(child);
-    }
-    let filter = SpatialQueryFilter::default().with_excluded_entities(excluded_entities);
-    
-    // Cast downward from player position
-    if let Some(hit) = spatial_query.cast_ray(
-        position,
-        Dir3::NEG_Y,
-        SNAP_DISTANCE,
-        true,
-        &filter
-    ) {
+    // Cast downward from player position, excluding player entity AND all child colliders
+    if let Some(hit) = ground_hit_below(position, SNAP_DISTANCE, spatial_query, player_and_children(player_entity, children)) {
         // Check if the surface is walkable
         let angle_with_up = hit.normal.dot(Vec3::Y).acos();
         
@@ -1,12 +1,20 @@
 use bevy::prelude::*;
 use crate::components::{Player, CharacterLevel, CharacterSkills, CharacterLoadouts, SkillType, WeaponType};
 use crate::components::progression::RoleType;
+use crate::resources::ProgressionConfig;
+use crate::systems::combat::Enemy;
+use crate::systems::damage::DamageDealt;
+use crate::systems::ingame_ui::{ExperienceNotifications, ExperienceGainEvent};
+use eryndor_core::events::ProgressionEvent;
 
 /// System to handle character level progression based on skill advancement
 /// Characters gain experience and level up when experience thresholds are met
 pub fn character_level_system(
+    config: Res<ProgressionConfig>,
     mut player_query: Query<(&mut CharacterLevel, &CharacterSkills), With<Player>>,
 ) {
+    let curve = &config.character_progression.experience_curve;
+
     for (mut character_level, skills) in player_query.iter_mut() {
         // Award character experience based on skill usage
         // This is a simplified system - in full implementation, XP would come from:
@@ -16,15 +24,14 @@ pub fn character_level_system(
         // - Crafting achievements
         
         let highest_skill = skills.highest_skill_level();
-        let max_level = 50; // Static max level from hardcoded config
-        let expected_character_level = std::cmp::min(max_level, highest_skill + (skills.average_skill_level() / 5.0) as u32);
+        let expected_character_level = std::cmp::min(curve.level_cap(), highest_skill + (skills.average_skill_level() / 5.0) as u32);
         
         // If skills have outpaced character level, award catch-up experience
         if expected_character_level > character_level.level {
-            let catch_up_exp = CharacterLevel::calculate_experience_for_level(expected_character_level) 
-                - CharacterLevel::calculate_experience_for_level(character_level.level);
+            let catch_up_exp = curve.experience_for_level(expected_character_level)
+                .saturating_sub(curve.experience_for_level(character_level.level));
             
-            if character_level.gain_experience(catch_up_exp / 4) { // Gradual catch-up
+            if character_level.gain_experience(catch_up_exp / 4, curve) { // Gradual catch-up
                 info!("Character level increased to {}", character_level.level);
             }
         }
@@ -35,6 +42,7 @@ pub fn character_level_system(
 /// Skill-by-use progression system
 pub fn skill_usage_system(
     time: Res<Time>,
+    config: Res<ProgressionConfig>,
     mut player_query: Query<&mut CharacterSkills, With<Player>>,
 ) {
     let dt = time.delta_secs();
@@ -61,46 +69,46 @@ pub fn skill_usage_system(
             // Practice skills based on current focus
             match suggested_role {
                 RoleType::Tank if viable_roles.contains(&RoleType::Tank) => {
-                    if skills.use_skill(SkillType::Swordsmanship, 10, simulation_rate) {
+                    if skills.use_skill(SkillType::Swordsmanship, 10, simulation_rate, &config) {
                         info!("Swordsmanship skill improved!");
                     }
-                    if skills.use_skill(SkillType::ShieldDefense, 10, simulation_rate) {
+                    if skills.use_skill(SkillType::ShieldDefense, 10, simulation_rate, &config) {
                         info!("Shield Defense skill improved!");
                     }
-                    if skills.use_skill(SkillType::HeavyArmor, 10, simulation_rate) {
+                    if skills.use_skill(SkillType::HeavyArmor, 10, simulation_rate, &config) {
                         info!("Heavy Armor skill improved!");
                     }
                 },
                 RoleType::Healer if viable_roles.contains(&RoleType::Healer) => {
-                    if skills.use_skill(SkillType::Restoration, 10, simulation_rate) {
+                    if skills.use_skill(SkillType::Restoration, 10, simulation_rate, &config) {
                         info!("Restoration magic skill improved!");
                     }
-                    if skills.use_skill(SkillType::LightArmor, 10, simulation_rate) {
+                    if skills.use_skill(SkillType::LightArmor, 10, simulation_rate, &config) {
                         info!("Light Armor skill improved!");
                     }
                 },
                 RoleType::Support if viable_roles.contains(&RoleType::Support) => {
-                    if skills.use_skill(SkillType::Smithing, 10, simulation_rate) {
+                    if skills.use_skill(SkillType::Smithing, 10, simulation_rate, &config) {
                         info!("Smithing skill improved!");
                     }
-                    if skills.use_skill(SkillType::Alchemy, 10, simulation_rate) {
+                    if skills.use_skill(SkillType::Alchemy, 10, simulation_rate, &config) {
                         info!("Alchemy skill improved!");
                     }
                 },
                 RoleType::Utility if viable_roles.contains(&RoleType::Utility) => {
-                    if skills.use_skill(SkillType::Stealth, 10, simulation_rate) {
+                    if skills.use_skill(SkillType::Stealth, 10, simulation_rate, &config) {
                         info!("Stealth skill improved!");
                     }
-                    if skills.use_skill(SkillType::Athletics, 10, simulation_rate) {
+                    if skills.use_skill(SkillType::Athletics, 10, simulation_rate, &config) {
                         info!("Athletics skill improved!");
                     }
                 },
                 _ => {
                     // Default DPS skills or well-rounded development
-                    if skills.use_skill(SkillType::FireMagic, 10, simulation_rate) {
+                    if skills.use_skill(SkillType::FireMagic, 10, simulation_rate, &config) {
                         info!("Fire Magic skill improved!");
                     }
-                    if skills.use_skill(SkillType::Archery, 10, simulation_rate) {
+                    if skills.use_skill(SkillType::Archery, 10, simulation_rate, &config) {
                         info!("Archery skill improved!");
                     }
                 },
@@ -109,6 +117,95 @@ pub fn skill_usage_system(
     }
 }
 
+/// System to grant experience from ProgressionEvent::ExperienceGained (combat kills, quests, ...)
+/// Character experience is awarded directly, the named skill improves through skill-by-use
+pub fn apply_experience_gained(
+    time: Res<Time>,
+    config: Res<ProgressionConfig>,
+    mut progression_events: EventReader<ProgressionEvent>,
+    mut notifications: Option<ResMut<ExperienceNotifications>>,
+    mut player_query: Query<(&mut CharacterLevel, &mut CharacterSkills), With<Player>>,
+) {
+    for event in progression_events.read() {
+        let ProgressionEvent::ExperienceGained { player_entity, skill_id, amount, source, target_level } = event else {
+            continue;
+        };
+
+        let Ok((mut character_level, mut skills)) = player_query.get_mut(*player_entity) else {
+            continue;
+        };
+
+        let mut gains = Vec::new();
+
+        if *amount > 0 {
+            let leveled_up = character_level.gain_experience(*amount, &config.character_progression.experience_curve);
+            info!("📈 Gained {} experience ({})", amount, source);
+            gains.push(("Character".to_string(), *amount, leveled_up.then_some(character_level.level)));
+        }
+
+        if let Some(skill_type) = config.skill_id_to_enum(skill_id) {
+            let experience_before = skills.skills.get(&skill_type).map(|skill| skill.experience).unwrap_or(0);
+            let leveled_up = skills.use_skill(skill_type, *target_level, 1.0, &config);
+
+            if let Some(skill) = skills.skills.get(&skill_type) {
+                let skill_name = config.get_skill_config_by_enum(skill_type)
+                    .map(|skill_config| skill_config.display_name.clone())
+                    .unwrap_or_else(|| format!("{:?}", skill_type));
+                gains.push((skill_name, skill.experience.saturating_sub(experience_before), leveled_up.then_some(skill.level)));
+            }
+        }
+
+        // Feed the in-game experience notifications
+        if let Some(notifications) = notifications.as_mut() {
+            for (skill_name, experience_gained, new_level) in gains {
+                notifications.pending_notifications.push(ExperienceGainEvent {
+                    skill_name,
+                    experience_gained,
+                    new_level,
+                    timestamp: time.elapsed_secs(),
+                });
+            }
+        }
+    }
+}
+
+/// Skill-by-use effectiveness of a single hit taken - armor trains on every hit, so each counts for little
+const ARMOR_TRAINING_EFFECTIVENESS: f32 = 0.25;
+
+/// System to train the worn armor's skill whenever the player takes a hit
+pub fn train_armor_skills(
+    config: Res<ProgressionConfig>,
+    mut damage_dealt_events: EventReader<DamageDealt>,
+    mut player_query: Query<(Entity, &CharacterLoadouts, &mut CharacterSkills), With<Player>>,
+    enemy_query: Query<&Enemy>,
+) {
+    let Ok((player_entity, loadouts, mut skills)) = player_query.single_mut() else {
+        return;
+    };
+    let Some(armor_skill) = loadouts
+        .active_loadout()
+        .and_then(|loadout| config.armor_training_skill(loadout.armor_type))
+    else {
+        return;
+    };
+
+    for event in damage_dealt_events.read() {
+        if event.target != player_entity || event.amount <= 0.0 {
+            continue;
+        }
+
+        // Hits from tougher enemies teach more - anything else trains at the current skill level
+        let target_level = event.attacker
+            .and_then(|attacker| enemy_query.get(attacker).ok())
+            .map(|enemy| enemy.level)
+            .unwrap_or_else(|| skills.get_skill_level(armor_skill));
+
+        if skills.use_skill(armor_skill, target_level, ARMOR_TRAINING_EFFECTIVENESS, &config) {
+            info!("🛡️ {:?} skill improved to {}!", armor_skill, skills.get_skill_level(armor_skill));
+        }
+    }
+}
+
 /// System to manage loadout switching at rest points
 /// This enforces the rule that loadouts can only be changed at inns, campfires, etc.
 pub fn loadout_management_system(
@@ -321,6 +418,7 @@ fn create_utility_loadout(skills: &CharacterSkills) -> crate::components::Loadou
 /// Debug system to display character progression info
 pub fn debug_character_v2_system(
     keyboard: Res<ButtonInput<KeyCode>>,
+    config: Res<ProgressionConfig>,
     mut debug_visible: Local<bool>,
     player_query: Query<(&CharacterLevel, &CharacterSkills, &CharacterLoadouts), With<Player>>,
 ) {
@@ -340,7 +438,7 @@ pub fn debug_character_v2_system(
             info!(
                 "CHARACTER: Level {} ({:.1}% to next) | Suggested Role: {:?} | Versatile: {} | Viable Roles: {:?}",
                 character_level.level,
-                character_level.level_progress() * 100.0,
+                character_level.level_progress(&config.character_progression.experience_curve) * 100.0,
                 skills.get_suggested_primary_role(),
                 skills.is_versatile_build(),
                 skills.get_viable_roles(20)
@@ -400,20 +498,73 @@ pub fn debug_rested_bonus_system(
 /// Award experience for testing (Ctrl+Shift+F6)
 pub fn debug_award_character_experience_system(
     keyboard: Res<ButtonInput<KeyCode>>,
+    config: Res<ProgressionConfig>,
     mut player_query: Query<&mut CharacterLevel, With<Player>>,
 ) {
+    let curve = &config.character_progression.experience_curve;
+
     if keyboard.pressed(KeyCode::ControlLeft) && keyboard.pressed(KeyCode::ShiftLeft) && keyboard.just_pressed(KeyCode::F6) {
         if let Ok(mut character_level) = player_query.single_mut() {
             let exp_award = 500;
-            let leveled_up = character_level.gain_experience(exp_award);
+            let leveled_up = character_level.gain_experience(exp_award, curve);
             
             info!(
                 "DEBUG: Awarded {} character XP! Level {} ({:.1}% to next){}",
                 exp_award,
                 character_level.level,
-                character_level.level_progress() * 100.0,
+                character_level.level_progress(curve) * 100.0,
                 if leveled_up { " - level increased" } else { "" }
             );
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::DamageType;
+    use crate::systems::attack_table::AttackOutcome;
+    use crate::systems::damage::DamageSource;
+
+    fn hit(target: Entity, amount: f32) -> DamageDealt {
+        DamageDealt {
+            attacker: None,
+            target,
+            damage_type: DamageType::Slashing,
+            source: DamageSource::AutoAttack,
+            raw_amount: amount,
+            amount,
+            resistance: 0.0,
+            special_effects: Vec::new(),
+            outcome: AttackOutcome::Hit,
+            position: None,
+            status_effect: None,
+            killing_blow: false,
+        }
+    }
+
+    #[test]
+    fn test_taking_hits_trains_the_worn_armor_skill() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(crate::resources::load_progression_config())
+            .add_event::<DamageDealt>()
+            .add_systems(Update, train_armor_skills);
+
+        // The default loadout wears medium armor
+        let player = app.world_mut().spawn((Player, CharacterLoadouts::default(), CharacterSkills::default())).id();
+        let bystander = app.world_mut().spawn_empty().id();
+
+        app.world_mut().send_event(hit(bystander, 10.0));
+        app.world_mut().send_event(hit(player, 0.0));
+        app.update();
+        let skills = app.world().get::<CharacterSkills>(player).unwrap();
+        assert_eq!(skills.get_skill_line(SkillType::MediumArmor).unwrap().experience, 0);
+
+        app.world_mut().send_event(hit(player, 10.0));
+        app.update();
+        let skills = app.world().get::<CharacterSkills>(player).unwrap();
+        assert!(skills.get_skill_line(SkillType::MediumArmor).unwrap().experience > 0);
+        assert_eq!(skills.get_skill_line(SkillType::HeavyArmor).unwrap().experience, 0);
+    }
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
-use crate::components::progression::{SkillType, WeaponType, DamageType, RoleType};
-use crate::resources::ProgressionConfig;
+use crate::components::progression::{SkillType, WeaponType, DamageType, RoleType, ArmorType, CharacterSkills};
+use crate::resources::{ProgressionConfig, ArmorTypeConfig, ExperienceCurve, StatusEffectConfig};
+use crate::systems::damage::is_handled_special_effect;
 
 /// System to demonstrate JSON configuration integration
 pub fn debug_progression_config_system(
@@ -191,6 +192,25 @@ impl ProgressionConfig {
         }
     }
 
+    /// Convert RoleType enum to string ID for config lookups
+    pub fn role_enum_to_id(&self, role_type: RoleType) -> &str {
+        match role_type {
+            RoleType::Tank => "tank",
+            RoleType::Healer => "healer",
+            RoleType::DPS => "dps",
+            RoleType::Support => "support",
+            RoleType::Utility => "utility",
+        }
+    }
+
+    /// Get a role bonus multiplier (e.g. "threat_generation") - returns None if the role doesn't grant it
+    pub fn get_role_bonus_by_enum(&self, role_type: RoleType, bonus: &str) -> Option<f32> {
+        let role_id = self.role_enum_to_id(role_type);
+        self.get_role(role_id)
+            .and_then(|role| role.role_bonuses.get(bonus))
+            .copied()
+    }
+
     /// Get weapon config from WeaponType enum
     pub fn get_weapon_config_by_enum(&self, weapon_type: WeaponType) -> Option<&crate::resources::progression_config::WeaponConfig> {
         let weapon_id = match weapon_type {
@@ -252,12 +272,40 @@ impl ProgressionConfig {
         self.get_damage_type(damage_type_id)
     }
 
+    /// Get armor type config from ArmorType enum
+    pub fn get_armor_type_config_by_enum(&self, armor_type: ArmorType) -> Option<&ArmorTypeConfig> {
+        self.armor_types.get(armor_type.resistance_key())
+    }
+
+    /// The armor skill trained by taking hits in this armor
+    pub fn armor_training_skill(&self, armor_type: ArmorType) -> Option<SkillType> {
+        self.get_armor_type_config_by_enum(armor_type)
+            .and_then(|armor| self.skill_id_to_enum(&armor.skill))
+    }
+
+    /// How strongly armor applies its damage_types.json resistances - grows with the armor skill
+    /// Wearers without skills (enemies) get the resistances as listed
+    pub fn armor_value(&self, armor_type: ArmorType, skills: Option<&CharacterSkills>) -> f32 {
+        let (Some(armor), Some(skills)) = (self.get_armor_type_config_by_enum(armor_type), skills) else {
+            return 1.0;
+        };
+        let skill_level = self.armor_training_skill(armor_type)
+            .map(|skill| skills.get_skill_level(skill))
+            .unwrap_or(1);
+        armor.armor_value_at(skill_level)
+    }
+
     /// Calculate experience for skill using config values
     pub fn calculate_skill_experience(&self, skill_type: SkillType, level: u32) -> u64 {
         let skill_id = self.skill_enum_to_id(skill_type);
         self.skill_experience_for_level(skill_id, level)
     }
 
+    /// Experience curve for a skill, with its per-skill overrides applied
+    pub fn skill_curve_by_enum(&self, skill_type: SkillType) -> ExperienceCurve {
+        self.skill_curve(self.skill_enum_to_id(skill_type))
+    }
+
     /// Get abilities unlocked for a skill at a given level
     pub fn get_skill_abilities(&self, skill_type: SkillType, level: u32) -> Vec<String> {
         let skill_id = self.skill_enum_to_id(skill_type);
@@ -269,7 +317,7 @@ impl ProgressionConfig {
 }
 
 /// System to validate progression configuration at startup
-pub fn validate_progression_config_system(config: Res<ProgressionConfig>) {
+pub fn validate_progression_config_system(config: Res<ProgressionConfig>, status_config: Res<StatusEffectConfig>) {
     info!("Validating progression configuration...");
     
     let mut warnings = Vec::new();
@@ -291,6 +339,13 @@ pub fn validate_progression_config_system(config: Res<ProgressionConfig>) {
                 errors.push(format!("Skill '{}' references unknown weapon '{}'", skill_id, weapon_id));
             }
         }
+
+        // Nothing teaches abilities yet, so requirements would be ignored
+        for ability in skill_config.abilities.values().flatten() {
+            if !ability.requirements.is_empty() {
+                warnings.push(format!("Ability '{}' has requirements {:?} that aren't enforced - it unlocks from skill level alone", ability.name, ability.requirements));
+            }
+        }
     }
     
     // Validate weapons
@@ -316,6 +371,46 @@ pub fn validate_progression_config_system(config: Res<ProgressionConfig>) {
         }
     }
     
+    // Validate damage type special effects - a proc nothing handles would only show up in the combat log
+    for (damage_type_id, damage_type) in &config.damage_types {
+        for (special_effect, chance) in &damage_type.special_effect_chances {
+            if *chance > 0.0 && !is_handled_special_effect(special_effect, &status_config) {
+                warnings.push(format!("Damage type '{}' gives special effect '{}' a {} chance but nothing handles it", damage_type_id, special_effect, chance));
+            }
+        }
+    }
+
+    // Validate armor types
+    for (armor_id, armor_config) in &config.armor_types {
+        if config.skill_id_to_enum(&armor_config.skill).is_none() {
+            errors.push(format!("Armor type '{}' references unknown skill '{}'", armor_id, armor_config.skill));
+        }
+
+        if armor_config.armor_value < 0.0 {
+            warnings.push(format!("Armor type '{}' has invalid armor value: {}", armor_id, armor_config.armor_value));
+        }
+    }
+
+    // Validate experience curves, including every skill's overrides
+    let mut curves = vec![
+        ("Character progression".to_string(), config.character_progression.experience_curve.clone()),
+        ("Skill progression".to_string(), config.skill_progression.experience_curve.clone()),
+    ];
+    curves.extend(config.skills.keys().map(|skill_id| (format!("Skill '{}'", skill_id), config.skill_curve(skill_id))));
+    for (name, curve) in &curves {
+        if curve.experience_table.windows(2).any(|pair| pair[1] < pair[0]) {
+            errors.push(format!("{} has a decreasing experience table", name));
+        }
+
+        if curve.experience_table.first().is_some_and(|&level_one| level_one != 0) {
+            warnings.push(format!("{} experience table should start at 0 for level 1", name));
+        }
+
+        if curve.level_cap() < curve.max_level {
+            warnings.push(format!("{} experience table stops at level {} (max level {})", name, curve.level_cap(), curve.max_level));
+        }
+    }
+
     // Validate roles
     for (role_id, role_config) in &config.roles {
         if role_config.display_name.is_empty() {
@@ -0,0 +1,204 @@
+use bevy::prelude::*;
+use avian3d::prelude::*;
+use crate::components::ProjectileFlight;
+use crate::systems::combat::{Enemy, Health};
+use crate::systems::damage::DamageEvent;
+use crate::systems::line_of_sight::aim_point;
+
+/// Projectiles that haven't hit anything by now are removed
+const MAX_FLIGHT_TIME: f32 = 6.0;
+/// Projectiles start this far towards the target so they clear the shooter's own collider
+const LAUNCH_OFFSET: f32 = 0.8;
+/// Shortest allowed flight - stops point-blank shots needing huge launch speeds
+const MIN_FLIGHT_TIME: f32 = 0.05;
+
+// Projectile Components
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectileKind {
+    Arrow,
+    Thrown,
+    Spell,
+}
+
+/// A projectile in flight - its damage is only dealt if it reaches something that can take it
+#[derive(Component, Debug)]
+pub struct Projectile {
+    /// Damage delivered on impact - the target is replaced by whatever is actually hit
+    pub payload: DamageEvent,
+    pub velocity: Vec3,
+    pub gravity: f32,
+    pub lifetime: Timer,
+}
+
+/// Request to launch a projectile from the payload's attacker towards its target
+#[derive(Event, Debug, Clone)]
+pub struct FireProjectile {
+    pub payload: DamageEvent,
+    pub flight: ProjectileFlight,
+    pub kind: ProjectileKind,
+}
+
+/// Shared meshes and materials so every shot doesn't allocate new assets
+#[derive(Resource)]
+pub struct ProjectileAssets {
+    arrow: (Handle<Mesh>, Handle<StandardMaterial>),
+    thrown: (Handle<Mesh>, Handle<StandardMaterial>),
+    spell: (Handle<Mesh>, Handle<StandardMaterial>),
+}
+
+impl FromWorld for ProjectileAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let arrow_mesh = meshes.add(Cuboid::new(0.05, 0.05, 0.8));
+        let thrown_mesh = meshes.add(Cuboid::new(0.3, 0.05, 0.3));
+        let spell_mesh = meshes.add(Sphere::new(0.25));
+
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let arrow_material = materials.add(Color::srgb(0.5, 0.35, 0.2));
+        let thrown_material = materials.add(Color::srgb(0.6, 0.6, 0.65));
+        let spell_material = materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.5, 0.1),
+            emissive: LinearRgba::new(4.0, 1.5, 0.2, 1.0),
+            ..default()
+        });
+
+        Self {
+            arrow: (arrow_mesh, arrow_material),
+            thrown: (thrown_mesh, thrown_material),
+            spell: (spell_mesh, spell_material),
+        }
+    }
+}
+
+/// Launch velocity that reaches `displacement` under `gravity`, flying at roughly `speed`
+pub fn launch_velocity(displacement: Vec3, speed: f32, gravity: f32) -> Vec3 {
+    let flight_time = (displacement.length() / speed.max(f32::EPSILON)).max(MIN_FLIGHT_TIME);
+    displacement / flight_time + Vec3::Y * (0.5 * gravity * flight_time)
+}
+
+// System to spawn projectiles for ranged attacks and travelling spells
+pub fn launch_projectiles(
+    mut commands: Commands,
+    projectile_assets: Res<ProjectileAssets>,
+    mut fire_events: EventReader<FireProjectile>,
+    transform_query: Query<(&Transform, Has<Enemy>)>,
+) {
+    for event in fire_events.read() {
+        let Some(attacker) = event.payload.attacker else {
+            continue;
+        };
+        let (Ok(shooter), Ok(target)) = (transform_query.get(attacker), transform_query.get(event.payload.target)) else {
+            continue;
+        };
+
+        let target_point = aim_point(target.0, target.1);
+        let shooter_point = aim_point(shooter.0, shooter.1);
+        let origin = shooter_point + (target_point - shooter_point).normalize_or_zero() * LAUNCH_OFFSET;
+        let velocity = launch_velocity(target_point - origin, event.flight.speed, event.flight.gravity);
+
+        let (mesh, material) = match event.kind {
+            ProjectileKind::Arrow => &projectile_assets.arrow,
+            ProjectileKind::Thrown => &projectile_assets.thrown,
+            ProjectileKind::Spell => &projectile_assets.spell,
+        };
+
+        commands.spawn((
+            Name::new("Projectile"),
+            Transform::from_translation(origin).looking_to(velocity.normalize_or(Vec3::Z), Vec3::Y),
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Projectile {
+                payload: event.payload.clone(),
+                velocity,
+                gravity: event.flight.gravity,
+                lifetime: Timer::from_seconds(MAX_FLIGHT_TIME, TimerMode::Once),
+            },
+        ));
+    }
+}
+
+// System to move projectiles and resolve what they hit - terrain and world objects block them
+pub fn update_projectiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+    mut projectile_query: Query<(Entity, &mut Transform, &mut Projectile)>,
+    children_query: Query<&Children>,
+    collider_query: Query<&ColliderOf>,
+    health_query: Query<&Health>,
+    mut damage_writer: EventWriter<DamageEvent>,
+) {
+    let delta_secs = time.delta_secs();
+
+    for (entity, mut transform, mut projectile) in &mut projectile_query {
+        projectile.lifetime.tick(time.delta());
+        if projectile.lifetime.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let start = transform.translation;
+        projectile.velocity.y -= projectile.gravity * delta_secs;
+        let step = projectile.velocity * delta_secs;
+
+        let Ok(direction) = Dir3::new(step) else {
+            continue;
+        };
+
+        // Never collide with the shooter's own body or its child colliders
+        let mut excluded = Vec::new();
+        if let Some(attacker) = projectile.payload.attacker {
+            excluded.push(attacker);
+            excluded.extend(children_query.iter_descendants(attacker));
+        }
+        let filter = SpatialQueryFilter::default().with_excluded_entities(excluded);
+
+        let Some(hit) = spatial_query.cast_ray(start, direction, step.length(), true, &filter) else {
+            transform.translation = start + step;
+            transform.look_to(direction, Vec3::Y);
+            continue;
+        };
+
+        let body = collider_query.get(hit.entity).map_or(hit.entity, |collider| collider.body);
+        if health_query.get(body).is_ok_and(|health| health.is_alive()) {
+            damage_writer.write(DamageEvent {
+                target: body,
+                ..projectile.payload.clone()
+            });
+        } else {
+            debug!("🏹 Projectile blocked by {:?}", body);
+        }
+
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Step the same integration update_projectiles uses and return the closest approach
+    fn closest_approach(displacement: Vec3, speed: f32, gravity: f32) -> f32 {
+        let mut position = Vec3::ZERO;
+        let mut velocity = launch_velocity(displacement, speed, gravity);
+        let delta_secs = 1.0 / 240.0;
+        let mut closest = f32::MAX;
+
+        for _ in 0..(MAX_FLIGHT_TIME / delta_secs) as usize {
+            velocity.y -= gravity * delta_secs;
+            position += velocity * delta_secs;
+            closest = closest.min(position.distance(displacement));
+        }
+        closest
+    }
+
+    #[test]
+    fn test_launch_velocity_reaches_target() {
+        let target = Vec3::new(12.0, -1.0, 5.0);
+        assert!(closest_approach(target, 45.0, 2.0) < 0.2);
+        assert!(closest_approach(target, 20.0, 9.81) < 0.2);
+
+        // Thrown weapons are lobbed upwards to make the distance
+        assert!(launch_velocity(Vec3::new(20.0, 0.0, 0.0), 20.0, 9.81).y > 0.0);
+    }
+}
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use crate::components::{Player, CharacterLevel};
+use crate::resources::{ProgressionConfig, ExperienceCurve};
 
 /// Quest reward system with level-scaling experience
 /// Implements dynamic experience scaling based on player level
@@ -35,10 +36,11 @@ impl QuestRewardSystem {
     /// Award quest experience to player
     pub fn award_quest_experience(
         character_level: &mut CharacterLevel, 
-        quest_type: QuestType
+        quest_type: QuestType,
+        curve: &ExperienceCurve,
     ) -> bool {
         let experience = Self::calculate_quest_experience(quest_type, character_level.level);
-        character_level.gain_experience(experience)
+        character_level.gain_experience(experience, curve)
     }
 }
 
@@ -53,13 +55,16 @@ pub enum QuestType {
 /// Debug system to test quest reward scaling
 pub fn debug_quest_rewards_system(
     keyboard: Res<ButtonInput<KeyCode>>,
+    config: Res<ProgressionConfig>,
     mut player_query: Query<&mut CharacterLevel, With<Player>>,
 ) {
+    let curve = &config.character_progression.experience_curve;
+
     if keyboard.pressed(KeyCode::ControlLeft) && keyboard.just_pressed(KeyCode::F7) {
         if let Ok(mut character_level) = player_query.single_mut() {
             let quest_type = QuestType::SideQuest;
             let experience = QuestRewardSystem::calculate_quest_experience(quest_type, character_level.level);
-            let leveled_up = character_level.gain_experience(experience);
+            let leveled_up = character_level.gain_experience(experience, curve);
             
             info!(
                 "DEBUG: Completed {:?} quest! Awarded {} XP (scaled for level {}). Level {} ({:.1}% to next){}",
@@ -67,7 +72,7 @@ pub fn debug_quest_rewards_system(
                 experience,
                 character_level.level,
                 character_level.level,
-                character_level.level_progress() * 100.0,
+                character_level.level_progress(curve) * 100.0,
                 if leveled_up { " - level increased" } else { "" }
             );
         }
@@ -78,7 +83,7 @@ pub fn debug_quest_rewards_system(
         if let Ok(mut character_level) = player_query.single_mut() {
             let quest_type = QuestType::MainStory;
             let experience = QuestRewardSystem::calculate_quest_experience(quest_type, character_level.level);
-            let leveled_up = character_level.gain_experience(experience);
+            let leveled_up = character_level.gain_experience(experience, curve);
             
             info!(
                 "DEBUG: Completed {:?} quest! Awarded {} XP (scaled for level {}). Level {} ({:.1}% to next){}",
@@ -86,7 +91,7 @@ pub fn debug_quest_rewards_system(
                 experience,
                 character_level.level,
                 character_level.level,
-                character_level.level_progress() * 100.0,
+                character_level.level_progress(curve) * 100.0,
                 if leveled_up { " - level increased" } else { "" }
             );
         }
@@ -0,0 +1,200 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use eryndor_core::components::{SpawnPoint, SpawnTableEntry};
+use crate::resources::LevelRange;
+use crate::systems::combat::{EnemySpawner, Health};
+
+// Spawning Components
+/// Runtime state the game keeps next to each zone's SpawnPoint
+#[derive(Component, Debug, Default)]
+pub struct SpawnPointState {
+    /// Living enemies spawned by this point
+    pub population: Vec<Entity>,
+    /// One timer per enemy waiting to (re)spawn
+    pub pending_spawns: Vec<Timer>,
+}
+
+/// Links an enemy back to the spawn point that created it
+#[derive(Component, Debug)]
+pub struct SpawnedBy(pub Entity);
+
+/// Pick an enemy id from a weighted spawn table
+pub fn roll_spawn_table<'a>(spawn_table: &'a [SpawnTableEntry], rng: &mut impl Rng) -> Option<&'a str> {
+    let total_weight: u32 = spawn_table.iter().map(|entry| entry.weight).sum();
+    if total_weight == 0 {
+        return None;
+    }
+
+    let mut roll = rng.gen_range(0..total_weight);
+    for entry in spawn_table {
+        if roll < entry.weight {
+            return Some(entry.enemy_id.as_str());
+        }
+        roll -= entry.weight;
+    }
+    None
+}
+
+/// Respawn delay with +/- jitter, never negative
+pub fn jittered_respawn_delay(respawn_delay: f32, respawn_jitter: f32, rng: &mut impl Rng) -> f32 {
+    let jitter = if respawn_jitter > 0.0 {
+        rng.gen_range(-respawn_jitter..=respawn_jitter)
+    } else {
+        0.0
+    };
+    (respawn_delay + jitter).max(0.0)
+}
+
+/// Random point within the wander radius, at the spawn point's height
+fn random_spawn_position(center: Vec3, wander_radius: f32, rng: &mut impl Rng) -> Vec3 {
+    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+    let distance = rng.gen_range(0.0..wander_radius.max(0.1));
+    center + Vec3::new(angle.cos() * distance, 0.0, angle.sin() * distance)
+}
+
+// System to place the demo world's spawn points
+pub fn spawn_demo_spawn_points(
+    mut commands: Commands,
+    mut spawned: Local<bool>,
+) {
+    if *spawned {
+        return;
+    }
+
+    info!("🐺 Placing demo spawn points");
+
+    let spawn_points = [
+        (Vec3::new(20.0, 5.0, 30.0), "forest_guardian", 2, 8.0),
+        (Vec3::new(-30.0, 8.0, 40.0), "rock_elemental", 1, 3.0),
+        (Vec3::new(50.0, 3.0, -20.0), "wild_boar", 3, 12.0),
+        (Vec3::new(-15.0, 6.0, -35.0), "forest_guardian", 1, 8.0),
+        (Vec3::new(0.0, 10.0, 60.0), "rock_elemental", 1, 3.0),
+    ];
+
+    for (position, enemy_id, max_population, wander_radius) in spawn_points {
+        commands.spawn((
+            Name::new(format!("{} spawn point", enemy_id)),
+            Transform::from_translation(position),
+            SpawnPoint {
+                spawn_table: vec![SpawnTableEntry { enemy_id: enemy_id.to_string(), weight: 1 }],
+                respawn_delay: 45.0,
+                respawn_jitter: 10.0,
+                max_population,
+                wander_radius,
+                level_band: None,
+            },
+        ));
+    }
+
+    *spawned = true;
+}
+
+// System to give new spawn points their runtime state - they fill up straight away
+pub fn initialize_spawn_points(
+    mut commands: Commands,
+    spawn_point_query: Query<(Entity, &SpawnPoint), Without<SpawnPointState>>,
+) {
+    for (entity, spawn_point) in &spawn_point_query {
+        commands.entity(entity).insert(SpawnPointState {
+            population: Vec::new(),
+            pending_spawns: (0..spawn_point.max_population)
+                .map(|_| Timer::from_seconds(0.0, TimerMode::Once))
+                .collect(),
+        });
+    }
+}
+
+// System to respawn enemies at spawn points after their respawn delay
+pub fn update_spawn_points(
+    time: Res<Time>,
+    mut spawner: EnemySpawner,
+    mut spawn_point_query: Query<(Entity, &Transform, &SpawnPoint, &mut SpawnPointState)>,
+    enemy_query: Query<&Health>,
+) {
+    let mut rng = thread_rng();
+
+    for (spawn_point_entity, transform, spawn_point, mut state) in &mut spawn_point_query {
+        // Dead or despawned enemies free their slot and start a respawn timer
+        let population_before = state.population.len();
+        state.population.retain(|enemy| enemy_query.get(*enemy).is_ok_and(|health| health.is_alive()));
+        for _ in state.population.len()..population_before {
+            let delay = jittered_respawn_delay(spawn_point.respawn_delay, spawn_point.respawn_jitter, &mut rng);
+            state.pending_spawns.push(Timer::from_seconds(delay, TimerMode::Once));
+        }
+
+        for timer in &mut state.pending_spawns {
+            timer.tick(time.delta());
+        }
+
+        let ready = state.pending_spawns.iter().filter(|timer| timer.finished()).count();
+        state.pending_spawns.retain(|timer| !timer.finished());
+
+        for _ in 0..ready {
+            if state.population.len() >= spawn_point.max_population as usize {
+                break;
+            }
+
+            let Some(enemy_id) = roll_spawn_table(&spawn_point.spawn_table, &mut rng) else {
+                warn!("Spawn point {:?} has an empty spawn table", spawn_point_entity);
+                break;
+            };
+
+            let position = random_spawn_position(transform.translation, spawn_point.wander_radius, &mut rng);
+            if let Some(mut enemy) = spawner.spawn(enemy_id, position, Some(spawn_point.wander_radius), spawn_point.level_band.map(LevelRange::from)) {
+                enemy.insert(SpawnedBy(spawn_point_entity));
+                state.population.push(enemy.id());
+            }
+        }
+    }
+}
+
+// System to remove enemies whose spawn point was removed (e.g. the dev console cleared the zone)
+pub fn despawn_orphaned_enemies(
+    mut commands: Commands,
+    spawned_query: Query<(Entity, &SpawnedBy)>,
+    spawn_point_query: Query<(), With<SpawnPoint>>,
+) {
+    for (entity, spawned_by) in &spawned_query {
+        if spawn_point_query.get(spawned_by.0).is_err() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+
+    fn entry(enemy_id: &str, weight: u32) -> SpawnTableEntry {
+        SpawnTableEntry { enemy_id: enemy_id.to_string(), weight }
+    }
+
+    #[test]
+    fn test_spawn_table_respects_weights() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let table = [entry("wild_boar", 3), entry("forest_guardian", 0), entry("rock_elemental", 1)];
+
+        let mut boars = 0;
+        for _ in 0..400 {
+            match roll_spawn_table(&table, &mut rng) {
+                Some("wild_boar") => boars += 1,
+                Some("rock_elemental") => {}
+                other => panic!("unexpected roll {:?}", other),
+            }
+        }
+        assert!((250..350).contains(&boars));
+        assert_eq!(roll_spawn_table(&[entry("wild_boar", 0)], &mut rng), None);
+    }
+
+    #[test]
+    fn test_respawn_jitter_stays_in_bounds() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..50 {
+            let delay = jittered_respawn_delay(30.0, 5.0, &mut rng);
+            assert!((25.0..=35.0).contains(&delay));
+            assert!(jittered_respawn_delay(2.0, 10.0, &mut rng) >= 0.0);
+        }
+        assert_eq!(jittered_respawn_delay(30.0, 0.0, &mut rng), 30.0);
+    }
+}
@@ -0,0 +1,368 @@
+use bevy::prelude::*;
+use std::fmt;
+use crate::components::{Player, CharacterLoadouts};
+use crate::resources::{ProgressionConfig, AbilityUnlock};
+use crate::systems::abilities::{AbilityCooldowns, AbilityUsed};
+use crate::systems::combat::Health;
+use crate::systems::death::Dead;
+use crate::systems::line_of_sight::LineOfSight;
+use crate::systems::status_effects::StatusEffects;
+use crate::systems::vitals::Mana;
+
+/// How far the caster can drift (horizontally) before a cast counts as moving
+const MOVEMENT_INTERRUPT_DISTANCE: f32 = 0.2;
+/// Cast time multiplier for spells of the same school as the equipped staff
+const STAFF_CAST_TIME_MULTIPLIER: f32 = 0.8;
+
+// Spellcasting Components
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastKind {
+    /// Fires once when the cast bar fills
+    Cast,
+    /// Delivers the ability in even ticks for as long as the channel lasts
+    Channel { ticks: u32, ticks_done: u32 },
+}
+
+/// A spell being cast or channelled - moving, being stunned or losing the target interrupts it
+#[derive(Component, Debug)]
+pub struct Casting {
+    pub ability_name: String,
+    pub skill_id: String,
+    pub slot_index: usize,
+    pub target: Option<Entity>,
+    pub mana_cost: f32, // Spent when a cast completes - channels pay up front
+    pub kind: CastKind,
+    pub timer: Timer,
+    pub start_position: Vec3,
+    pub potency: f32, // Locked in when the cast starts, so a combo bonus survives the window closing mid-cast
+}
+
+impl Casting {
+    /// Start casting the ability, or None if it's instant
+    pub fn begin(
+        ability: &AbilityUnlock,
+        skill_id: &str,
+        slot_index: usize,
+        target: Option<Entity>,
+        start_position: Vec3,
+        cast_time_multiplier: f32,
+    ) -> Option<Self> {
+        let (kind, duration) = if ability.channel_time > 0.0 {
+            (CastKind::Channel { ticks: ability.channel_ticks.max(1), ticks_done: 0 }, ability.channel_time)
+        } else if ability.cast_time > 0.0 {
+            (CastKind::Cast, ability.cast_time * cast_time_multiplier)
+        } else {
+            return None;
+        };
+
+        Some(Self {
+            ability_name: ability.name.clone(),
+            skill_id: skill_id.to_string(),
+            slot_index,
+            target,
+            mana_cost: ability.mana_cost,
+            kind,
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+            start_position,
+            potency: 1.0,
+        })
+    }
+
+    pub fn is_channel(&self) -> bool {
+        matches!(self.kind, CastKind::Channel { .. })
+    }
+
+    /// Cast bar fill - casts fill up, channels drain
+    pub fn bar_fraction(&self) -> f32 {
+        if self.is_channel() {
+            self.timer.fraction_remaining()
+        } else {
+            self.timer.fraction()
+        }
+    }
+
+    /// Channel ticks that have come due since the last call - the final tick lands as the channel ends
+    pub fn take_due_ticks(&mut self) -> u32 {
+        let fraction = self.timer.fraction();
+        let CastKind::Channel { ticks, ticks_done } = &mut self.kind else {
+            return 0;
+        };
+
+        let due = ((fraction * *ticks as f32).floor() as u32).min(*ticks);
+        let new_ticks = due.saturating_sub(*ticks_done);
+        *ticks_done = due;
+        new_ticks
+    }
+
+    pub fn has_moved(&self, position: Vec3) -> bool {
+        (position - self.start_position).xz().length() > MOVEMENT_INTERRUPT_DISTANCE
+    }
+}
+
+/// Why a cast stopped before it finished
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterruptReason {
+    Moved,
+    Stunned,
+    Died,
+    TargetLost,
+    OutOfSight,
+    NotEnoughMana,
+}
+
+impl fmt::Display for InterruptReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterruptReason::Moved => write!(f, "you moved"),
+            InterruptReason::Stunned => write!(f, "you were stunned"),
+            InterruptReason::Died => write!(f, "you died"),
+            InterruptReason::TargetLost => write!(f, "your target is gone"),
+            InterruptReason::OutOfSight => write!(f, "your target is out of sight"),
+            InterruptReason::NotEnoughMana => write!(f, "not enough mana"),
+        }
+    }
+}
+
+// Spellcasting Events
+#[derive(Event, Debug, Clone)]
+pub struct CastInterrupted {
+    pub caster: Entity,
+    pub ability_name: String,
+    pub reason: InterruptReason,
+}
+
+/// Staves speed up spells of their own school
+pub fn cast_time_multiplier(loadouts: Option<&CharacterLoadouts>, skill_id: &str, config: &ProgressionConfig) -> f32 {
+    let staff_matches = loadouts
+        .and_then(|loadouts| loadouts.active_loadout())
+        .map(|loadout| loadout.primary_weapon)
+        .is_some_and(|weapon| weapon.is_staff() && config.skill_enum_to_id(weapon.associated_skill()) == skill_id);
+
+    if staff_matches { STAFF_CAST_TIME_MULTIPLIER } else { 1.0 }
+}
+
+// System to advance casts and channels, delivering them or interrupting them
+#[allow(clippy::too_many_arguments)]
+pub fn update_casting(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<ProgressionConfig>,
+    mut caster_query: Query<(Entity, &Transform, &mut Casting, &mut AbilityCooldowns, Option<&mut Mana>, Option<&StatusEffects>, Has<Dead>)>,
+    target_query: Query<Option<&Health>>,
+    line_of_sight: LineOfSight,
+    mut ability_used_writer: EventWriter<AbilityUsed>,
+    mut interrupt_writer: EventWriter<CastInterrupted>,
+) {
+    for (caster, transform, mut casting, mut cooldowns, mut mana, status_effects, is_dead) in &mut caster_query {
+        let target_lost = casting
+            .target
+            .is_some_and(|target| !target_query.get(target).is_ok_and(|health| health.is_none_or(|health| health.is_alive())));
+
+        let mut interrupt = if is_dead {
+            Some(InterruptReason::Died)
+        } else if status_effects.is_some_and(|effects| effects.is_stunned()) {
+            Some(InterruptReason::Stunned)
+        } else if casting.has_moved(transform.translation) {
+            Some(InterruptReason::Moved)
+        } else if target_lost {
+            Some(InterruptReason::TargetLost)
+        } else if casting.target.is_some_and(|target| !line_of_sight.between(caster, target)) {
+            Some(InterruptReason::OutOfSight)
+        } else {
+            None
+        };
+
+        if interrupt.is_none() {
+            casting.timer.tick(time.delta());
+
+            let kind = casting.kind;
+            match kind {
+                CastKind::Cast if casting.timer.finished() => {
+                    let mana_cost = casting.mana_cost;
+                    if mana.as_deref_mut().is_none_or(|mana| mana.spend(mana_cost)) {
+                        if let Some((_, _, ability)) = config.find_ability(&casting.ability_name) {
+                            cooldowns.start_cooldown(ability);
+                        }
+                        info!("✨ Cast {}", casting.ability_name);
+                        ability_used_writer.write(AbilityUsed {
+                            caster,
+                            ability_name: casting.ability_name.clone(),
+                            skill_id: casting.skill_id.clone(),
+                            slot_index: casting.slot_index,
+                            target: casting.target,
+                            potency: casting.potency,
+                            applies_effects: true,
+                        });
+                        commands.entity(caster).remove::<Casting>();
+                        continue;
+                    }
+                    interrupt = Some(InterruptReason::NotEnoughMana);
+                }
+                CastKind::Channel { ticks, ticks_done } => {
+                    // Damage and healing are split across the ticks - everything else lands once, on the first
+                    for tick in ticks_done..ticks_done + casting.take_due_ticks() {
+                        ability_used_writer.write(AbilityUsed {
+                            caster,
+                            ability_name: casting.ability_name.clone(),
+                            skill_id: casting.skill_id.clone(),
+                            slot_index: casting.slot_index,
+                            target: casting.target,
+                            potency: casting.potency / ticks as f32,
+                            applies_effects: tick == 0,
+                        });
+                    }
+                    if casting.timer.finished() {
+                        commands.entity(caster).remove::<Casting>();
+                    }
+                    continue;
+                }
+                CastKind::Cast => continue,
+            }
+        }
+
+        if let Some(reason) = interrupt {
+            info!("⚡ {} interrupted - {}", casting.ability_name, reason);
+            interrupt_writer.write(CastInterrupted {
+                caster,
+                ability_name: casting.ability_name.clone(),
+                reason,
+            });
+            commands.entity(caster).remove::<Casting>();
+        }
+    }
+}
+
+// Cast Bar UI Components
+#[derive(Component)]
+pub struct CastBar;
+
+#[derive(Component)]
+pub struct CastBarFill;
+
+#[derive(Component)]
+pub struct CastBarText;
+
+// System to show the player's cast bar while casting or channelling
+pub fn update_cast_bar_ui(
+    player_query: Query<Option<&Casting>, With<Player>>,
+    mut bar_query: Query<&mut Node, (With<CastBar>, Without<CastBarFill>)>,
+    mut fill_query: Query<(&mut Node, &mut BackgroundColor), (With<CastBarFill>, Without<CastBar>)>,
+    mut text_query: Query<&mut Text, With<CastBarText>>,
+) {
+    let casting = player_query.single().ok().flatten();
+
+    for mut node in &mut bar_query {
+        let display = if casting.is_some() { Display::Flex } else { Display::None };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+
+    let Some(casting) = casting else {
+        return;
+    };
+
+    for (mut node, mut color) in &mut fill_query {
+        node.width = Val::Percent(casting.bar_fraction() * 100.0);
+        color.0 = if casting.is_channel() { Color::srgb(0.3, 0.6, 1.0) } else { Color::srgb(0.9, 0.7, 0.2) };
+    }
+
+    for mut text in &mut text_query {
+        let remaining = casting.timer.remaining_secs();
+        let new_text = format!("{} ({:.1}s)", casting.ability_name, remaining);
+        if **text != new_text {
+            **text = new_text;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel(ticks: u32) -> Casting {
+        Casting {
+            ability_name: "Wall of Fire".to_string(),
+            skill_id: "fire_magic".to_string(),
+            slot_index: 0,
+            target: None,
+            mana_cost: 0.0,
+            kind: CastKind::Channel { ticks, ticks_done: 0 },
+            timer: Timer::from_seconds(4.0, TimerMode::Once),
+            start_position: Vec3::ZERO,
+            potency: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_channel_ticks_land_evenly() {
+        let mut casting = channel(4);
+
+        casting.timer.tick(std::time::Duration::from_secs_f32(0.5));
+        assert_eq!(casting.take_due_ticks(), 0);
+
+        casting.timer.tick(std::time::Duration::from_secs_f32(2.5));
+        assert_eq!(casting.take_due_ticks(), 3);
+
+        // Overshooting the end still only delivers the remaining tick
+        casting.timer.tick(std::time::Duration::from_secs_f32(5.0));
+        assert_eq!(casting.take_due_ticks(), 1);
+        assert_eq!(casting.take_due_ticks(), 0);
+    }
+
+    #[test]
+    fn test_movement_interrupt_ignores_vertical_drift() {
+        let casting = channel(1);
+        assert!(!casting.has_moved(Vec3::new(0.05, 1.0, 0.05)));
+        assert!(casting.has_moved(Vec3::new(0.5, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_channels_apply_their_effects_once() {
+        use avian3d::prelude::SpatialQueryPipeline;
+        use crate::systems::abilities::execute_abilities;
+        use crate::systems::combat::CombatConfig;
+        use crate::systems::damage::{DamageEvent, HealEvent};
+        use crate::systems::projectiles::FireProjectile;
+        use crate::systems::status_effects::{ApplyStatusEffect, DispelEvent};
+        use crate::systems::threat::TauntEvent;
+
+        // Give the Wall of Fire channel a taunt and a status effect to deliver
+        let mut config = crate::resources::load_progression_config();
+        let wall_of_fire = config.skills.values_mut()
+            .flat_map(|skill| skill.abilities.values_mut().flatten())
+            .find(|ability| ability.name == "Wall of Fire")
+            .unwrap();
+        wall_of_fire.taunt = true;
+        wall_of_fire.status_effect = Some("burning".to_string());
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(config)
+            .init_resource::<CombatConfig>()
+            .init_resource::<SpatialQueryPipeline>()
+            .add_event::<AbilityUsed>()
+            .add_event::<CastInterrupted>()
+            .add_event::<DamageEvent>()
+            .add_event::<HealEvent>()
+            .add_event::<TauntEvent>()
+            .add_event::<ApplyStatusEffect>()
+            .add_event::<DispelEvent>()
+            .add_event::<FireProjectile>()
+            .add_systems(Update, (update_casting, execute_abilities).chain());
+
+        let target = app.world_mut().spawn((Transform::from_xyz(0.0, 0.0, 10.0), Health::new(100.0))).id();
+        let mut casting = channel(4);
+        casting.target = Some(target);
+        casting.timer.tick(std::time::Duration::from_secs(4));
+        app.world_mut().spawn((Transform::default(), casting, AbilityCooldowns::default()));
+
+        app.update();
+
+        let world = app.world();
+        let damage: Vec<&DamageEvent> = world.resource::<Events<DamageEvent>>().iter_current_update_events().collect();
+        assert_eq!(damage.len(), 4);
+        assert_eq!(damage.iter().filter(|event| event.status_effect.is_some()).count(), 1);
+        assert_eq!(world.resource::<Events<TauntEvent>>().iter_current_update_events().count(), 1);
+    }
+}
@@ -0,0 +1,493 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::components::DamageType;
+use crate::resources::{ProgressionConfig, StatusEffectConfig, StatusEffectDefinition, StackingRule};
+use crate::systems::combat::Health;
+use crate::systems::damage::{DamageDealt, DamageEvent, DamageSource, HealEvent};
+use crate::systems::attack_table::AttackOutcome;
+
+// Status Effect Components
+
+/// A status effect currently affecting an entity
+/// Values are snapshotted from the definition when the effect is applied
+#[derive(Debug, Clone)]
+pub struct ActiveStatusEffect {
+    pub effect_id: String,
+    pub source: Option<Entity>,
+    pub remaining: f32,
+    pub stacks: u32,
+    pub max_stacks: u32,
+    pub stacking: StackingRule,
+    pub duration: f32,
+    pub tick_interval: f32,
+    pub time_until_tick: f32,
+    pub damage_per_tick: f32,
+    pub damage_type: Option<String>,
+    pub healing_per_tick: f32,
+    pub movement_multiplier: f32,
+    pub stuns: bool,
+    pub dodge_bonus: f32,
+    pub parry_bonus: f32,
+    pub block_bonus: f32,
+    pub dispel_category: String,
+    pub immunity_duration: f32,
+}
+
+impl ActiveStatusEffect {
+    pub fn from_definition(effect_id: &str, definition: &StatusEffectDefinition, source: Option<Entity>) -> Self {
+        Self {
+            effect_id: effect_id.to_string(),
+            source,
+            remaining: definition.duration,
+            stacks: 1,
+            max_stacks: definition.max_stacks.max(1),
+            stacking: definition.stacking,
+            duration: definition.duration,
+            tick_interval: definition.tick_interval,
+            time_until_tick: definition.tick_interval,
+            damage_per_tick: definition.damage_per_tick,
+            damage_type: definition.damage_type.clone(),
+            healing_per_tick: definition.healing_per_tick,
+            movement_multiplier: definition.movement_multiplier,
+            stuns: definition.stuns,
+            dodge_bonus: definition.dodge_bonus,
+            parry_bonus: definition.parry_bonus,
+            block_bonus: definition.block_bonus,
+            dispel_category: definition.dispel_category.clone(),
+            immunity_duration: definition.immunity_duration,
+        }
+    }
+}
+
+/// Result of trying to apply a status effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusApplication {
+    Applied,
+    Refreshed,
+    Stacked(u32),
+    Immune,
+    Ignored,
+}
+
+/// Damage or healing produced by a status effect tick
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusTick {
+    pub effect_id: String,
+    pub source: Option<Entity>,
+    pub damage: f32,
+    pub damage_type: Option<String>,
+    pub healing: f32,
+}
+
+/// All status effects on an entity plus any immunity windows
+#[derive(Component, Debug, Default)]
+pub struct StatusEffects {
+    pub active: Vec<ActiveStatusEffect>,
+    /// Effect ID -> seconds of immunity remaining
+    pub immunities: HashMap<String, f32>,
+}
+
+impl StatusEffects {
+    pub fn apply(&mut self, effect_id: &str, definition: &StatusEffectDefinition, source: Option<Entity>) -> StatusApplication {
+        if self.immunities.contains_key(effect_id) {
+            return StatusApplication::Immune;
+        }
+
+        let Some(existing) = self.active.iter_mut().find(|effect| effect.effect_id == effect_id) else {
+            self.active.push(ActiveStatusEffect::from_definition(effect_id, definition, source));
+            return StatusApplication::Applied;
+        };
+
+        match existing.stacking {
+            StackingRule::Ignore => StatusApplication::Ignored,
+            StackingRule::Refresh => {
+                existing.remaining = existing.duration;
+                existing.source = source.or(existing.source);
+                StatusApplication::Refreshed
+            }
+            StackingRule::Stack => {
+                existing.remaining = existing.duration;
+                existing.source = source.or(existing.source);
+                if existing.stacks < existing.max_stacks {
+                    existing.stacks += 1;
+                    StatusApplication::Stacked(existing.stacks)
+                } else {
+                    StatusApplication::Refreshed
+                }
+            }
+        }
+    }
+
+    /// Advance durations, returning any ticks that fired and the IDs of expired effects
+    pub fn tick(&mut self, delta_secs: f32) -> (Vec<StatusTick>, Vec<String>) {
+        let mut ticks = Vec::new();
+
+        for immunity in self.immunities.values_mut() {
+            *immunity -= delta_secs;
+        }
+        self.immunities.retain(|_, remaining| *remaining > 0.0);
+
+        for effect in &mut self.active {
+            // Never tick for longer than the effect has left
+            let elapsed = delta_secs.min(effect.remaining.max(0.0));
+            effect.remaining -= delta_secs;
+
+            if effect.tick_interval > 0.0 {
+                effect.time_until_tick -= elapsed;
+                while effect.time_until_tick <= 0.0 {
+                    effect.time_until_tick += effect.tick_interval;
+                    ticks.push(StatusTick {
+                        effect_id: effect.effect_id.clone(),
+                        source: effect.source,
+                        damage: effect.damage_per_tick * effect.stacks as f32,
+                        damage_type: effect.damage_type.clone(),
+                        healing: effect.healing_per_tick * effect.stacks as f32,
+                    });
+                }
+            }
+        }
+
+        let expired = self.remove_where(|effect| effect.remaining <= 0.0);
+        (ticks, expired)
+    }
+
+    /// Remove every effect of a dispel category, returning the removed effect IDs
+    pub fn dispel(&mut self, category: &str) -> Vec<String> {
+        self.remove_where(|effect| effect.dispel_category == category)
+    }
+
+    /// Remove everything - used on death
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+
+    fn remove_where(&mut self, predicate: impl Fn(&ActiveStatusEffect) -> bool) -> Vec<String> {
+        let mut removed = Vec::new();
+        let immunities = &mut self.immunities;
+
+        self.active.retain(|effect| {
+            if !predicate(effect) {
+                return true;
+            }
+            if effect.immunity_duration > 0.0 {
+                immunities.insert(effect.effect_id.clone(), effect.immunity_duration);
+            }
+            removed.push(effect.effect_id.clone());
+            false
+        });
+
+        removed
+    }
+
+    pub fn has_effect(&self, effect_id: &str) -> bool {
+        self.active.iter().any(|effect| effect.effect_id == effect_id)
+    }
+
+    pub fn is_stunned(&self) -> bool {
+        self.active.iter().any(|effect| effect.stuns)
+    }
+
+    /// Combined movement speed multiplier - stuns and roots return 0.0
+    pub fn movement_multiplier(&self) -> f32 {
+        if self.is_stunned() {
+            return 0.0;
+        }
+        self.active
+            .iter()
+            .map(|effect| effect.movement_multiplier)
+            .fold(1.0, |multiplier, effect_multiplier| multiplier * effect_multiplier.max(0.0))
+    }
+
+    /// Combined avoidance bonuses - fed into the attack table
+    pub fn dodge_bonus(&self) -> f32 {
+        self.active.iter().map(|effect| effect.dodge_bonus).sum()
+    }
+
+    pub fn parry_bonus(&self) -> f32 {
+        self.active.iter().map(|effect| effect.parry_bonus).sum()
+    }
+
+    pub fn block_bonus(&self) -> f32 {
+        self.active.iter().map(|effect| effect.block_bonus).sum()
+    }
+}
+
+/// Request to apply a status effect by ID
+#[derive(Event, Debug, Clone)]
+pub struct ApplyStatusEffect {
+    pub target: Entity,
+    pub effect_id: String,
+    pub source: Option<Entity>,
+}
+
+/// A status effect landed on its target (new application or an extra stack)
+#[derive(Event, Debug, Clone)]
+pub struct StatusEffectApplied {
+    pub target: Entity,
+    pub effect_id: String,
+    pub source: Option<Entity>,
+    pub stacks: u32,
+}
+
+/// Request to remove all effects of the given dispel categories
+#[derive(Event, Debug, Clone)]
+pub struct DispelEvent {
+    pub target: Entity,
+    pub categories: Vec<String>,
+}
+
+// System to turn damage special effects, and the status effects of abilities that landed, into status effects
+pub fn trigger_status_effects_from_damage(
+    mut damage_dealt_events: EventReader<DamageDealt>,
+    status_config: Res<StatusEffectConfig>,
+    mut apply_writer: EventWriter<ApplyStatusEffect>,
+) {
+    for event in damage_dealt_events.read() {
+        // Ticks from existing effects never proc new ones
+        if event.killing_blow || matches!(event.source, DamageSource::StatusEffect(_)) {
+            continue;
+        }
+
+        let special_effects = event.special_effects
+            .iter()
+            .filter_map(|special_effect| status_config.effect_for_special(special_effect));
+
+        for effect_id in special_effects.chain(event.status_effect.as_deref()) {
+            apply_writer.write(ApplyStatusEffect {
+                target: event.target,
+                effect_id: effect_id.to_string(),
+                source: event.attacker,
+            });
+        }
+    }
+}
+
+// System to apply requested status effects
+pub fn apply_status_effects(
+    mut apply_events: EventReader<ApplyStatusEffect>,
+    status_config: Res<StatusEffectConfig>,
+    mut target_query: Query<&mut StatusEffects>,
+    mut applied_writer: EventWriter<StatusEffectApplied>,
+) {
+    for event in apply_events.read() {
+        let Some(definition) = status_config.get_effect(&event.effect_id) else {
+            warn!("Unknown status effect '{}'", event.effect_id);
+            continue;
+        };
+
+        let Ok(mut status_effects) = target_query.get_mut(event.target) else {
+            continue;
+        };
+
+        let stacks = match status_effects.apply(&event.effect_id, definition, event.source) {
+            StatusApplication::Applied => {
+                info!("🌀 {} applied", definition.display_name);
+                1
+            }
+            StatusApplication::Stacked(stacks) => {
+                info!("🌀 {} stacked to {}", definition.display_name, stacks);
+                stacks
+            }
+            StatusApplication::Immune => {
+                info!("🛡️ Target is immune to {}", definition.display_name);
+                continue;
+            }
+            StatusApplication::Refreshed | StatusApplication::Ignored => continue,
+        };
+
+        applied_writer.write(StatusEffectApplied {
+            target: event.target,
+            effect_id: event.effect_id.clone(),
+            source: event.source,
+            stacks,
+        });
+    }
+}
+
+// System to tick status effects into damage and healing
+pub fn tick_status_effects(
+    time: Res<Time>,
+    config: Res<ProgressionConfig>,
+    mut status_query: Query<(Entity, &mut StatusEffects, Option<&Health>)>,
+    mut damage_writer: EventWriter<DamageEvent>,
+    mut heal_writer: EventWriter<HealEvent>,
+) {
+    for (entity, mut status_effects, health) in &mut status_query {
+        if health.is_some_and(|health| !health.is_alive()) {
+            status_effects.clear();
+            continue;
+        }
+
+        if status_effects.active.is_empty() && status_effects.immunities.is_empty() {
+            continue;
+        }
+
+        let (ticks, expired) = status_effects.tick(time.delta_secs());
+
+        for tick in ticks {
+            if tick.damage > 0.0 {
+                // Untyped effects deal plain physical damage
+                let damage_type = tick.damage_type
+                    .as_deref()
+                    .and_then(|id| config.damage_type_id_to_enum(id))
+                    .unwrap_or(DamageType::Bludgeoning);
+
+                damage_writer.write(DamageEvent {
+                    attacker: tick.source,
+                    target: entity,
+                    damage_type,
+                    amount: tick.damage,
+                    source: DamageSource::StatusEffect(tick.effect_id.clone()),
+                    outcome: AttackOutcome::Hit,
+                    position: None,
+                    status_effect: None,
+                });
+            }
+
+            if tick.healing > 0.0 {
+                heal_writer.write(HealEvent {
+                    healer: tick.source,
+                    target: entity,
+                    amount: tick.healing,
+                    source: DamageSource::StatusEffect(tick.effect_id),
+                });
+            }
+        }
+
+        for effect_id in expired {
+            info!("⌛ {} faded", effect_id);
+        }
+    }
+}
+
+// System to dispel status effects
+pub fn handle_dispel_events(
+    mut dispel_events: EventReader<DispelEvent>,
+    mut target_query: Query<&mut StatusEffects>,
+) {
+    for event in dispel_events.read() {
+        if let Ok(mut status_effects) = target_query.get_mut(event.target) {
+            for category in &event.categories {
+                for effect_id in status_effects.dispel(category) {
+                    info!("✨ Dispelled {}", effect_id);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(stacking: StackingRule, max_stacks: u32) -> StatusEffectDefinition {
+        StatusEffectDefinition {
+            display_name: "Bleeding".to_string(),
+            description: String::new(),
+            dispel_category: "physical".to_string(),
+            triggered_by: Vec::new(),
+            duration: 3.0,
+            tick_interval: 1.0,
+            damage_per_tick: 2.0,
+            damage_type: Some("slashing".to_string()),
+            healing_per_tick: 0.0,
+            movement_multiplier: 1.0,
+            stuns: false,
+            dodge_bonus: 0.0,
+            parry_bonus: 0.0,
+            block_bonus: 0.0,
+            stacking,
+            max_stacks,
+            immunity_duration: 0.0,
+            beneficial: false,
+        }
+    }
+
+    #[test]
+    fn test_stacking_multiplies_tick_damage() {
+        let bleed = definition(StackingRule::Stack, 2);
+        let mut effects = StatusEffects::default();
+
+        assert_eq!(effects.apply("bleeding", &bleed, None), StatusApplication::Applied);
+        assert_eq!(effects.apply("bleeding", &bleed, None), StatusApplication::Stacked(2));
+        assert_eq!(effects.apply("bleeding", &bleed, None), StatusApplication::Refreshed);
+
+        let (ticks, expired) = effects.tick(1.0);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].damage, 4.0);
+        assert!(expired.is_empty());
+
+        let (ticks, expired) = effects.tick(2.0);
+        assert_eq!(ticks.len(), 2);
+        assert_eq!(expired, vec!["bleeding".to_string()]);
+    }
+
+    #[test]
+    fn test_stun_grants_immunity_window() {
+        let mut stun = definition(StackingRule::Ignore, 1);
+        stun.tick_interval = 0.0;
+        stun.stuns = true;
+        stun.immunity_duration = 5.0;
+
+        let mut effects = StatusEffects::default();
+        effects.apply("stunned", &stun, None);
+        assert!(effects.is_stunned());
+        assert_eq!(effects.movement_multiplier(), 0.0);
+
+        effects.tick(3.0);
+        assert!(!effects.is_stunned());
+        assert_eq!(effects.apply("stunned", &stun, None), StatusApplication::Immune);
+
+        effects.tick(5.0);
+        assert_eq!(effects.apply("stunned", &stun, None), StatusApplication::Applied);
+    }
+
+    #[test]
+    fn test_dispel_removes_matching_category() {
+        let mut effects = StatusEffects::default();
+        let mut poison = definition(StackingRule::Refresh, 1);
+        poison.dispel_category = "poison".to_string();
+
+        effects.apply("poisoned", &poison, None);
+        effects.apply("bleeding", &definition(StackingRule::Stack, 5), None);
+
+        assert_eq!(effects.dispel("poison"), vec!["poisoned".to_string()]);
+        assert!(effects.has_effect("bleeding"));
+    }
+
+    #[test]
+    fn test_ability_effects_only_apply_when_the_hit_lands() {
+        use crate::systems::damage::{resolve_damage_events, AttackAvoided};
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(crate::resources::load_progression_config())
+            .insert_resource(crate::resources::load_status_effect_config())
+            .add_event::<DamageEvent>()
+            .add_event::<DamageDealt>()
+            .add_event::<AttackAvoided>()
+            .add_event::<ApplyStatusEffect>()
+            .add_event::<StatusEffectApplied>()
+            .add_systems(Update, (resolve_damage_events, trigger_status_effects_from_damage, apply_status_effects).chain());
+
+        let target = app.world_mut().spawn((Health::new(100.0), StatusEffects::default())).id();
+        let strike = |outcome| DamageEvent {
+            attacker: None,
+            target,
+            damage_type: DamageType::Piercing,
+            amount: 5.0,
+            source: DamageSource::Ability("Crippling Strike".to_string()),
+            outcome,
+            position: None,
+            status_effect: Some("entangled".to_string()),
+        };
+
+        app.world_mut().send_event(strike(AttackOutcome::Dodge));
+        app.update();
+        assert!(!app.world().get::<StatusEffects>(target).unwrap().has_effect("entangled"));
+
+        app.world_mut().send_event(strike(AttackOutcome::Hit));
+        app.update();
+        assert!(app.world().get::<StatusEffects>(target).unwrap().has_effect("entangled"));
+    }
+}
@@ -0,0 +1,311 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use bevy::window::PrimaryWindow;
+use avian3d::prelude::*;
+use crate::components::{Player, NpcInfo, CharacterLevel};
+use crate::resources::InputResource;
+use crate::systems::camera::GameCamera;
+use crate::systems::combat::{CombatState, Enemy, Health};
+use crate::systems::threat::ThreatTable;
+use crate::systems::difficulty::con_color;
+
+/// How far the cursor can travel between press and release and still count as a click, not a camera drag
+const CLICK_DRAG_THRESHOLD: f32 = 6.0;
+/// Furthest away a click can pick a target
+const CLICK_TARGET_RANGE: f32 = 100.0;
+
+// Targeting Components
+/// Allied players - friendly targets for heals and buffs
+#[derive(Component, Debug, Default)]
+pub struct PartyMember;
+
+/// How the player relates to a target - decides which abilities can be used on it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetDisposition {
+    Hostile,
+    Friendly,
+    /// The player themselves
+    Player,
+}
+
+impl TargetDisposition {
+    pub fn is_hostile(&self) -> bool {
+        matches!(self, TargetDisposition::Hostile)
+    }
+}
+
+/// Everything that can be targeted - enemies, friendly NPCs, party members and the player
+#[derive(SystemParam)]
+pub struct Targetables<'w, 's> {
+    query: Query<'w, 's, (Has<Player>, Has<Enemy>, Has<NpcInfo>, Has<PartyMember>, Option<&'static Health>)>,
+    name_query: Query<'w, 's, (Option<&'static Enemy>, Option<&'static NpcInfo>, Option<&'static Name>)>,
+}
+
+impl Targetables<'_, '_> {
+    /// None if the entity can't be targeted at all (or no longer exists)
+    pub fn disposition(&self, entity: Entity) -> Option<TargetDisposition> {
+        let (is_player, is_enemy, is_npc, is_party_member, _) = self.query.get(entity).ok()?;
+        if is_player {
+            Some(TargetDisposition::Player)
+        } else if is_enemy {
+            Some(TargetDisposition::Hostile)
+        } else if is_npc || is_party_member {
+            Some(TargetDisposition::Friendly)
+        } else {
+            None
+        }
+    }
+
+    /// Targets without health (most NPCs) can't die, so they're always alive
+    pub fn is_alive(&self, entity: Entity) -> bool {
+        self.query
+            .get(entity)
+            .is_ok_and(|(.., health)| health.is_none_or(|health| health.is_alive()))
+    }
+
+    pub fn name(&self, entity: Entity, player_entity: Entity) -> String {
+        if entity == player_entity {
+            return "You".to_string();
+        }
+
+        match self.name_query.get(entity) {
+            Ok((Some(enemy), _, _)) => format!("{} (Lv {})", enemy.name, enemy.level),
+            Ok((_, Some(npc), _)) => npc.display_name.clone(),
+            Ok((_, _, Some(name))) => name.to_string(),
+            _ => "Unknown".to_string(),
+        }
+    }
+
+    pub fn health(&self, entity: Entity) -> Option<&Health> {
+        self.query.get(entity).ok().and_then(|(.., health)| health)
+    }
+
+    /// None for anything that isn't an enemy
+    pub fn enemy_level(&self, entity: Entity) -> Option<u32> {
+        self.name_query.get(entity).ok().and_then(|(enemy, ..)| enemy).map(|enemy| enemy.level)
+    }
+}
+
+/// Who a target is attacking - enemies follow their threat table, the player their own target
+pub fn target_of(entity: Entity, player_entity: Entity, combat_state: &CombatState, threat_query: &Query<&ThreatTable>) -> Option<Entity> {
+    if entity == player_entity {
+        return combat_state.player_target;
+    }
+    threat_query.get(entity).ok().and_then(|threat| threat.current_target)
+}
+
+/// Cursor travel tracked between pressing and releasing the left mouse button
+#[derive(Default)]
+pub struct ClickTracker {
+    press_position: Option<Vec2>,
+    drag_distance: f32,
+}
+
+// System to target whatever is under the cursor on a left click (drags still turn the camera)
+#[allow(clippy::too_many_arguments)]
+pub fn handle_click_targeting(
+    input: Res<InputResource>,
+    mut tracker: Local<ClickTracker>,
+    mut combat_state: ResMut<CombatState>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    player_query: Query<Entity, With<Player>>,
+    spatial_query: SpatialQuery,
+    collider_query: Query<&ColliderOf>,
+    targetables: Targetables,
+) {
+    if input.mouse_left_just_pressed {
+        tracker.press_position = windows.single().ok().and_then(|window| window.cursor_position());
+        tracker.drag_distance = 0.0;
+        return;
+    }
+
+    if input.mouse_left_held {
+        tracker.drag_distance += input.mouse_delta.length();
+        return;
+    }
+
+    if !input.mouse_left_just_released {
+        return;
+    }
+
+    // The cursor is locked while held, so aim with where the button went down
+    let Some(cursor_position) = tracker.press_position.take() else {
+        return;
+    };
+    if tracker.drag_distance > CLICK_DRAG_THRESHOLD {
+        return;
+    }
+
+    let (Ok((camera, camera_transform)), Ok(player_entity)) = (camera_query.single(), player_query.single()) else {
+        return;
+    };
+    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_position) else {
+        return;
+    };
+
+    let Some(hit) = spatial_query.cast_ray(ray.origin, ray.direction, CLICK_TARGET_RANGE, true, &SpatialQueryFilter::default()) else {
+        return;
+    };
+
+    let body = collider_query.get(hit.entity).map_or(hit.entity, |collider| collider.body);
+    let Some(disposition) = targetables.disposition(body) else {
+        return;
+    };
+
+    if !targetables.is_alive(body) {
+        return;
+    }
+
+    combat_state.player_target = Some(body);
+    if disposition.is_hostile() {
+        combat_state.in_combat = true;
+    }
+    info!("🎯 Target selected: {}", targetables.name(body, player_entity));
+}
+
+// System to set a focus target (G), switch to it (Shift+G) and assist by targeting the target's target (T)
+pub fn handle_focus_and_assist(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut combat_state: ResMut<CombatState>,
+    player_query: Query<Entity, With<Player>>,
+    threat_query: Query<&ThreatTable>,
+    targetables: Targetables,
+) {
+    let Ok(player_entity) = player_query.single() else {
+        return;
+    };
+
+    if keyboard.just_pressed(KeyCode::KeyG) {
+        if keyboard.pressed(KeyCode::ShiftLeft) {
+            if let Some(focus) = combat_state.focus_target {
+                combat_state.player_target = Some(focus);
+                info!("🎯 Target selected: {}", targetables.name(focus, player_entity));
+            }
+        } else {
+            combat_state.focus_target = combat_state.player_target;
+            match combat_state.focus_target {
+                Some(focus) => info!("🔵 Focus set: {}", targetables.name(focus, player_entity)),
+                None => info!("🔵 Focus cleared"),
+            }
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyT) {
+        let assist = combat_state
+            .player_target
+            .and_then(|target| target_of(target, player_entity, &combat_state, &threat_query));
+
+        if let Some(assist) = assist {
+            combat_state.player_target = Some(assist);
+            info!("🎯 Assisting - target selected: {}", targetables.name(assist, player_entity));
+        }
+    }
+
+    // Forget a focus that has died or despawned
+    if combat_state.focus_target.is_some_and(|focus| !targetables.is_alive(focus)) {
+        combat_state.focus_target = None;
+    }
+}
+
+// System to mark friendly and focus targets in the world - enemies get their own health bar
+pub fn highlight_targets(
+    combat_state: Res<CombatState>,
+    mut gizmos: Gizmos,
+    transform_query: Query<&Transform, Without<Enemy>>,
+    focus_query: Query<&Transform>,
+) {
+    if let Some(transform) = combat_state.player_target.and_then(|target| transform_query.get(target).ok()) {
+        gizmos.cuboid(
+            Transform::from_translation(transform.translation + Vec3::Y * 0.1)
+                .with_scale(Vec3::new(2.0, 0.1, 2.0)),
+            Color::srgb(0.2, 1.0, 0.3),
+        );
+    }
+
+    if let Some(transform) = combat_state.focus_target.and_then(|focus| focus_query.get(focus).ok()) {
+        gizmos.cuboid(
+            Transform::from_translation(transform.translation + Vec3::Y * 0.2)
+                .with_scale(Vec3::new(2.5, 0.05, 2.5)),
+            Color::srgb(0.3, 0.5, 1.0),
+        );
+    }
+}
+
+// Target Frame UI Components
+#[derive(Component)]
+pub struct TargetFrame;
+
+#[derive(Component)]
+pub struct TargetFrameText;
+
+/// One line of the target frame - name, disposition and health
+fn describe_target(label: &str, entity: Entity, player_entity: Entity, targetables: &Targetables) -> String {
+    let disposition = match targetables.disposition(entity) {
+        Some(TargetDisposition::Hostile) => "hostile",
+        Some(TargetDisposition::Friendly) => "friendly",
+        Some(TargetDisposition::Player) => "self",
+        None => "gone",
+    };
+    let health = targetables
+        .health(entity)
+        .map(|health| format!(" - {:.0}/{:.0} HP", health.current, health.max))
+        .unwrap_or_default();
+
+    format!("{}: {} [{}]{}", label, targetables.name(entity, player_entity), disposition, health)
+}
+
+// System to show the target, your threat on it, target-of-target and focus in the target frame
+pub fn update_target_frame_ui(
+    combat_state: Res<CombatState>,
+    player_query: Query<(Entity, &CharacterLevel), With<Player>>,
+    threat_query: Query<&ThreatTable>,
+    targetables: Targetables,
+    mut frame_query: Query<&mut Node, With<TargetFrame>>,
+    mut text_query: Query<(&mut Text, &mut TextColor), With<TargetFrameText>>,
+) {
+    let Ok((player_entity, character_level)) = player_query.single() else {
+        return;
+    };
+
+    let mut lines = Vec::new();
+    if let Some(target) = combat_state.player_target {
+        lines.push(describe_target("Target", target, player_entity, &targetables));
+
+        // Above 100% the player is about to pull aggro
+        if let Some(threat) = threat_query.get(target).ok().and_then(|table| table.threat_percentage_of(player_entity)) {
+            lines.push(format!("Your threat: {:.0}%", threat));
+        }
+
+        if let Some(target_of_target) = target_of(target, player_entity, &combat_state, &threat_query) {
+            lines.push(describe_target("Target of target", target_of_target, player_entity, &targetables));
+        }
+    }
+    if let Some(focus) = combat_state.focus_target {
+        lines.push(describe_target("Focus", focus, player_entity, &targetables));
+    }
+
+    for mut node in &mut frame_query {
+        let display = if lines.is_empty() { Display::None } else { Display::Flex };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+
+    // Enemy targets are shown in their con colour
+    let new_color = combat_state
+        .player_target
+        .and_then(|target| targetables.enemy_level(target))
+        .map(|enemy_level| con_color(character_level.level, enemy_level).color())
+        .unwrap_or(Color::srgb(0.95, 0.95, 0.9));
+
+    let new_text = lines.join("\n");
+    for (mut text, mut color) in &mut text_query {
+        if **text != new_text {
+            **text = new_text.clone();
+        }
+        if color.0 != new_color {
+            color.0 = new_color;
+        }
+    }
+}
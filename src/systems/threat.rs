@@ -0,0 +1,405 @@
+use bevy::prelude::*;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use crate::components::CharacterLoadouts;
+use crate::resources::ProgressionConfig;
+use crate::systems::combat::Health;
+use crate::systems::damage::{DamageDealt, HealingDone};
+use crate::systems::enemy_ai::{EnemyAi, EnemyAiState};
+
+/// Challengers in melee range must exceed the current target's threat by 10% to pull
+pub const MELEE_PULL_THRESHOLD: f32 = 1.1;
+/// Challengers at range must exceed the current target's threat by 30% to pull
+pub const RANGED_PULL_THRESHOLD: f32 = 1.3;
+/// Distance at which a challenger counts as being in melee range of the enemy
+pub const MELEE_THREAT_RANGE: f32 = 5.0;
+/// Healing generates half as much threat as damage, split across every enemy fighting the healed target
+const HEALING_THREAT_FACTOR: f32 = 0.5;
+/// How long a taunt forces the enemy onto the taunter
+const DEFAULT_TAUNT_DURATION: f32 = 3.0;
+
+// Threat Components
+#[derive(Debug)]
+pub struct ActiveTaunt {
+    pub taunter: Entity,
+    pub timer: Timer,
+}
+
+/// Per-enemy threat table - the enemy attacks whoever holds aggro in this table
+#[derive(Component, Debug, Default)]
+pub struct ThreatTable {
+    pub entries: HashMap<Entity, f32>,
+    pub current_target: Option<Entity>,
+    pub taunt: Option<ActiveTaunt>,
+}
+
+impl ThreatTable {
+    pub fn add_threat(&mut self, entity: Entity, amount: f32) {
+        *self.entries.entry(entity).or_insert(0.0) += amount.max(0.0);
+    }
+
+    pub fn threat_of(&self, entity: Entity) -> f32 {
+        self.entries.get(&entity).copied().unwrap_or(0.0)
+    }
+
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.entries.contains_key(&entity)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entity with the most threat - ties keep the current target, then go to the lowest entity id
+    pub fn highest_threat(&self) -> Option<(Entity, f32)> {
+        self.entries
+            .iter()
+            .map(|(entity, threat)| (*entity, *threat))
+            .max_by(|a, b| self.compare_threat(*a, *b))
+    }
+
+    /// Order two entries by threat, breaking ties the same way every time instead of by HashMap order
+    fn compare_threat(&self, a: (Entity, f32), b: (Entity, f32)) -> Ordering {
+        let is_current = |entity: Entity| self.current_target == Some(entity);
+        a.1.partial_cmp(&b.1)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| is_current(a.0).cmp(&is_current(b.0)))
+            .then_with(|| b.0.cmp(&a.0))
+    }
+
+    pub fn remove(&mut self, entity: Entity) {
+        self.entries.remove(&entity);
+        if self.current_target == Some(entity) {
+            self.current_target = None;
+        }
+        if self.taunt.as_ref().is_some_and(|taunt| taunt.taunter == entity) {
+            self.taunt = None;
+        }
+    }
+
+    /// Wipe the table - used when the enemy leashes or resets
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.current_target = None;
+        self.taunt = None;
+    }
+
+    /// Taunt: match the highest threat on the table and force aggro for the duration
+    pub fn apply_taunt(&mut self, taunter: Entity, duration: f32) {
+        let top_threat = self.highest_threat().map(|(_, threat)| threat).unwrap_or(0.0);
+        let taunter_threat = self.entries.entry(taunter).or_insert(0.0);
+        *taunter_threat = taunter_threat.max(top_threat);
+
+        self.taunt = Some(ActiveTaunt {
+            taunter,
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        });
+        self.current_target = Some(taunter);
+    }
+
+    /// Pick the enemy's target using the 110% melee / 130% ranged pull-over rules
+    pub fn select_target(&mut self, in_melee_range: impl Fn(Entity) -> bool) -> Option<Entity> {
+        // An active taunt overrides the table
+        if let Some(taunt) = &self.taunt {
+            if self.entries.contains_key(&taunt.taunter) {
+                self.current_target = Some(taunt.taunter);
+                return self.current_target;
+            }
+        }
+
+        let current = self.current_target.filter(|target| self.entries.contains_key(target));
+
+        let Some(current) = current else {
+            // No one holds aggro yet - highest threat takes it
+            self.current_target = self.highest_threat().map(|(entity, _)| entity);
+            return self.current_target;
+        };
+
+        let current_threat = self.threat_of(current);
+        let challenger = self.entries
+            .iter()
+            .filter(|(entity, threat)| {
+                let threshold = if in_melee_range(**entity) { MELEE_PULL_THRESHOLD } else { RANGED_PULL_THRESHOLD };
+                **entity != current && **threat > current_threat * threshold
+            })
+            .map(|(entity, threat)| (*entity, *threat))
+            .max_by(|a, b| self.compare_threat(*a, *b))
+            .map(|(entity, _)| entity);
+
+        self.current_target = Some(challenger.unwrap_or(current));
+        self.current_target
+    }
+
+    /// Threat of each entity as a percentage of the current target's threat, highest first
+    /// The aggro holder is always 100% - anything above that is about to pull
+    pub fn threat_percentages(&self) -> Vec<(Entity, f32)> {
+        let baseline = self.current_target
+            .map(|target| self.threat_of(target))
+            .filter(|threat| *threat > 0.0)
+            .or_else(|| self.highest_threat().map(|(_, threat)| threat))
+            .unwrap_or(0.0);
+
+        let mut percentages: Vec<(Entity, f32)> = self.entries
+            .iter()
+            .map(|(entity, threat)| {
+                let percentage = if baseline > 0.0 { threat / baseline * 100.0 } else { 0.0 };
+                (*entity, percentage)
+            })
+            .collect();
+
+        percentages.sort_by(|a, b| self.compare_threat(*b, *a));
+        percentages
+    }
+
+    /// One entity's threat as a percentage of the current target's - None if it isn't on the table
+    pub fn threat_percentage_of(&self, entity: Entity) -> Option<f32> {
+        self.threat_percentages()
+            .into_iter()
+            .find(|(candidate, _)| *candidate == entity)
+            .map(|(_, percentage)| percentage)
+    }
+}
+
+/// Taunt ability hit - forces the target enemy to attack the taunter
+#[derive(Event, Debug, Clone)]
+pub struct TauntEvent {
+    pub taunter: Entity,
+    pub target: Entity,
+    pub duration: f32,
+}
+
+impl TauntEvent {
+    pub fn new(taunter: Entity, target: Entity) -> Self {
+        Self { taunter, target, duration: DEFAULT_TAUNT_DURATION }
+    }
+}
+
+/// Threat multiplier from the role bonuses of the entity's active loadout
+pub fn threat_multiplier(loadouts: Option<&CharacterLoadouts>, config: &ProgressionConfig) -> f32 {
+    loadouts
+        .and_then(|loadouts| loadouts.active_loadout())
+        .and_then(|loadout| config.get_role_bonus_by_enum(loadout.primary_suggested_role(), "threat_generation"))
+        .unwrap_or(1.0)
+}
+
+/// Resetting enemies are immune to threat until they are back home
+fn accepts_threat(ai: Option<&EnemyAi>) -> bool {
+    ai.is_none_or(|ai| ai.state != EnemyAiState::Returning)
+}
+
+// System to generate threat from damage
+pub fn generate_threat_from_damage(
+    mut damage_dealt_events: EventReader<DamageDealt>,
+    config: Res<ProgressionConfig>,
+    attacker_query: Query<Option<&CharacterLoadouts>>,
+    mut threat_query: Query<(&mut ThreatTable, Option<&EnemyAi>)>,
+) {
+    for event in damage_dealt_events.read() {
+        let Some(attacker) = event.attacker else {
+            continue; // Environmental damage generates no threat
+        };
+
+        let Ok((mut threat_table, ai)) = threat_query.get_mut(event.target) else {
+            continue;
+        };
+
+        if !accepts_threat(ai) {
+            continue;
+        }
+
+        let multiplier = threat_multiplier(attacker_query.get(attacker).ok().flatten(), &config);
+        threat_table.add_threat(attacker, event.amount * multiplier);
+    }
+}
+
+// System to generate threat from healing on every enemy fighting the healed target
+pub fn generate_threat_from_healing(
+    mut healing_done_events: EventReader<HealingDone>,
+    config: Res<ProgressionConfig>,
+    healer_query: Query<Option<&CharacterLoadouts>>,
+    mut threat_query: Query<(&mut ThreatTable, Option<&EnemyAi>)>,
+) {
+    for event in healing_done_events.read() {
+        let Some(healer) = event.healer else {
+            continue;
+        };
+
+        if event.amount <= 0.0 {
+            continue; // Pure overheal generates no threat
+        }
+
+        let engaged_enemies = threat_query
+            .iter()
+            .filter(|(table, ai)| accepts_threat(*ai) && (table.contains(event.target) || table.contains(healer)))
+            .count();
+
+        if engaged_enemies == 0 {
+            continue;
+        }
+
+        let multiplier = threat_multiplier(healer_query.get(healer).ok().flatten(), &config);
+        let threat_per_enemy = event.amount * HEALING_THREAT_FACTOR * multiplier / engaged_enemies as f32;
+
+        for (mut table, ai) in &mut threat_query {
+            if accepts_threat(ai) && (table.contains(event.target) || table.contains(healer)) {
+                table.add_threat(healer, threat_per_enemy);
+            }
+        }
+    }
+}
+
+// System to apply taunts
+pub fn handle_taunt_events(
+    mut taunt_events: EventReader<TauntEvent>,
+    mut threat_query: Query<(&mut ThreatTable, Option<&EnemyAi>)>,
+) {
+    for event in taunt_events.read() {
+        if let Ok((mut threat_table, ai)) = threat_query.get_mut(event.target) {
+            if accepts_threat(ai) {
+                threat_table.apply_taunt(event.taunter, event.duration);
+                info!("📢 Enemy taunted for {:.1}s", event.duration);
+            }
+        }
+    }
+}
+
+// System to drop invalid threat entries and pick each enemy's target
+pub fn update_threat_targets(
+    time: Res<Time>,
+    mut threat_query: Query<(&Transform, &mut ThreatTable)>,
+    participant_query: Query<(&Transform, Option<&Health>), Without<ThreatTable>>,
+) {
+    for (enemy_transform, mut threat_table) in &mut threat_query {
+        // Forget despawned or dead participants
+        let stale: Vec<Entity> = threat_table.entries
+            .keys()
+            .filter(|entity| match participant_query.get(**entity) {
+                Ok((_, health)) => health.is_some_and(|health| !health.is_alive()),
+                Err(_) => true,
+            })
+            .copied()
+            .collect();
+
+        for entity in stale {
+            threat_table.remove(entity);
+        }
+
+        // Expire taunts
+        if let Some(taunt) = threat_table.taunt.as_mut() {
+            taunt.timer.tick(time.delta());
+            if taunt.timer.finished() {
+                threat_table.taunt = None;
+            }
+        }
+
+        let previous_target = threat_table.current_target;
+        let new_target = threat_table.select_target(|entity| {
+            participant_query
+                .get(entity)
+                .is_ok_and(|(transform, _)| transform.translation.distance(enemy_transform.translation) <= MELEE_THREAT_RANGE)
+        });
+
+        if previous_target.is_some() && new_target != previous_target {
+            if let Some(new_target) = new_target {
+                info!("⚠️ Aggro pulled by {:?}", new_target);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highest_threat_takes_initial_aggro() {
+        let mut table = ThreatTable::default();
+        let tank = Entity::from_raw(1);
+        let dps = Entity::from_raw(2);
+
+        table.add_threat(tank, 100.0);
+        table.add_threat(dps, 50.0);
+
+        assert_eq!(table.select_target(|_| true), Some(tank));
+    }
+
+    #[test]
+    fn test_pull_over_thresholds() {
+        let mut table = ThreatTable::default();
+        let tank = Entity::from_raw(1);
+        let melee = Entity::from_raw(2);
+        let ranged = Entity::from_raw(3);
+
+        table.add_threat(tank, 100.0);
+        table.select_target(|_| true);
+
+        // Ranged at 120% doesn't pull, melee at 105% doesn't pull
+        table.add_threat(ranged, 120.0);
+        table.add_threat(melee, 105.0);
+        assert_eq!(table.select_target(|entity| entity == melee), Some(tank));
+
+        // Melee at 115% pulls
+        table.add_threat(melee, 10.0);
+        assert_eq!(table.select_target(|entity| entity == melee), Some(melee));
+    }
+
+    #[test]
+    fn test_taunt_matches_top_threat_and_forces_target() {
+        let mut table = ThreatTable::default();
+        let tank = Entity::from_raw(1);
+        let dps = Entity::from_raw(2);
+
+        table.add_threat(dps, 200.0);
+        table.select_target(|_| true);
+        table.apply_taunt(tank, 3.0);
+
+        assert_eq!(table.threat_of(tank), 200.0);
+        assert_eq!(table.select_target(|_| true), Some(tank));
+    }
+
+    #[test]
+    fn test_threat_percentages_relative_to_aggro_holder() {
+        let mut table = ThreatTable::default();
+        let tank = Entity::from_raw(1);
+        let dps = Entity::from_raw(2);
+
+        table.add_threat(tank, 200.0);
+        table.add_threat(dps, 100.0);
+        table.select_target(|_| true);
+
+        let percentages = table.threat_percentages();
+        assert_eq!(percentages[0], (tank, 100.0));
+        assert_eq!(percentages[1], (dps, 50.0));
+    }
+
+    #[test]
+    fn test_threat_ties_keep_the_current_target() {
+        let mut table = ThreatTable::default();
+        let first = Entity::from_raw(1);
+        let second = Entity::from_raw(2);
+
+        // Nobody holds aggro yet - the lowest entity id wins the tie
+        table.add_threat(second, 100.0);
+        table.add_threat(first, 100.0);
+        assert_eq!(table.select_target(|_| true), Some(first));
+
+        // Once someone holds aggro, a tie never moves it
+        table.current_target = Some(second);
+        assert_eq!(table.highest_threat(), Some((second, 100.0)));
+        assert_eq!(table.threat_percentages()[0].0, second);
+    }
+
+    #[test]
+    fn test_threat_percentage_of_one_entity() {
+        let mut table = ThreatTable::default();
+        let tank = Entity::from_raw(1);
+        let dps = Entity::from_raw(2);
+
+        table.add_threat(tank, 200.0);
+        table.add_threat(dps, 150.0);
+        table.select_target(|_| true);
+
+        assert_eq!(table.threat_percentage_of(dps), Some(75.0));
+        assert_eq!(table.threat_percentage_of(Entity::from_raw(3)), None);
+    }
+}
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
-use crate::{resources::GameConfig, states::GameState, components::{Player, CharacterLevel, CharacterSkills}};
+use crate::{resources::GameConfig, states::GameState, components::Player};
+use crate::systems::{combat::Health, vitals::{Mana, Stamina}, death::Dead};
 
 // UI marker components
 #[derive(Component)]
@@ -217,10 +218,9 @@ pub fn debug_info(
     **text = format!("FPS: {:.1}", fps);
 }
 
-// Stats UI update system - displays basic character level info
-// Note: V2 system focuses on skill progression rather than traditional health/mana bars
+// Stats UI update system - player health, mana and stamina
 pub fn update_stats_ui(
-    player_query: Query<(&CharacterLevel, &CharacterSkills), With<Player>>,
+    player_query: Query<(&Health, Option<&Mana>, Option<&Stamina>, Option<&Dead>), With<Player>>,
     mut health_bar_query: Query<&mut Node, (With<HealthBar>, Without<ManaBar>, Without<StaminaBar>)>,
     mut mana_bar_query: Query<&mut Node, (With<ManaBar>, Without<HealthBar>, Without<StaminaBar>)>,
     mut stamina_bar_query: Query<&mut Node, (With<StaminaBar>, Without<HealthBar>, Without<ManaBar>)>,
@@ -228,35 +228,39 @@ pub fn update_stats_ui(
     mut mana_text_query: Query<&mut Text, (With<ManaText>, Without<HealthText>, Without<StaminaText>)>,
     mut stamina_text_query: Query<&mut Text, (With<StaminaText>, Without<HealthText>, Without<ManaText>)>,
 ) {
-    let Ok((character_level, _skills)) = player_query.single() else {
+    let Ok((health, mana, stamina, dead)) = player_query.single() else {
         return; // No player found
     };
-    
-    // Display character level progress as health bar for now
+
     if let Ok(mut health_bar) = health_bar_query.single_mut() {
-        health_bar.width = Val::Percent(character_level.level_progress() * 100.0);
-    }
-    
-    // Hide mana and stamina bars (not used in V2 system currently)
-    if let Ok(mut mana_bar) = mana_bar_query.single_mut() {
-        mana_bar.width = Val::Percent(0.0);
+        health_bar.width = Val::Percent(health.health_percentage() * 100.0);
     }
-    
-    if let Ok(mut stamina_bar) = stamina_bar_query.single_mut() {
-        stamina_bar.width = Val::Percent(0.0);
-    }
-    
-    // Update text to show character level instead
+
     if let Ok(mut health_text) = health_text_query.single_mut() {
-        **health_text = format!("Level {}", character_level.level);
+        **health_text = match dead {
+            Some(dead) => format!("Dead - respawn in {:.0}s", dead.respawn_timer.remaining_secs().ceil()),
+            None => format!("{:.0} / {:.0}", health.current, health.max),
+        };
     }
-    
-    if let Ok(mut mana_text) = mana_text_query.single_mut() {
-        **mana_text = "".to_string();
+
+    if let Some(mana) = mana {
+        if let Ok(mut mana_bar) = mana_bar_query.single_mut() {
+            mana_bar.width = Val::Percent(mana.mana_percentage() * 100.0);
+        }
+
+        if let Ok(mut mana_text) = mana_text_query.single_mut() {
+            **mana_text = format!("{:.0} / {:.0}", mana.current, mana.max);
+        }
     }
-    
-    if let Ok(mut stamina_text) = stamina_text_query.single_mut() {
-        **stamina_text = "".to_string();
+
+    if let Some(stamina) = stamina {
+        if let Ok(mut stamina_bar) = stamina_bar_query.single_mut() {
+            stamina_bar.width = Val::Percent(stamina.stamina_percentage() * 100.0);
+        }
+
+        if let Ok(mut stamina_text) = stamina_text_query.single_mut() {
+            **stamina_text = format!("{:.0} / {:.0}", stamina.current, stamina.max);
+        }
     }
 }
 
@@ -0,0 +1,291 @@
+use bevy::prelude::*;
+use crate::components::{Player, PlayerMovementState, CharacterLoadouts};
+use crate::resources::{ProgressionConfig, ArmorTypeConfig};
+use crate::systems::combat::Health;
+use crate::systems::damage::DamageDealt;
+use crate::systems::attack_table::AttackOutcome;
+
+/// Seconds without taking damage before health starts regenerating
+const OUT_OF_COMBAT_DELAY: f32 = 6.0;
+
+/// Fraction of max stamina an exhausted player must recover before sprinting again
+const EXHAUSTION_RECOVERY_THRESHOLD: f32 = 0.2;
+
+// Player resource pools - Health lives with the rest of the combat components
+#[derive(Component, Debug)]
+pub struct Mana {
+    pub current: f32,
+    pub max: f32,
+    pub base_max: f32, // Max mana before armor bonuses
+}
+
+impl Mana {
+    pub fn new(max_mana: f32) -> Self {
+        Self {
+            current: max_mana,
+            max: max_mana,
+            base_max: max_mana,
+        }
+    }
+
+    /// Scale max mana from its base - current mana never ends up above the new max
+    pub fn set_max_multiplier(&mut self, multiplier: f32) {
+        self.max = self.base_max * multiplier;
+        self.current = self.current.min(self.max);
+    }
+
+    /// Spend mana if there's enough - returns false and spends nothing otherwise
+    pub fn spend(&mut self, amount: f32) -> bool {
+        if self.current < amount {
+            return false;
+        }
+        self.current -= amount;
+        true
+    }
+
+    pub fn restore(&mut self, amount: f32) -> f32 {
+        let restored = amount.min(self.max - self.current).max(0.0);
+        self.current += restored;
+        restored
+    }
+
+    pub fn mana_percentage(&self) -> f32 {
+        if self.max <= 0.0 { 0.0 } else { self.current / self.max }
+    }
+}
+
+#[derive(Component, Debug)]
+pub struct Stamina {
+    pub current: f32,
+    pub max: f32,
+    /// Set when stamina runs out - stays set until it recovers past the threshold or sprint is released
+    pub exhausted: bool,
+}
+
+impl Stamina {
+    pub fn new(max_stamina: f32) -> Self {
+        Self {
+            current: max_stamina,
+            max: max_stamina,
+            exhausted: false,
+        }
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Drain stamina down to zero - sprinting uses whatever is left, then the player is exhausted
+    pub fn drain(&mut self, amount: f32) {
+        self.current = (self.current - amount).max(0.0);
+        if self.current <= 0.0 {
+            self.exhausted = true;
+        }
+    }
+
+    pub fn restore(&mut self, amount: f32) -> f32 {
+        let restored = amount.min(self.max - self.current).max(0.0);
+        self.current += restored;
+        if self.stamina_percentage() >= EXHAUSTION_RECOVERY_THRESHOLD {
+            self.exhausted = false;
+        }
+        restored
+    }
+
+    /// Letting go of sprint clears exhaustion - holding it down doesn't
+    pub fn release_sprint(&mut self) {
+        if self.current > 0.0 {
+            self.exhausted = false;
+        }
+    }
+
+    pub fn stamina_percentage(&self) -> f32 {
+        if self.max <= 0.0 { 0.0 } else { self.current / self.max }
+    }
+}
+
+/// Per-second regeneration rates for the player's resource pools
+#[derive(Component, Debug)]
+pub struct VitalsRegen {
+    pub health_per_second: f32,
+    pub mana_per_second: f32,
+    pub stamina_per_second: f32,
+    pub sprint_stamina_cost: f32, // Stamina drained per second while running
+    /// Health only regenerates once this has run out since the last hit
+    pub combat_timer: Timer,
+}
+
+impl Default for VitalsRegen {
+    fn default() -> Self {
+        let mut combat_timer = Timer::from_seconds(OUT_OF_COMBAT_DELAY, TimerMode::Once);
+        combat_timer.tick(combat_timer.duration());
+
+        Self {
+            health_per_second: 5.0,
+            mana_per_second: 2.0,
+            stamina_per_second: 12.0,
+            sprint_stamina_cost: 10.0,
+            combat_timer,
+        }
+    }
+}
+
+impl VitalsRegen {
+    pub fn in_combat(&self) -> bool {
+        !self.combat_timer.finished()
+    }
+}
+
+/// Mana regeneration multiplier from the role bonuses of the active loadout
+pub fn mana_regeneration_multiplier(loadouts: Option<&CharacterLoadouts>, config: &ProgressionConfig) -> f32 {
+    loadouts
+        .and_then(|loadouts| loadouts.active_loadout())
+        .and_then(|loadout| config.get_role_bonus_by_enum(loadout.primary_suggested_role(), "mana_regeneration"))
+        .unwrap_or(1.0)
+}
+
+/// Armor config for the active loadout's armor type
+pub fn worn_armor<'a>(loadouts: Option<&CharacterLoadouts>, config: &'a ProgressionConfig) -> Option<&'a ArmorTypeConfig> {
+    loadouts
+        .and_then(|loadouts| loadouts.active_loadout())
+        .and_then(|loadout| config.get_armor_type_config_by_enum(loadout.armor_type))
+}
+
+// System to regenerate health, mana and stamina (sprinting drains stamina instead)
+pub fn regenerate_vitals(
+    time: Res<Time>,
+    config: Res<ProgressionConfig>,
+    mut player_query: Query<(&mut Health, Option<&mut Mana>, Option<&mut Stamina>, &mut VitalsRegen, &PlayerMovementState, Option<&CharacterLoadouts>), With<Player>>,
+) {
+    let delta_secs = time.delta_secs();
+
+    for (mut health, mana, stamina, mut regen, movement_state, loadouts) in &mut player_query {
+        regen.combat_timer.tick(time.delta());
+
+        // The dead don't regenerate - respawn restores them
+        if !health.is_alive() {
+            continue;
+        }
+
+        if !regen.in_combat() {
+            health.heal(regen.health_per_second * delta_secs);
+        }
+
+        if let Some(mut mana) = mana {
+            let multiplier = mana_regeneration_multiplier(loadouts, &config);
+            mana.restore(regen.mana_per_second * multiplier * delta_secs);
+        }
+
+        // Heavier armor is more tiring to run in and slower to recover in
+        if let Some(mut stamina) = stamina {
+            let armor = worn_armor(loadouts, &config);
+            if movement_state.is_running {
+                let multiplier = armor.map_or(1.0, |armor| armor.sprint_stamina_multiplier);
+                stamina.drain(regen.sprint_stamina_cost * multiplier * delta_secs);
+            } else {
+                let multiplier = armor.map_or(1.0, |armor| armor.stamina_regeneration_multiplier);
+                stamina.restore(regen.stamina_per_second * multiplier * delta_secs);
+            }
+        }
+    }
+}
+
+// System to resize the mana pool whenever the worn armor changes - light armor deepens it
+pub fn apply_armor_mana_bonus(
+    config: Res<ProgressionConfig>,
+    mut player_query: Query<(&CharacterLoadouts, &mut Mana), Changed<CharacterLoadouts>>,
+) {
+    for (loadouts, mut mana) in &mut player_query {
+        let multiplier = worn_armor(Some(loadouts), &config).map_or(1.0, |armor| armor.max_mana_multiplier);
+        if mana.max != mana.base_max * multiplier {
+            mana.set_max_multiplier(multiplier);
+        }
+    }
+}
+
+// System to put the player in combat whenever they take damage
+pub fn track_player_damage_taken(
+    mut damage_dealt_events: EventReader<DamageDealt>,
+    mut player_query: Query<(Entity, &Health, &mut VitalsRegen), With<Player>>,
+) {
+    let Ok((player_entity, health, mut regen)) = player_query.single_mut() else {
+        return;
+    };
+
+    for event in damage_dealt_events.read() {
+        if event.target != player_entity {
+            continue;
+        }
+
+        regen.combat_timer.reset();
+
+        let verb = match event.outcome {
+            AttackOutcome::Critical => "critically hit",
+            AttackOutcome::Block => "hit (blocked)",
+            _ => "hit",
+        };
+        info!("🩸 You were {} for {:.1} {:?} damage ({:.1}/{:.1} HP)",
+              verb, event.amount, event.damage_type, health.current, health.max);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mana_spend_requires_enough_mana() {
+        let mut mana = Mana::new(50.0);
+        assert!(mana.spend(30.0));
+        assert!(!mana.spend(30.0));
+        assert_eq!(mana.current, 20.0);
+        assert_eq!(mana.restore(100.0), 30.0);
+        assert_eq!(mana.current, 50.0);
+
+        mana.set_max_multiplier(1.25);
+        assert_eq!(mana.max, 62.5);
+        mana.set_max_multiplier(0.9);
+        assert_eq!(mana.current, 45.0);
+    }
+
+    #[test]
+    fn test_stamina_drains_to_zero() {
+        let mut stamina = Stamina::new(80.0);
+        stamina.drain(100.0);
+        assert!(stamina.is_exhausted());
+        assert_eq!(stamina.stamina_percentage(), 0.0);
+    }
+
+    #[test]
+    fn test_exhaustion_holds_until_stamina_recovers() {
+        let mut stamina = Stamina::new(100.0);
+        let (drain_per_frame, regen_per_frame) = (10.0, 1.2);
+
+        // Sprint every frame the player isn't exhausted, regenerate on the others
+        let mut sprinting_frames = 0;
+        for frame in 0..27 {
+            if stamina.is_exhausted() {
+                stamina.restore(regen_per_frame);
+            } else {
+                stamina.drain(drain_per_frame);
+                sprinting_frames += 1;
+            }
+            // A sliver of regen isn't enough to start sprinting again
+            if frame == 10 {
+                assert!(stamina.is_exhausted());
+            }
+        }
+        // Ten frames empty the pool, then 17 frames of regen get it back past 20%
+        assert_eq!(sprinting_frames, 10);
+        assert!(!stamina.is_exhausted());
+
+        // Releasing sprint clears exhaustion early once there's anything left
+        stamina.drain(stamina.max);
+        stamina.release_sprint();
+        assert!(stamina.is_exhausted());
+        stamina.restore(regen_per_frame);
+        assert!(stamina.is_exhausted());
+        stamina.release_sprint();
+        assert!(!stamina.is_exhausted());
+    }
+}